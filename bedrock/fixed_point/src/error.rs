@@ -9,7 +9,19 @@ pub enum FixedPointError {
         bits: usize,
         fractional: usize,
     },
-    
+
+    /// Two arrays that must have the same length do not.
+    #[error("Length mismatch: expected {expected}, found {found}")]
+    LengthMismatch { expected: usize, found: usize },
+
+    /// A byte buffer does not hold a whole number of elements.
+    #[error("Byte length {len} is not a multiple of the element size {element_size}")]
+    InvalidByteLength { len: usize, element_size: usize },
+
+    /// A string could not be parsed as a decimal fixed-point literal.
+    #[error("Invalid fixed-point literal: {0:?}")]
+    ParseError(String),
+
     /// Error from the underlying PackedBits container.
     #[cfg(feature = "packed_container")]
     #[error("PackedBits operation failed: {0}")]
@@ -14,6 +14,9 @@ use bytemuck::{Pod, Zeroable};
 /// - `N`: Total number of bits (must be ≤ 32)
 /// - `F`: Number of fractional bits (must be < N)
 ///
+/// Values are ordered by their raw representation, which for a given `N` and
+/// `F` matches the ordering of the real numbers they represent.
+///
 /// # Examples
 ///
 /// ```
@@ -29,7 +32,7 @@ use bytemuck::{Pod, Zeroable};
 /// assert!((sum.to_f32() - 5.14159).abs() < 0.01);
 /// # Ok::<(), fixed_point::FixedPointError>(())
 /// ```
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
 #[repr(transparent)]
 pub struct FixedSmall<const N: usize, const F: usize> {
     /// The raw integer representation.
@@ -80,18 +83,19 @@ impl<const N: usize, const F: usize> FixedSmall<N, F> {
     /// # Ok::<(), fixed_point::FixedPointError>(())
     /// ```
     pub fn from_f32(value: f32) -> Result<Self, FixedPointError> {
-        let scaled = value * Self::SCALE;
-    
-        // Check bounds before casting to i32
-        if scaled < Self::MIN_INT as f32 || scaled > Self::MAX_INT as f32 {
+        // Scale and bounds-check in f64: `i32::MAX as f32` rounds up to 2^31,
+        // which would let out-of-range values through for N = 32.
+        // NaN fails the range check as well.
+        let scaled = value as f64 * Self::SCALE as f64;
+        if !(Self::MIN_INT as f64..=Self::MAX_INT as f64).contains(&scaled) {
             return Err(FixedPointError::Overflow {
                 value,
                 bits: N,
                 fractional: F,
             });
         }
-        
-        let raw = scaled.round() as i32;
+
+        let raw = scaled.round().clamp(i32::MIN as f64, i32::MAX as f64) as i32;
         Ok(Self { raw })
     }
 
@@ -111,6 +115,119 @@ impl<const N: usize, const F: usize> FixedSmall<N, F> {
         self.raw as f32 / Self::SCALE
     }
 
+    /// Creates a fixed-point number from an f64 value.
+    ///
+    /// Prefer this over [`from_f32`](Self::from_f32) when `N > 24`, where the
+    /// f32 mantissa can no longer hold every representable raw value.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FixedPointError::Overflow` if the value is out of range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_point::FixedSmall;
+    ///
+    /// let x = FixedSmall::<32, 16>::from_f64(30000.00001525878)?;
+    /// assert_eq!(x.raw_value(), (30000 << 16) + 1);
+    /// # Ok::<(), fixed_point::FixedPointError>(())
+    /// ```
+    pub fn from_f64(value: f64) -> Result<Self, FixedPointError> {
+        let scaled = value * (1u64 << F) as f64;
+
+        // NaN fails the range check as well.
+        if !(Self::MIN_INT as f64..=Self::MAX_INT as f64).contains(&scaled) {
+            return Err(FixedPointError::Overflow {
+                value: value as f32,
+                bits: N,
+                fractional: F,
+            });
+        }
+
+        let raw = scaled.round() as i32;
+        Ok(Self { raw })
+    }
+
+    /// Converts the fixed-point number to an f64.
+    ///
+    /// This is exact for every `N ≤ 32`.
+    pub fn to_f64(&self) -> f64 {
+        self.raw as f64 / (1u64 << F) as f64
+    }
+
+    /// Creates a fixed-point number from an exact integer (`raw = n << F`).
+    ///
+    /// # Panics
+    ///
+    /// Panics if `n` is out of range for the format. Use
+    /// [`try_from_integer`](Self::try_from_integer) to handle that case.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_point::FixedSmall;
+    ///
+    /// let x = FixedSmall::<16, 8>::from_integer(-3);
+    /// assert_eq!(x.raw_value(), -3 << 8);
+    /// ```
+    pub fn from_integer(n: i32) -> Self {
+        match Self::try_from_integer(n) {
+            Ok(value) => value,
+            Err(err) => panic!("{err}"),
+        }
+    }
+
+    /// Creates a fixed-point number from an exact integer.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FixedPointError::Overflow` if `n << F` does not fit in N bits.
+    pub fn try_from_integer(n: i32) -> Result<Self, FixedPointError> {
+        let raw = (n as i64) << F;
+        if raw < Self::MIN_INT as i64 || raw > Self::MAX_INT as i64 {
+            return Err(FixedPointError::Overflow {
+                value: n as f32,
+                bits: N,
+                fractional: F,
+            });
+        }
+        Ok(Self { raw: raw as i32 })
+    }
+
+    /// Returns the integer part, truncating toward zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_point::FixedSmall;
+    ///
+    /// assert_eq!(FixedSmall::<16, 8>::from_f32(2.75)?.to_integer(), 2);
+    /// assert_eq!(FixedSmall::<16, 8>::from_f32(-2.75)?.to_integer(), -2);
+    /// # Ok::<(), fixed_point::FixedPointError>(())
+    /// ```
+    pub fn to_integer(&self) -> i32 {
+        (self.raw as i64 / (1i64 << F)) as i32
+    }
+
+    /// Returns the fractional part, with the same sign as `self` (matching
+    /// `f32::fract`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_point::FixedSmall;
+    ///
+    /// assert_eq!(FixedSmall::<16, 8>::from_f32(2.75)?.fractional_part().to_f32(), 0.75);
+    /// assert_eq!(FixedSmall::<16, 8>::from_f32(-2.75)?.fractional_part().to_f32(), -0.75);
+    /// # Ok::<(), fixed_point::FixedPointError>(())
+    /// ```
+    pub fn fractional_part(&self) -> Self {
+        Self {
+            raw: (self.raw as i64 % (1i64 << F)) as i32,
+        }
+    }
+
     /// Creates a fixed-point number from a raw integer value.
     ///
     /// # Examples
@@ -201,6 +318,110 @@ impl<const N: usize, const F: usize> FixedSmall<N, F> {
         }
     }
 
+    /// Divides two fixed-point numbers with saturation.
+    ///
+    /// The quotient is truncated toward zero. Division by zero saturates to
+    /// `max_value()` for a non-negative dividend and `min_value()` otherwise.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_point::FixedSmall;
+    ///
+    /// let x = FixedSmall::<16, 8>::from_f32(6.0)?;
+    /// let y = FixedSmall::<16, 8>::from_f32(4.0)?;
+    /// let quotient = x.div(y);
+    /// assert_eq!(quotient.to_f32(), 1.5);
+    /// # Ok::<(), fixed_point::FixedPointError>(())
+    /// ```
+    #[allow(clippy::should_implement_trait)]
+    pub fn div(self, other: Self) -> Self {
+        match self.checked_div(other) {
+            Some(result) => result,
+            None if self.raw >= 0 => Self::max_value(),
+            None => Self::min_value(),
+        }
+    }
+
+    /// Divides two fixed-point numbers, returning `None` if `other` is zero.
+    ///
+    /// A quotient outside the representable range saturates, as with [`div`](Self::div).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_point::FixedSmall;
+    ///
+    /// let x = FixedSmall::<16, 8>::from_f32(1.0)?;
+    /// assert!(x.checked_div(FixedSmall::zero()).is_none());
+    /// # Ok::<(), fixed_point::FixedPointError>(())
+    /// ```
+    pub fn checked_div(self, other: Self) -> Option<Self> {
+        if other.raw == 0 {
+            return None;
+        }
+        let result = ((self.raw as i64) << F) / other.raw as i64;
+        Some(Self {
+            raw: result.clamp(Self::MIN_INT as i64, Self::MAX_INT as i64) as i32,
+        })
+    }
+
+    /// Adds two fixed-point numbers, returning `None` if the result is out of range.
+    pub fn checked_add(self, other: Self) -> Option<Self> {
+        Self::checked_from_wide(self.raw as i64 + other.raw as i64)
+    }
+
+    /// Subtracts two fixed-point numbers, returning `None` if the result is out of range.
+    pub fn checked_sub(self, other: Self) -> Option<Self> {
+        Self::checked_from_wide(self.raw as i64 - other.raw as i64)
+    }
+
+    /// Multiplies two fixed-point numbers, returning `None` if the result is out of range.
+    pub fn checked_mul(self, other: Self) -> Option<Self> {
+        Self::checked_from_wide((self.raw as i64 * other.raw as i64) >> F)
+    }
+
+    /// Adds two fixed-point numbers, wrapping around at the N-bit boundary.
+    ///
+    /// Useful for cyclic quantities such as angles and timers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_point::FixedSmall;
+    ///
+    /// let max = FixedSmall::<16, 8>::max_value();
+    /// let lsb = FixedSmall::<16, 8>::from_raw(1);
+    /// assert_eq!(max.wrapping_add(lsb), FixedSmall::min_value());
+    /// ```
+    pub fn wrapping_add(self, other: Self) -> Self {
+        Self::wrap(self.raw as i64 + other.raw as i64)
+    }
+
+    /// Subtracts two fixed-point numbers, wrapping around at the N-bit boundary.
+    pub fn wrapping_sub(self, other: Self) -> Self {
+        Self::wrap(self.raw as i64 - other.raw as i64)
+    }
+
+    /// Multiplies two fixed-point numbers, wrapping around at the N-bit boundary.
+    pub fn wrapping_mul(self, other: Self) -> Self {
+        Self::wrap((self.raw as i64 * other.raw as i64) >> F)
+    }
+
+    /// Keeps the low N bits of `raw`, sign-extended.
+    fn wrap(raw: i64) -> Self {
+        let shift = 64 - N as u32;
+        Self {
+            raw: ((raw << shift) >> shift) as i32,
+        }
+    }
+
+    fn checked_from_wide(raw: i64) -> Option<Self> {
+        (Self::MIN_INT as i64..=Self::MAX_INT as i64)
+            .contains(&raw)
+            .then_some(Self { raw: raw as i32 })
+    }
+
     /// Negates the fixed-point number.
     pub fn neg(self) -> Self {
         Self {
@@ -214,6 +435,440 @@ impl<const N: usize, const F: usize> FixedSmall<N, F> {
             raw: self.raw.abs(),
         }
     }
+
+    /// Linearly interpolates between `a` and `b`, computing `a + (b - a) * t`
+    /// with saturation.
+    ///
+    /// The computation is carried out in `i64`, so only the final result is
+    /// clamped to the representable range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_point::FixedSmall;
+    ///
+    /// let a = FixedSmall::<16, 8>::from_f32(2.0)?;
+    /// let b = FixedSmall::<16, 8>::from_f32(4.0)?;
+    /// let t = FixedSmall::<16, 8>::from_f32(0.25)?;
+    /// assert_eq!(FixedSmall::lerp(a, b, t).to_f32(), 2.5);
+    /// # Ok::<(), fixed_point::FixedPointError>(())
+    /// ```
+    pub fn lerp(a: Self, b: Self, t: Self) -> Self {
+        let delta = b.raw as i64 - a.raw as i64;
+        let result = a.raw as i64 + ((delta * t.raw as i64) >> F);
+        Self {
+            raw: result.clamp(Self::MIN_INT as i64, Self::MAX_INT as i64) as i32,
+        }
+    }
+}
+
+// Rounding operations
+impl<const N: usize, const F: usize> FixedSmall<N, F> {
+    const FRAC_MASK: i64 = (1i64 << F) - 1;
+
+    /// Saturates a whole-unit raw value, keeping the result an integer.
+    fn saturate_whole(raw: i64) -> Self {
+        let max = Self::MAX_INT as i64 & !Self::FRAC_MASK;
+        let min = Self::MIN_INT as i64 & !Self::FRAC_MASK;
+        Self {
+            raw: raw.clamp(min, max) as i32,
+        }
+    }
+
+    /// Rounds down to the nearest integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_point::FixedSmall;
+    ///
+    /// let x = FixedSmall::<16, 8>::from_f32(1.75)?;
+    /// assert_eq!(x.floor().to_f32(), 1.0);
+    ///
+    /// let y = FixedSmall::<16, 8>::from_f32(-1.25)?;
+    /// assert_eq!(y.floor().to_f32(), -2.0);
+    /// # Ok::<(), fixed_point::FixedPointError>(())
+    /// ```
+    pub fn floor(self) -> Self {
+        Self {
+            raw: (self.raw >> F) << F,
+        }
+    }
+
+    /// Rounds up to the nearest integer, saturating to the largest
+    /// representable integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_point::FixedSmall;
+    ///
+    /// let x = FixedSmall::<16, 8>::from_f32(1.25)?;
+    /// assert_eq!(x.ceil().to_f32(), 2.0);
+    ///
+    /// let y = FixedSmall::<16, 8>::from_f32(-1.25)?;
+    /// assert_eq!(y.ceil().to_f32(), -1.0);
+    /// # Ok::<(), fixed_point::FixedPointError>(())
+    /// ```
+    pub fn ceil(self) -> Self {
+        Self::saturate_whole((self.raw as i64 + Self::FRAC_MASK) & !Self::FRAC_MASK)
+    }
+
+    /// Rounds to the nearest integer, with ties rounding away from zero
+    /// (matching `f32::round`), saturating to the largest representable integer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_point::FixedSmall;
+    ///
+    /// let x = FixedSmall::<16, 8>::from_f32(2.5)?;
+    /// assert_eq!(x.round().to_f32(), 3.0);
+    ///
+    /// let y = FixedSmall::<16, 8>::from_f32(-2.5)?;
+    /// assert_eq!(y.round().to_f32(), -3.0);
+    /// # Ok::<(), fixed_point::FixedPointError>(())
+    /// ```
+    pub fn round(self) -> Self {
+        if F == 0 {
+            return self;
+        }
+        let half = 1i64 << (F - 1);
+        let raw = self.raw as i64;
+        let rounded = if raw >= 0 {
+            (raw + half) & !Self::FRAC_MASK
+        } else {
+            -((-raw + half) & !Self::FRAC_MASK)
+        };
+        Self::saturate_whole(rounded)
+    }
+}
+
+impl<const N: usize, const F: usize> Default for FixedSmall<N, F> {
+    /// Returns [`zero`](Self::zero).
+    fn default() -> Self {
+        Self::zero()
+    }
+}
+
+// Operator overloads, delegating to the saturating methods above
+impl<const N: usize, const F: usize> core::ops::Add for FixedSmall<N, F> {
+    type Output = Self;
+
+    fn add(self, rhs: Self) -> Self {
+        FixedSmall::add(self, rhs)
+    }
+}
+
+impl<const N: usize, const F: usize> core::ops::Sub for FixedSmall<N, F> {
+    type Output = Self;
+
+    fn sub(self, rhs: Self) -> Self {
+        FixedSmall::sub(self, rhs)
+    }
+}
+
+impl<const N: usize, const F: usize> core::ops::Mul for FixedSmall<N, F> {
+    type Output = Self;
+
+    fn mul(self, rhs: Self) -> Self {
+        FixedSmall::mul(self, rhs)
+    }
+}
+
+impl<const N: usize, const F: usize> core::ops::Div for FixedSmall<N, F> {
+    type Output = Self;
+
+    fn div(self, rhs: Self) -> Self {
+        FixedSmall::div(self, rhs)
+    }
+}
+
+impl<const N: usize, const F: usize> core::ops::Neg for FixedSmall<N, F> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        FixedSmall::neg(self)
+    }
+}
+
+impl<const N: usize, const F: usize> core::ops::AddAssign for FixedSmall<N, F> {
+    fn add_assign(&mut self, rhs: Self) {
+        *self = FixedSmall::add(*self, rhs);
+    }
+}
+
+impl<const N: usize, const F: usize> core::ops::SubAssign for FixedSmall<N, F> {
+    fn sub_assign(&mut self, rhs: Self) {
+        *self = FixedSmall::sub(*self, rhs);
+    }
+}
+
+impl<const N: usize, const F: usize> core::ops::MulAssign for FixedSmall<N, F> {
+    fn mul_assign(&mut self, rhs: Self) {
+        *self = FixedSmall::mul(*self, rhs);
+    }
+}
+
+impl<const N: usize, const F: usize> core::ops::DivAssign for FixedSmall<N, F> {
+    fn div_assign(&mut self, rhs: Self) {
+        *self = FixedSmall::div(*self, rhs);
+    }
+}
+
+impl<const N: usize, const F: usize> FixedSmall<N, F> {
+    /// Default number of fractional digits shown by `Display`: the
+    /// `F * log10(2)` meaningful decimal places, but at least one when `F > 0`.
+    const DISPLAY_DIGITS: usize = if F == 0 {
+        0
+    } else if F * 30103 / 100_000 == 0 {
+        1
+    } else {
+        F * 30103 / 100_000
+    };
+
+    /// Writes `int_part`, then `frac_digits` (ASCII) and `zeros` trailing
+    /// zeros after a decimal point if there are any.
+    fn write_decimal(
+        out: &mut impl core::fmt::Write,
+        int_part: u64,
+        frac_digits: &[u8],
+        zeros: usize,
+    ) -> core::fmt::Result {
+        write!(out, "{int_part}")?;
+        if frac_digits.len() + zeros > 0 {
+            out.write_char('.')?;
+            for &d in frac_digits {
+                out.write_char(d as char)?;
+            }
+            for _ in 0..zeros {
+                out.write_char('0')?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Raw magnitude of `int_part.frac_digits`, rounded half away from zero
+    /// like `from_f32`. `frac_digits` are ASCII digits.
+    fn decimal_magnitude(int_part: u128, frac_digits: &[u8]) -> u128 {
+        // Later fraction digits are ignored; they are worth less than 2^-89
+        // and keep `frac << F` within u128
+        const MAX_FRAC_DIGITS: usize = 27;
+
+        let kept = &frac_digits[..frac_digits.len().min(MAX_FRAC_DIGITS)];
+        let mut frac: u128 = 0;
+        for b in kept {
+            frac = frac * 10 + (b - b'0') as u128;
+        }
+        let denom = 10u128.pow(kept.len() as u32);
+
+        let scaled = frac << F;
+        let mut frac_raw = scaled / denom;
+        if (scaled % denom) * 2 >= denom {
+            frac_raw += 1;
+        }
+        (int_part << F) + frac_raw
+    }
+}
+
+/// Fixed-capacity ASCII buffer for `Display`; writes past the end fail.
+struct StackStr {
+    bytes: [u8; 64],
+    len: usize,
+}
+
+impl StackStr {
+    fn new() -> Self {
+        Self {
+            bytes: [0; 64],
+            len: 0,
+        }
+    }
+
+    fn as_str(&self) -> &str {
+        // Only ASCII is ever written
+        core::str::from_utf8(&self.bytes[..self.len]).unwrap_or_default()
+    }
+}
+
+impl core::fmt::Write for StackStr {
+    fn write_str(&mut self, s: &str) -> core::fmt::Result {
+        let end = self.len + s.len();
+        self.bytes
+            .get_mut(self.len..end)
+            .ok_or(core::fmt::Error)?
+            .copy_from_slice(s.as_bytes());
+        self.len = end;
+        Ok(())
+    }
+}
+
+/// Formats the exact decimal value, rounded half-to-even to the requested
+/// precision, or by default to the `F * log10(2)` meaningful places (at
+/// least one when `F > 0`).
+///
+/// Only integer arithmetic is used, so the output matches formatting
+/// `to_f64()` with the same precision, except that a value is rounded
+/// towards zero instead when rounding up would print a number `FromStr`
+/// rejects as out of range. Width, fill, alignment, `+` and `0` flags are
+/// honoured as for integers.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_point::FixedSmall;
+///
+/// let x = FixedSmall::<16, 8>::from_f32(3.14)?;
+/// assert_eq!(x.to_string(), "3.14");
+/// assert_eq!(format!("{x:.4}"), "3.1406");
+/// assert_eq!(format!("{x:>7}"), "   3.14");
+///
+/// // 127.996 would round to 128.00, which does not fit
+/// assert_eq!(FixedSmall::<16, 8>::max_value().to_string(), "127.99");
+/// # Ok::<(), fixed_point::FixedPointError>(())
+/// ```
+impl<const N: usize, const F: usize> core::fmt::Display for FixedSmall<N, F> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let digits = f.precision().unwrap_or(Self::DISPLAY_DIGITS);
+        let magnitude = self.raw.unsigned_abs() as u64;
+        let mut int_part = magnitude >> F;
+        let mut frac = magnitude & ((1u64 << F) - 1);
+
+        // A fraction with F binary digits has exactly F decimal digits
+        let exact = digits.min(F);
+        let mut buf = [b'0'; 32];
+        for d in buf.iter_mut().take(exact) {
+            frac *= 10;
+            *d = b'0' + (frac >> F) as u8;
+            frac &= (1u64 << F) - 1;
+        }
+
+        // Round half to even on the remaining fraction
+        let half = if F == 0 { 1 } else { 1u64 << (F - 1) };
+        let last_odd = match exact {
+            0 => int_part % 2 == 1,
+            n => buf[n - 1] % 2 == 1,
+        };
+        if frac > half || (frac == half && last_odd) {
+            let truncated = (int_part, buf);
+            let mut carry = true;
+            for d in buf[..exact].iter_mut().rev() {
+                if *d == b'9' {
+                    *d = b'0';
+                } else {
+                    *d += 1;
+                    carry = false;
+                    break;
+                }
+            }
+            if carry {
+                int_part += 1;
+            }
+
+            // Keep the printed value parseable
+            let limit = if self.raw < 0 {
+                Self::MIN_INT.unsigned_abs() as u128
+            } else {
+                Self::MAX_INT as u128
+            };
+            if Self::decimal_magnitude(int_part as u128, &buf[..exact]) > limit {
+                (int_part, buf) = truncated;
+            }
+        }
+
+        // Only very long precisions overflow the stack buffer
+        let mut out = StackStr::new();
+        if Self::write_decimal(&mut out, int_part, &buf[..exact], digits - exact).is_ok() {
+            return f.pad_integral(self.raw >= 0, "", out.as_str());
+        }
+        let mut out = String::new();
+        Self::write_decimal(&mut out, int_part, &buf[..exact], digits - exact)?;
+        f.pad_integral(self.raw >= 0, "", &out)
+    }
+}
+
+/// Parses a decimal literal such as `"3.14"`, `"-2"` or `"+.5"`, rounding to
+/// the nearest representable value.
+///
+/// # Errors
+///
+/// Returns `FixedPointError::ParseError` for malformed input and
+/// `FixedPointError::Overflow` if the value is out of range.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_point::FixedSmall;
+///
+/// let x: FixedSmall<16, 8> = "3.14".parse()?;
+/// assert_eq!(x, FixedSmall::from_f32(3.14)?);
+///
+/// assert!("1.2.3".parse::<FixedSmall<16, 8>>().is_err());
+/// # Ok::<(), fixed_point::FixedPointError>(())
+/// ```
+impl<const N: usize, const F: usize> core::str::FromStr for FixedSmall<N, F> {
+    type Err = FixedPointError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let malformed = || FixedPointError::ParseError(s.to_string());
+        let overflow = || FixedPointError::Overflow {
+            value: s.parse().unwrap_or(f32::INFINITY),
+            bits: N,
+            fractional: F,
+        };
+
+        let (negative, unsigned) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        let (int_str, frac_str) = unsigned.split_once('.').unwrap_or((unsigned, ""));
+        let all_digits = |part: &str| part.bytes().all(|b| b.is_ascii_digit());
+        if int_str.is_empty() && frac_str.is_empty()
+            || !all_digits(int_str)
+            || !all_digits(frac_str)
+        {
+            return Err(malformed());
+        }
+
+        let mut int_part: u128 = 0;
+        for b in int_str.bytes() {
+            int_part = int_part
+                .checked_mul(10)
+                .and_then(|v| v.checked_add((b - b'0') as u128))
+                .filter(|&v| v <= u32::MAX as u128)
+                .ok_or_else(overflow)?;
+        }
+
+        let magnitude = Self::decimal_magnitude(int_part, frac_str.as_bytes());
+        let raw = if negative {
+            -(magnitude as i128)
+        } else {
+            magnitude as i128
+        };
+        if raw < Self::MIN_INT as i128 || raw > Self::MAX_INT as i128 {
+            return Err(overflow());
+        }
+        Ok(Self { raw: raw as i32 })
+    }
+}
+
+/// Serializes as an `f64`, which represents every value exactly.
+#[cfg(feature = "serde")]
+impl<const N: usize, const F: usize> serde::Serialize for FixedSmall<N, F> {
+    fn serialize<S: serde::Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_f64(self.to_f64())
+    }
+}
+
+/// Deserializes from a number via [`FixedSmall::from_f64`], rejecting
+/// values outside the representable range.
+#[cfg(feature = "serde")]
+impl<'de, const N: usize, const F: usize> serde::Deserialize<'de> for FixedSmall<N, F> {
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let value = f64::deserialize(deserializer)?;
+        Self::from_f64(value).map_err(serde::de::Error::custom)
+    }
 }
 
 
@@ -239,6 +894,47 @@ mod tests {
         assert!(result.is_err());
     }
 
+    #[test]
+    fn test_f64_roundtrip_where_f32_loses_precision() {
+        // 30000 + 2^-16 needs 31 significant bits
+        let raw = (30000 << 16) + 1;
+        let x = FixedSmall::<32, 16>::from_raw(raw);
+
+        let via_f32 = FixedSmall::<32, 16>::from_f32(x.to_f32()).unwrap();
+        assert_ne!(via_f32.raw_value(), raw);
+
+        let via_f64 = FixedSmall::<32, 16>::from_f64(x.to_f64()).unwrap();
+        assert_eq!(via_f64.raw_value(), raw);
+    }
+
+    #[test]
+    fn test_from_f32_full_width() {
+        type Fx = FixedSmall<32, 0>;
+        assert!(Fx::from_f32(f32::MAX).is_err());
+        assert!(Fx::from_f32(f32::NEG_INFINITY).is_err());
+        assert!(Fx::from_f32(f32::INFINITY).is_err());
+        assert!(Fx::from_f32(f32::NAN).is_err());
+
+        // 2^31 is the nearest f32 to i32::MAX, and is out of range
+        assert!(Fx::from_f32(2_147_483_648.0).is_err());
+        // The largest f32 below 2^31 is representable exactly
+        assert_eq!(
+            Fx::from_f32(2_147_483_520.0).unwrap().raw_value(),
+            2_147_483_520
+        );
+        assert_eq!(Fx::from_f32(-2_147_483_648.0).unwrap(), Fx::min_value());
+    }
+
+    #[test]
+    fn test_from_f64_overflow() {
+        assert!(FixedSmall::<8, 4>::from_f64(100.0).is_err());
+        assert!(FixedSmall::<8, 4>::from_f64(-100.0).is_err());
+        assert!(FixedSmall::<32, 16>::from_f64(40000.0).is_err());
+        assert!(FixedSmall::<32, 16>::from_f64(f64::NAN).is_err());
+        assert!(FixedSmall::<32, 16>::from_f64(f64::INFINITY).is_err());
+        assert!(FixedSmall::<32, 16>::from_f64(f64::NEG_INFINITY).is_err());
+    }
+
     #[test]
     fn test_zero_and_one() {
         let zero = FixedSmall::<16, 8>::zero();
@@ -248,6 +944,15 @@ mod tests {
         assert_eq!(one.to_f32(), 1.0);
     }
 
+    #[test]
+    fn test_default_is_zero() {
+        assert_eq!(FixedSmall::<16, 8>::default().to_f32(), 0.0);
+
+        let mut values = Vec::new();
+        values.resize_with(5, FixedSmall::<16, 8>::default);
+        assert!(values.iter().all(|v| *v == FixedSmall::zero()));
+    }
+
     #[test]
     fn test_add() {
         let x = FixedSmall::<16, 8>::from_f32(1.5).unwrap();
@@ -264,6 +969,51 @@ mod tests {
         assert!((diff.to_f32() - 2.0).abs() < 0.01);
     }
 
+    #[test]
+    fn test_wrapping_arithmetic() {
+        type Fx = FixedSmall<16, 8>;
+        let max = Fx::max_value();
+        let min = Fx::min_value();
+
+        assert_eq!(max.wrapping_add(Fx::from_raw(1)), min);
+        assert_eq!(
+            max.wrapping_add(Fx::one()).raw_value(),
+            min.raw_value() + 255
+        );
+        assert_eq!(min.wrapping_sub(Fx::from_raw(1)), max);
+
+        // 64.0 * 4.0 = 256.0 wraps to 0.0 with 8 integer bits
+        let big = Fx::from_f32(64.0).unwrap();
+        assert_eq!(big.wrapping_mul(Fx::from_f32(4.0).unwrap()), Fx::zero());
+
+        // Within range, wrapping and saturating agree
+        let a = Fx::from_f32(1.5).unwrap();
+        let b = Fx::from_f32(-2.25).unwrap();
+        assert_eq!(a.wrapping_add(b), a.add(b));
+        assert_eq!(a.wrapping_sub(b), a.sub(b));
+        assert_eq!(a.wrapping_mul(b), a.mul(b));
+
+        // Full-width formats wrap at 32 bits
+        let max32 = FixedSmall::<32, 16>::max_value();
+        assert_eq!(
+            max32.wrapping_add(FixedSmall::from_raw(1)),
+            FixedSmall::min_value()
+        );
+    }
+
+    #[test]
+    fn test_checked_arithmetic() {
+        type Fx = FixedSmall<16, 8>;
+        assert_eq!(Fx::max_value().checked_add(Fx::from_raw(1)), None);
+        assert_eq!(Fx::min_value().checked_sub(Fx::from_raw(1)), None);
+        let hundred = Fx::from_f32(100.0).unwrap();
+        assert_eq!(hundred.checked_mul(Fx::from_f32(2.0).unwrap()), None);
+        assert_eq!(
+            Fx::from_f32(1.5).unwrap().checked_add(Fx::one()),
+            Some(Fx::from_f32(2.5).unwrap())
+        );
+    }
+
     #[test]
     fn test_mul() {
         let x = FixedSmall::<16, 8>::from_f32(2.0).unwrap();
@@ -272,6 +1022,311 @@ mod tests {
         assert!((product.to_f32() - 6.0).abs() < 0.01);
     }
 
+    #[test]
+    fn test_div_exact() {
+        let x = FixedSmall::<16, 8>::from_f32(6.0).unwrap();
+        let y = FixedSmall::<16, 8>::from_f32(-4.0).unwrap();
+        assert_eq!(x.div(y).to_f32(), -1.5);
+        assert_eq!((x / y).to_f32(), -1.5);
+    }
+
+    #[test]
+    fn test_div_truncates_toward_zero() {
+        let one = FixedSmall::<16, 8>::one();
+        let three = FixedSmall::<16, 8>::from_f32(3.0).unwrap();
+        // 256 / 3 = 85.33..., truncated to 85
+        assert_eq!(one.div(three).raw_value(), 85);
+        assert_eq!(one.neg().div(three).raw_value(), -85);
+    }
+
+    #[test]
+    fn test_div_by_zero() {
+        let x = FixedSmall::<16, 8>::from_f32(2.0).unwrap();
+        let zero = FixedSmall::<16, 8>::zero();
+        assert!(x.checked_div(zero).is_none());
+        assert_eq!(x.div(zero), FixedSmall::max_value());
+        assert_eq!(x.neg().div(zero), FixedSmall::min_value());
+        assert_eq!(zero.div(zero), FixedSmall::max_value());
+    }
+
+    #[test]
+    fn test_div_overflow_saturates() {
+        let big = FixedSmall::<16, 8>::from_f32(100.0).unwrap();
+        let tiny = FixedSmall::<16, 8>::from_raw(1);
+        assert_eq!(big.div(tiny), FixedSmall::max_value());
+        assert_eq!(big.div(tiny.neg()), FixedSmall::min_value());
+        assert_eq!(big.checked_div(tiny), Some(FixedSmall::max_value()));
+    }
+
+    #[test]
+    fn test_operators_match_methods() {
+        let x = FixedSmall::<16, 8>::from_f32(5.25).unwrap();
+        let y = FixedSmall::<16, 8>::from_f32(-1.5).unwrap();
+
+        assert_eq!(x + y, x.add(y));
+        assert_eq!(x - y, x.sub(y));
+        assert_eq!(x * y, x.mul(y));
+        assert_eq!(x / y, x.div(y));
+        assert_eq!(-x, x.neg());
+
+        let max = FixedSmall::<16, 8>::max_value();
+        assert_eq!(max + max, max.add(max));
+        assert_eq!(-FixedSmall::<16, 8>::min_value(), FixedSmall::<16, 8>::min_value().neg());
+    }
+
+    #[test]
+    fn test_assign_operators() {
+        let x = FixedSmall::<16, 8>::from_f32(5.25).unwrap();
+        let y = FixedSmall::<16, 8>::from_f32(-1.5).unwrap();
+
+        let mut z = x;
+        z += y;
+        assert_eq!(z, x.add(y));
+
+        let mut z = x;
+        z -= y;
+        assert_eq!(z, x.sub(y));
+
+        let mut z = x;
+        z *= y;
+        assert_eq!(z, x.mul(y));
+
+        let mut z = x;
+        z /= y;
+        assert_eq!(z, x.div(y));
+    }
+
+    #[test]
+    fn test_lerp_midpoint() {
+        let a = FixedSmall::<16, 8>::zero();
+        let b = FixedSmall::<16, 8>::one();
+        let t = FixedSmall::<16, 8>::from_f32(0.5).unwrap();
+        let lsb = 1.0 / 256.0;
+        assert!((FixedSmall::lerp(a, b, t).to_f32() - 0.5).abs() <= lsb);
+    }
+
+    #[test]
+    fn test_lerp_saturates() {
+        let a = FixedSmall::<16, 8>::min_value();
+        let b = FixedSmall::<16, 8>::max_value();
+        let t = FixedSmall::<16, 8>::from_f32(2.0).unwrap();
+        assert_eq!(FixedSmall::lerp(a, b, t), FixedSmall::max_value());
+        assert_eq!(FixedSmall::lerp(b, a, t), FixedSmall::min_value());
+    }
+
+    #[test]
+    fn test_floor() {
+        let fx = |v: f32| FixedSmall::<16, 8>::from_f32(v).unwrap();
+        assert_eq!(fx(1.75).floor().to_f32(), 1.0);
+        assert_eq!(fx(2.0).floor().to_f32(), 2.0);
+        assert_eq!(fx(-1.25).floor().to_f32(), -2.0);
+        assert_eq!(fx(-3.0).floor().to_f32(), -3.0);
+        assert_eq!(FixedSmall::<16, 8>::min_value().floor().to_f32(), -128.0);
+    }
+
+    #[test]
+    fn test_ceil() {
+        let fx = |v: f32| FixedSmall::<16, 8>::from_f32(v).unwrap();
+        assert_eq!(fx(1.25).ceil().to_f32(), 2.0);
+        assert_eq!(fx(2.0).ceil().to_f32(), 2.0);
+        assert_eq!(fx(-1.25).ceil().to_f32(), -1.0);
+        assert_eq!(fx(-0.5).ceil().to_f32(), 0.0);
+        // 128.0 is not representable, so ceil saturates to the largest integer
+        assert_eq!(FixedSmall::<16, 8>::max_value().ceil().to_f32(), 127.0);
+    }
+
+    #[test]
+    fn test_round() {
+        let fx = |v: f32| FixedSmall::<16, 8>::from_f32(v).unwrap();
+        assert_eq!(fx(1.25).round().to_f32(), 1.0);
+        assert_eq!(fx(1.5).round().to_f32(), 2.0);
+        assert_eq!(fx(1.75).round().to_f32(), 2.0);
+        assert_eq!(fx(-1.25).round().to_f32(), -1.0);
+        assert_eq!(fx(-1.5).round().to_f32(), -2.0);
+        assert_eq!(fx(-1.75).round().to_f32(), -2.0);
+        assert_eq!(FixedSmall::<16, 8>::max_value().round().to_f32(), 127.0);
+        assert_eq!(FixedSmall::<16, 8>::min_value().round().to_f32(), -128.0);
+    }
+
+    #[test]
+    fn test_integer_conversions() {
+        let three = FixedSmall::<16, 8>::from_integer(3);
+        assert_eq!(three.to_f32(), 3.0);
+        assert_eq!(three.to_integer(), 3);
+        assert_eq!(three.fractional_part(), FixedSmall::zero());
+
+        assert_eq!(FixedSmall::<16, 8>::from_integer(-128), FixedSmall::min_value());
+        assert_eq!(FixedSmall::<16, 8>::max_value().to_integer(), 127);
+        assert!(FixedSmall::<16, 8>::try_from_integer(128).is_err());
+        assert!(FixedSmall::<16, 8>::try_from_integer(-129).is_err());
+        assert_eq!(FixedSmall::<32, 0>::from_integer(i32::MIN).to_integer(), i32::MIN);
+    }
+
+    #[test]
+    #[should_panic(expected = "out of range")]
+    fn test_from_integer_overflow() {
+        FixedSmall::<8, 4>::from_integer(8);
+    }
+
+    #[test]
+    fn test_rounding_without_fraction() {
+        let x = FixedSmall::<8, 0>::from_raw(-7);
+        assert_eq!(x.floor(), x);
+        assert_eq!(x.ceil(), x);
+        assert_eq!(x.round(), x);
+    }
+
+    #[test]
+    fn test_display_matches_f32_formatting() {
+        // Near the maximum, rounding up would leave the range and is clamped
+        let in_range = |s: &str| s.parse::<FixedSmall<16, 8>>().is_ok();
+        for raw in i16::MIN..=i16::MAX {
+            let x = FixedSmall::<16, 8>::from_raw(raw as i32);
+            for expected in [
+                format!("{:.2}", x.to_f32()),
+                format!("{:.5}", x.to_f32()),
+                format!("{:.0}", x.to_f32()),
+            ] {
+                let digits = expected.split_once('.').map_or(0, |(_, d)| d.len());
+                let shown = format!("{x:.digits$}");
+                if in_range(&expected) {
+                    assert_eq!(shown, expected);
+                } else {
+                    assert!(in_range(&shown), "{shown}");
+                    assert!(shown < expected, "{shown} vs {expected}");
+                }
+            }
+        }
+        for raw in -128..=127 {
+            let x = FixedSmall::<8, 4>::from_raw(raw);
+            assert_eq!(x.to_string(), format!("{:.1}", x.to_f32()));
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let fx = |v: f32| FixedSmall::<16, 8>::from_f32(v).unwrap();
+        assert_eq!(fx(3.14).to_string(), "3.14");
+        assert_eq!(fx(-0.5).to_string(), "-0.50");
+        assert_eq!(fx(0.0).to_string(), "0.00");
+        assert_eq!(format!("{:.10}", fx(1.5)), "1.5000000000");
+        assert_eq!(FixedSmall::<8, 0>::from_raw(-7).to_string(), "-7");
+        assert_eq!(FixedSmall::<32, 16>::min_value().to_string(), "-32768.0000");
+        assert_eq!(FixedSmall::<32, 31>::max_value().to_string(), "0.999999999");
+    }
+
+    #[test]
+    fn test_display_padding() {
+        let x = FixedSmall::<16, 8>::from_f32(-3.14).unwrap();
+        assert_eq!(format!("{x:>8}"), "   -3.14");
+        assert_eq!(format!("{x:<8}|"), "-3.14   |");
+        assert_eq!(format!("{x:*^9.1}"), "**-3.1***");
+        assert_eq!(format!("{x:08.3}"), "-003.141");
+        assert_eq!(format!("{:+}", FixedSmall::<16, 8>::one()), "+1.00");
+    }
+
+    #[test]
+    fn test_display_long_precision() {
+        // Longer than the stack buffer
+        let x = FixedSmall::<32, 31>::min_value();
+        assert_eq!(format!("{x:.80}"), format!("{:.80}", x.to_f64()));
+        assert_eq!(format!("{x:>90.80}"), format!("{:>90.80}", x.to_f64()));
+        let x = FixedSmall::<32, 0>::min_value();
+        assert_eq!(format!("{x:.51}"), format!("{:.51}", x.to_f64()));
+    }
+
+    #[test]
+    fn test_display_round_trips_extremes() {
+        fn check<const N: usize, const F: usize>() {
+            for x in [FixedSmall::<N, F>::max_value(), FixedSmall::<N, F>::min_value()] {
+                for digits in 0..=F + 1 {
+                    let shown = format!("{x:.digits$}");
+                    assert!(shown.parse::<FixedSmall<N, F>>().is_ok(), "<{N}, {F}> {shown}");
+                }
+                assert!(x.to_string().parse::<FixedSmall<N, F>>().is_ok());
+            }
+        }
+        check::<16, 8>();
+        check::<8, 4>();
+        check::<8, 8>();
+        check::<32, 16>();
+        check::<32, 31>();
+        check::<32, 32>();
+        check::<32, 0>();
+        assert_eq!(FixedSmall::<16, 8>::min_value().to_string(), "-128.00");
+    }
+
+    #[test]
+    fn test_from_str() {
+        let parse = |s: &str| s.parse::<FixedSmall<16, 8>>();
+        assert_eq!(parse("3.14").unwrap(), FixedSmall::from_f32(3.14).unwrap());
+        assert_eq!(parse("42").unwrap().to_f32(), 42.0);
+        assert_eq!(parse("-1.5").unwrap().to_f32(), -1.5);
+        assert_eq!(parse("+.25").unwrap().to_f32(), 0.25);
+        assert_eq!(parse("7.").unwrap().to_f32(), 7.0);
+        assert_eq!(parse("-0").unwrap(), FixedSmall::zero());
+        assert_eq!(parse("-0.0").unwrap(), FixedSmall::zero());
+        // Rounds to nearest, ties away from zero
+        assert_eq!(parse("0.001953125").unwrap().raw_value(), 1);
+        assert_eq!(parse("-0.001953125").unwrap().raw_value(), -1);
+        assert_eq!(parse("0.0019").unwrap().raw_value(), 0);
+        assert_eq!(
+            parse("0.0000000000000000000000000000001").unwrap(),
+            FixedSmall::zero()
+        );
+    }
+
+    #[test]
+    fn test_from_str_malformed() {
+        for input in ["", "-", "+", ".", "1.2.3", "abc", "1e3", " 1", "--1", "1_000", "0x10"] {
+            assert!(
+                matches!(
+                    input.parse::<FixedSmall<16, 8>>(),
+                    Err(FixedPointError::ParseError(ref s)) if s == input
+                ),
+                "{input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn test_from_str_overflow() {
+        let parse = |s: &str| s.parse::<FixedSmall<16, 8>>();
+        assert!(matches!(parse("128"), Err(FixedPointError::Overflow { .. })));
+        assert!(matches!(parse("-128.01"), Err(FixedPointError::Overflow { .. })));
+        assert!(matches!(parse("99999999999999999999999"), Err(FixedPointError::Overflow { .. })));
+        assert_eq!(parse("-128").unwrap(), FixedSmall::min_value());
+        assert_eq!(parse("127.99609375").unwrap(), FixedSmall::max_value());
+    }
+
+    #[test]
+    fn test_ordering() {
+        let one = FixedSmall::<16, 8>::from_f32(1.0).unwrap();
+        let two = FixedSmall::<16, 8>::from_f32(2.0).unwrap();
+        let neg = FixedSmall::<16, 8>::from_f32(-2.0).unwrap();
+
+        assert!(one < two);
+        assert!(neg < one);
+        assert_eq!(one.max(two), two);
+        assert_eq!(FixedSmall::<16, 8>::min_value().cmp(&neg), std::cmp::Ordering::Less);
+    }
+
+    #[test]
+    fn test_sort_matches_f32_order() {
+        let inputs = [3.5f32, -1.25, 0.0, 100.0, -50.5, 0.5];
+        let mut values: Vec<FixedSmall<16, 8>> = inputs
+            .iter()
+            .map(|&v| FixedSmall::from_f32(v).unwrap())
+            .collect();
+        values.sort();
+
+        let mut expected = inputs;
+        expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let sorted: Vec<f32> = values.iter().map(|v| v.to_f32()).collect();
+        assert_eq!(sorted, expected);
+    }
+
     #[test]
     fn test_neg() {
         let x = FixedSmall::<16, 8>::from_f32(3.5).unwrap();
@@ -321,6 +1376,14 @@ mod tests {
                 let yx = y.mul(x).to_f32();
                 prop_assert!((xy - yx).abs() < 0.1);
             }
+
+            #[test]
+            fn test_lerp_endpoints(a in any::<i16>(), b in any::<i16>()) {
+                let a = FixedSmall::<16, 8>::from_raw(a as i32);
+                let b = FixedSmall::<16, 8>::from_raw(b as i32);
+                prop_assert_eq!(FixedSmall::lerp(a, b, FixedSmall::zero()), a);
+                prop_assert_eq!(FixedSmall::lerp(a, b, FixedSmall::one()), b);
+            }
         }
     }
 }
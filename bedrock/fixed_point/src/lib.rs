@@ -68,7 +68,7 @@ pub use error::FixedPointError;
 /// assert_eq!(array.get(0).unwrap().to_f32(), 1.5);
 /// # Ok::<(), fixed_point::FixedPointError>(())
 /// ```
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 pub struct FixedPointArray<const N: usize, const F: usize> {
     container: FixedPointContainer<N, F>,
 }
@@ -126,6 +126,38 @@ impl<const N: usize, const F: usize> FixedPointArray<N, F> {
     pub fn push(&mut self, value: FixedSmall<N, F>) -> Result<(), FixedPointError> {
         self.container.push(value)
     }
+
+    /// Appends f32 values from an iterator, stopping at the first one that is
+    /// out of range.
+    ///
+    /// Values before the failing one remain appended.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if a value is out of range for the fixed-point format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_point::FixedPointArray;
+    ///
+    /// let mut array = FixedPointArray::<16, 8>::from_iter(vec![1.0])?;
+    /// array.try_extend(vec![2.0, 3.5])?;
+    /// assert_eq!(array.to_f32_vec(), vec![1.0, 2.0, 3.5]);
+    ///
+    /// assert!(array.try_extend(vec![4.0, 1000.0, 5.0]).is_err());
+    /// assert_eq!(array.len(), 4);
+    /// # Ok::<(), fixed_point::FixedPointError>(())
+    /// ```
+    pub fn try_extend<I>(&mut self, iter: I) -> Result<(), FixedPointError>
+    where
+        I: IntoIterator<Item = f32>,
+    {
+        for value in iter {
+            self.push(FixedSmall::from_f32(value)?)?;
+        }
+        Ok(())
+    }
     /// Returns the number of elements in the array.
     pub fn len(&self) -> usize {
         self.container.len()
@@ -170,6 +202,9 @@ impl<const N: usize, const F: usize> FixedPointArray<N, F> {
 
     /// Returns the raw bytes of the array for serialization.
     ///
+    /// With the `std_container` backend these are the native-endian `i32`
+    /// raw values, which [`from_raw_bytes`](Self::from_raw_bytes) reads back.
+    ///
     /// # Examples
     ///
     /// ```
@@ -186,6 +221,63 @@ impl<const N: usize, const F: usize> FixedPointArray<N, F> {
         self.container.as_bytes()
     }
 
+    /// Returns the raw bytes of the array; an alias of
+    /// [`as_bytes`](Self::as_bytes) named as the inverse of
+    /// [`from_raw_bytes`](Self::from_raw_bytes).
+    #[cfg(feature = "std_container")]
+    pub fn as_raw_bytes(&self) -> &[u8] {
+        self.as_bytes()
+    }
+
+    /// Creates a fixed-point array from raw bytes as produced by
+    /// [`as_raw_bytes`](Self::as_raw_bytes), e.g. files written by external
+    /// tools.
+    ///
+    /// The bytes need not be aligned.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FixedPointError::InvalidByteLength` if the length is not a
+    /// multiple of the element size, or `FixedPointError::Overflow` if a raw
+    /// value does not fit the `N`-bit format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_point::FixedPointArray;
+    ///
+    /// let array = FixedPointArray::<16, 8>::from_iter(vec![1.5, -2.25])?;
+    /// let restored = FixedPointArray::<16, 8>::from_raw_bytes(array.as_raw_bytes())?;
+    /// assert_eq!(restored.to_f32_vec(), vec![1.5, -2.25]);
+    /// # Ok::<(), fixed_point::FixedPointError>(())
+    /// ```
+    #[cfg(feature = "std_container")]
+    pub fn from_raw_bytes(bytes: &[u8]) -> Result<Self, FixedPointError> {
+        let element_size = core::mem::size_of::<FixedSmall<N, F>>();
+        if !bytes.len().is_multiple_of(element_size) {
+            return Err(FixedPointError::InvalidByteLength {
+                len: bytes.len(),
+                element_size,
+            });
+        }
+
+        let min = FixedSmall::<N, F>::min_value();
+        let max = FixedSmall::<N, F>::max_value();
+        let mut array = Self::with_capacity(bytes.len() / element_size);
+        for chunk in bytes.chunks_exact(element_size) {
+            let value: FixedSmall<N, F> = bytemuck::pod_read_unaligned(chunk);
+            if value < min || value > max {
+                return Err(FixedPointError::Overflow {
+                    value: value.to_f32(),
+                    bits: N,
+                    fractional: F,
+                });
+            }
+            array.push(value)?;
+        }
+        Ok(array)
+    }
+
     /// Creates a fixed-point array from an iterator of f32 values.
     ///
     /// # Errors
@@ -235,6 +327,47 @@ impl<const N: usize, const F: usize> FixedPointArray<N, F> {
             .collect()
     }
 
+    /// Creates a fixed-point array from an iterator of f64 values.
+    ///
+    /// Prefer this over [`from_iter`](Self::from_iter) for formats with more
+    /// than 24 bits, where f32 cannot represent every value exactly.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if any value is out of range for the fixed-point format.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_point::FixedPointArray;
+    ///
+    /// // 30000 + 2^-16 needs more mantissa bits than f32 has
+    /// let value = 30000.0 + 1.0 / 65536.0;
+    ///
+    /// let via_f32 = FixedPointArray::<32, 16>::from_iter(vec![value as f32])?;
+    /// assert_ne!(via_f32.to_f64_vec(), vec![value]);
+    ///
+    /// let via_f64 = FixedPointArray::<32, 16>::from_f64_iter(vec![value])?;
+    /// assert_eq!(via_f64.to_f64_vec(), vec![value]);
+    /// # Ok::<(), fixed_point::FixedPointError>(())
+    /// ```
+    pub fn from_f64_iter<I>(iter: I) -> Result<Self, FixedPointError>
+    where
+        I: IntoIterator<Item = f64>,
+    {
+        let mut array = Self::new();
+        for value in iter {
+            let fixed = FixedSmall::from_f64(value)?;
+            array.push(fixed)?;
+        }
+        Ok(array)
+    }
+
+    /// Converts the array to a vector of f64 values.
+    pub fn to_f64_vec(&self) -> Vec<f64> {
+        self.iter().map(|v| v.to_f64()).collect()
+    }
+
     /// Returns an iterator over the fixed-point values.
     ///
     /// # Examples
@@ -256,6 +389,402 @@ impl<const N: usize, const F: usize> FixedPointArray<N, F> {
             len: self.len(),
         }
     }
+
+    /// Returns an iterator over all overlapping windows of `size` elements.
+    ///
+    /// Mirrors [`slice::windows`]; each window is collected into a `Vec`
+    /// since not every backend exposes a slice. Yields nothing if the array
+    /// is shorter than `size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_point::FixedPointArray;
+    ///
+    /// let array = FixedPointArray::<16, 8>::from_iter(vec![1.0, 2.0, 3.0])?;
+    /// let windows: Vec<_> = array.windows(2).collect();
+    /// assert_eq!(windows.len(), 2);
+    /// assert_eq!(windows[1][0].to_f32(), 2.0);
+    /// # Ok::<(), fixed_point::FixedPointError>(())
+    /// ```
+    pub fn windows(&self, size: usize) -> impl Iterator<Item = Vec<FixedSmall<N, F>>> + '_ {
+        assert!(size > 0, "window size must be non-zero");
+        let count = (self.len() + 1).saturating_sub(size);
+        (0..count).map(move |start| self.collect_range(start, start + size))
+    }
+
+    /// Returns an iterator over non-overlapping chunks of `size` elements.
+    ///
+    /// Mirrors [`slice::chunks`]: the last chunk is shorter when `size` does
+    /// not divide the length.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_point::FixedPointArray;
+    ///
+    /// let array = FixedPointArray::<16, 8>::from_iter(vec![1.0, 2.0, 3.0])?;
+    /// let lengths: Vec<usize> = array.chunks(2).map(|c| c.len()).collect();
+    /// assert_eq!(lengths, vec![2, 1]);
+    /// # Ok::<(), fixed_point::FixedPointError>(())
+    /// ```
+    pub fn chunks(&self, size: usize) -> impl Iterator<Item = Vec<FixedSmall<N, F>>> + '_ {
+        assert!(size > 0, "chunk size must be non-zero");
+        let len = self.len();
+        (0..len)
+            .step_by(size)
+            .map(move |start| self.collect_range(start, (start + size).min(len)))
+    }
+
+    /// Collects the elements in `start..end` into a vector.
+    fn collect_range(&self, start: usize, end: usize) -> Vec<FixedSmall<N, F>> {
+        (start..end).filter_map(|i| self.get(i)).collect()
+    }
+
+    /// Returns the saturating sum of all elements, or zero for an empty array.
+    ///
+    /// The sum is accumulated in `i64` and saturated once at the end, so the
+    /// result does not depend on the order of the elements.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_point::FixedPointArray;
+    ///
+    /// let array = FixedPointArray::<16, 8>::from_iter(vec![1.5, 2.25, -0.75])?;
+    /// assert_eq!(array.sum().to_f32(), 3.0);
+    /// # Ok::<(), fixed_point::FixedPointError>(())
+    /// ```
+    pub fn sum(&self) -> FixedSmall<N, F> {
+        Self::saturate(self.raw_sum())
+    }
+
+    /// Returns the product of all elements, or one for an empty array.
+    ///
+    /// The running product is kept in an `i64` accumulator and saturated only
+    /// once at the end, so a large intermediate value that is scaled back into
+    /// range by later elements does not saturate early.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_point::FixedPointArray;
+    ///
+    /// let array = FixedPointArray::<16, 8>::from_iter(vec![2.0, 1.5, -0.5])?;
+    /// assert_eq!(array.product().to_f32(), -1.5);
+    /// # Ok::<(), fixed_point::FixedPointError>(())
+    /// ```
+    pub fn product(&self) -> FixedSmall<N, F> {
+        let product = self.iter().fold(FixedSmall::<N, F>::one().raw as i64, |acc, v| {
+            let wide = (acc as i128 * v.raw as i128) >> F;
+            wide.clamp(i64::MIN as i128, i64::MAX as i128) as i64
+        });
+        Self::saturate(product)
+    }
+
+    /// Returns the arithmetic mean of the elements, or `None` for an empty
+    /// array.
+    ///
+    /// The sum is divided before saturating, so the mean is exact (up to
+    /// truncation) even when [`sum`](Self::sum) would saturate.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_point::FixedPointArray;
+    ///
+    /// let array = FixedPointArray::<16, 8>::from_iter(vec![1.0, 2.0, 4.5])?;
+    /// assert_eq!(array.mean().unwrap().to_f32(), 2.5);
+    /// assert!(FixedPointArray::<16, 8>::new().mean().is_none());
+    /// # Ok::<(), fixed_point::FixedPointError>(())
+    /// ```
+    pub fn mean(&self) -> Option<FixedSmall<N, F>> {
+        self.raw_sum()
+            .checked_div(self.len() as i64)
+            .map(Self::saturate)
+    }
+
+    /// Returns the dot product of two arrays, saturating the final result.
+    ///
+    /// The full element-wise products are accumulated in `i128` and scaled
+    /// back by `F` once at the end, so no precision is lost per term and
+    /// intermediate sums may exceed the range of `FixedSmall<N, F>` without
+    /// saturating.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the arrays have different lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_point::FixedPointArray;
+    ///
+    /// let a = FixedPointArray::<16, 8>::from_iter(vec![1.0, 2.0, 3.0])?;
+    /// let b = FixedPointArray::<16, 8>::from_iter(vec![4.0, -5.0, 0.5])?;
+    /// assert_eq!(a.dot_product(&b).to_f32(), -4.5);
+    /// # Ok::<(), fixed_point::FixedPointError>(())
+    /// ```
+    pub fn dot_product(&self, other: &Self) -> FixedSmall<N, F> {
+        assert_eq!(
+            self.len(),
+            other.len(),
+            "dot_product requires arrays of equal length"
+        );
+        let total: i128 = self
+            .iter()
+            .zip(other.iter())
+            .map(|(a, b)| a.raw as i128 * b.raw as i128)
+            .sum();
+        let scaled = total >> F;
+        Self::saturate(scaled.clamp(i64::MIN as i128, i64::MAX as i128) as i64)
+    }
+
+    /// Returns a new array with the saturating sum of each pair of elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FixedPointError::LengthMismatch` if the arrays have different
+    /// lengths.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_point::FixedPointArray;
+    ///
+    /// let a = FixedPointArray::<16, 8>::from_iter(vec![1.0, 2.0])?;
+    /// let b = FixedPointArray::<16, 8>::from_iter(vec![0.5, -3.0])?;
+    /// assert_eq!(a.element_wise_add(&b)?.to_f32_vec(), vec![1.5, -1.0]);
+    /// # Ok::<(), fixed_point::FixedPointError>(())
+    /// ```
+    pub fn element_wise_add(&self, other: &Self) -> Result<Self, FixedPointError> {
+        self.zip_with(other, FixedSmall::add)
+    }
+
+    /// Returns a new array with the saturating difference of each pair of
+    /// elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FixedPointError::LengthMismatch` if the arrays have different
+    /// lengths.
+    pub fn element_wise_sub(&self, other: &Self) -> Result<Self, FixedPointError> {
+        self.zip_with(other, FixedSmall::sub)
+    }
+
+    /// Returns a new array with the saturating product of each pair of
+    /// elements.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FixedPointError::LengthMismatch` if the arrays have different
+    /// lengths.
+    pub fn element_wise_mul(&self, other: &Self) -> Result<Self, FixedPointError> {
+        self.zip_with(other, FixedSmall::mul)
+    }
+
+    /// Returns a new array with `f` applied to each element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_point::FixedPointArray;
+    ///
+    /// let a = FixedPointArray::<16, 8>::from_iter(vec![1.25, -2.75])?;
+    /// assert_eq!(a.map(|v| v.abs()).to_f32_vec(), vec![1.25, 2.75]);
+    /// # Ok::<(), fixed_point::FixedPointError>(())
+    /// ```
+    pub fn map(&self, f: impl Fn(FixedSmall<N, F>) -> FixedSmall<N, F>) -> Self {
+        let mut result = Self::with_capacity(self.len());
+        for value in self.iter() {
+            result
+                .push(f(value))
+                .expect("pushing a FixedSmall value cannot fail");
+        }
+        result
+    }
+
+    /// Returns the smallest element, or `None` for an empty array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_point::FixedPointArray;
+    ///
+    /// let array = FixedPointArray::<16, 8>::from_iter(vec![2.5, -1.0, 0.5])?;
+    /// assert_eq!(array.min().unwrap().to_f32(), -1.0);
+    /// assert_eq!(array.max().unwrap().to_f32(), 2.5);
+    /// # Ok::<(), fixed_point::FixedPointError>(())
+    /// ```
+    pub fn min(&self) -> Option<FixedSmall<N, F>> {
+        self.iter().min()
+    }
+
+    /// Returns the largest element, or `None` for an empty array.
+    pub fn max(&self) -> Option<FixedSmall<N, F>> {
+        self.iter().max()
+    }
+
+    /// Returns the index of the first smallest element, or `None` for an
+    /// empty array.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_point::FixedPointArray;
+    ///
+    /// let array = FixedPointArray::<16, 8>::from_iter(vec![2.5, -1.0, 2.5, -1.0])?;
+    /// assert_eq!(array.argmin(), Some(1));
+    /// assert_eq!(array.argmax(), Some(0));
+    /// # Ok::<(), fixed_point::FixedPointError>(())
+    /// ```
+    pub fn argmin(&self) -> Option<usize> {
+        self.iter()
+            .enumerate()
+            .min_by_key(|&(_, v)| v)
+            .map(|(i, _)| i)
+    }
+
+    /// Returns the index of the first largest element, or `None` for an
+    /// empty array.
+    pub fn argmax(&self) -> Option<usize> {
+        // `max_by_key` keeps the last maximum, so break ties on the index
+        self.iter()
+            .enumerate()
+            .max_by(|(i, a), (j, b)| a.cmp(b).then(j.cmp(i)))
+            .map(|(i, _)| i)
+    }
+
+    /// Clamps every element into `lo..=hi` in place.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `lo > hi`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_point::{FixedPointArray, FixedSmall};
+    ///
+    /// let mut array = FixedPointArray::<16, 8>::from_iter(vec![-3.0, 0.5, 7.0])?;
+    /// array.clamp_all(FixedSmall::from_f32(-1.0)?, FixedSmall::from_f32(1.0)?);
+    /// assert_eq!(array.to_f32_vec(), vec![-1.0, 0.5, 1.0]);
+    /// # Ok::<(), fixed_point::FixedPointError>(())
+    /// ```
+    pub fn clamp_all(&mut self, lo: FixedSmall<N, F>, hi: FixedSmall<N, F>) {
+        assert!(lo <= hi, "clamp_all requires lo <= hi");
+        match self.container.as_mut_slice() {
+            Some(slice) => slice.iter_mut().for_each(|v| *v = (*v).clamp(lo, hi)),
+            None => *self = self.map(|v| v.clamp(lo, hi)),
+        }
+    }
+
+    /// Combines two arrays of equal length element by element.
+    fn zip_with(
+        &self,
+        other: &Self,
+        f: impl Fn(FixedSmall<N, F>, FixedSmall<N, F>) -> FixedSmall<N, F>,
+    ) -> Result<Self, FixedPointError> {
+        if self.len() != other.len() {
+            return Err(FixedPointError::LengthMismatch {
+                expected: self.len(),
+                found: other.len(),
+            });
+        }
+
+        let mut result = Self::with_capacity(self.len());
+        for (a, b) in self.iter().zip(other.iter()) {
+            result.push(f(a, b))?;
+        }
+        Ok(result)
+    }
+
+    /// Sums the raw values without saturating.
+    fn raw_sum(&self) -> i64 {
+        self.iter().map(|v| v.raw as i64).sum()
+    }
+
+    /// Clamps a wide raw value into `FixedSmall<N, F>`.
+    fn saturate(raw: i64) -> FixedSmall<N, F> {
+        let min = FixedSmall::<N, F>::min_value().raw as i64;
+        let max = FixedSmall::<N, F>::max_value().raw as i64;
+        FixedSmall::from_raw(raw.clamp(min, max) as i32)
+    }
+
+    /// Sorts the array in ascending order.
+    ///
+    /// This sort is stable. Backends without slice access (such as
+    /// `packed_container`) extract the values, sort them and re-pack them,
+    /// using O(n) extra space.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_point::FixedPointArray;
+    ///
+    /// let mut array = FixedPointArray::<16, 8>::from_iter(vec![2.5, -1.0, 0.5])?;
+    /// array.sort();
+    /// assert_eq!(array.to_f32_vec(), vec![-1.0, 0.5, 2.5]);
+    /// # Ok::<(), fixed_point::FixedPointError>(())
+    /// ```
+    pub fn sort(&mut self) {
+        self.sort_with(|slice| slice.sort());
+    }
+
+    /// Sorts the array in ascending order without preserving the order of
+    /// equal elements.
+    pub fn sort_unstable(&mut self) {
+        self.sort_with(|slice| slice.sort_unstable());
+    }
+
+    /// Sorts the array with a comparator function.
+    ///
+    /// This sort is stable.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use fixed_point::FixedPointArray;
+    ///
+    /// let mut array = FixedPointArray::<16, 8>::from_iter(vec![2.5, -1.0, 0.5])?;
+    /// array.sort_by(|a, b| b.cmp(a));
+    /// assert_eq!(array.to_f32_vec(), vec![2.5, 0.5, -1.0]);
+    /// # Ok::<(), fixed_point::FixedPointError>(())
+    /// ```
+    pub fn sort_by<C>(&mut self, mut compare: C)
+    where
+        C: FnMut(&FixedSmall<N, F>, &FixedSmall<N, F>) -> core::cmp::Ordering,
+    {
+        self.sort_with(|slice| slice.sort_by(&mut compare));
+    }
+
+    /// Applies `sort` to the values, in place when the backend exposes a
+    /// mutable slice and via a temporary vector otherwise.
+    fn sort_with(&mut self, sort: impl FnOnce(&mut [FixedSmall<N, F>])) {
+        if let Some(slice) = self.container.as_mut_slice() {
+            sort(slice);
+            return;
+        }
+
+        let mut values: Vec<FixedSmall<N, F>> = self.iter().collect();
+        sort(&mut values);
+
+        let mut container = FixedPointContainer::with_capacity(values.len());
+        for value in values {
+            container
+                .push(value)
+                .expect("re-packing values read from the container cannot fail");
+        }
+        self.container = container;
+    }
 }
 
 impl<const N: usize, const F: usize> Default for FixedPointArray<N, F> {
@@ -264,6 +793,99 @@ impl<const N: usize, const F: usize> Default for FixedPointArray<N, F> {
     }
 }
 
+/// Appends f32 values, converting each with [`FixedSmall::from_f32`].
+///
+/// # Panics
+///
+/// Panics if a value is out of range for the fixed-point format. Use
+/// [`FixedPointArray::try_extend`] to handle that case instead.
+impl<const N: usize, const F: usize> Extend<f32> for FixedPointArray<N, F> {
+    fn extend<I: IntoIterator<Item = f32>>(&mut self, iter: I) {
+        if let Err(err) = self.try_extend(iter) {
+            panic!("cannot extend FixedPointArray: {err}");
+        }
+    }
+}
+
+/// Appends fixed-point values; this cannot fail.
+impl<const N: usize, const F: usize> Extend<FixedSmall<N, F>> for FixedPointArray<N, F> {
+    fn extend<I: IntoIterator<Item = FixedSmall<N, F>>>(&mut self, iter: I) {
+        for value in iter {
+            self.push(value)
+                .expect("pushing a FixedSmall value cannot fail");
+        }
+    }
+}
+
+/// Collects fixed-point values into an array.
+///
+/// # Panics
+///
+/// Panics if the underlying container fails to store a value, which the
+/// in-memory backends never do.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_point::{FixedPointArray, FixedSmall};
+///
+/// let values = FixedPointArray::<16, 8>::from_iter(vec![1.0, 2.0, 3.0])?;
+/// let scale = FixedSmall::<16, 8>::from_f32(0.5)?;
+///
+/// let scaled: FixedPointArray<16, 8> = values.iter().map(|v| v.mul(scale)).collect();
+/// assert_eq!(scaled.to_f32_vec(), vec![0.5, 1.0, 1.5]);
+/// # Ok::<(), fixed_point::FixedPointError>(())
+/// ```
+impl<const N: usize, const F: usize> FromIterator<FixedSmall<N, F>> for FixedPointArray<N, F> {
+    fn from_iter<I: IntoIterator<Item = FixedSmall<N, F>>>(iter: I) -> Self {
+        let mut array = Self::new();
+        array.extend(iter);
+        array
+    }
+}
+
+/// Collects fallible fixed-point conversions into a [`FixedPointArray`].
+///
+/// Named `try_collect_fixed` so it does not collide with the unstable
+/// `Iterator::try_collect`. It is equivalent to collecting into
+/// `Result<FixedPointArray<N, F>, FixedPointError>`.
+///
+/// # Examples
+///
+/// ```
+/// use fixed_point::{FixedPointTryCollect, FixedSmall};
+///
+/// let array = [1.0, 2.5, -3.75]
+///     .into_iter()
+///     .map(FixedSmall::<16, 8>::from_f32)
+///     .try_collect_fixed()?;
+/// assert_eq!(array.len(), 3);
+///
+/// let overflow = [1.0, 1000.0]
+///     .into_iter()
+///     .map(FixedSmall::<16, 8>::from_f32)
+///     .try_collect_fixed();
+/// assert!(overflow.is_err());
+/// # Ok::<(), fixed_point::FixedPointError>(())
+/// ```
+pub trait FixedPointTryCollect<const N: usize, const F: usize>: Iterator {
+    /// Collects the values, stopping at the first error.
+    fn try_collect_fixed(self) -> Result<FixedPointArray<N, F>, FixedPointError>;
+}
+
+impl<I, const N: usize, const F: usize> FixedPointTryCollect<N, F> for I
+where
+    I: Iterator<Item = Result<FixedSmall<N, F>, FixedPointError>>,
+{
+    fn try_collect_fixed(self) -> Result<FixedPointArray<N, F>, FixedPointError> {
+        let mut array = FixedPointArray::new();
+        for value in self {
+            array.push(value?)?;
+        }
+        Ok(array)
+    }
+}
+
 /// Iterator over fixed-point values in an array.
 pub struct FixedPointIter<'a, const N: usize, const F: usize> {
     container: &'a FixedPointContainer<N, F>,
@@ -352,4 +974,343 @@ mod tests {
             assert!((a - b).abs() < 0.01);
         }
     }
+
+    #[test]
+    fn test_extend() {
+        let mut a = FixedPointArray::<16, 8>::from_iter(vec![1.0, 2.0]).unwrap();
+        let b = FixedPointArray::<16, 8>::from_iter(vec![-0.5, 4.25]).unwrap();
+
+        a.extend(b.iter());
+        assert_eq!(a.to_f32_vec(), vec![1.0, 2.0, -0.5, 4.25]);
+
+        a.extend(vec![8.0f32, 0.125]);
+        assert_eq!(a.to_f32_vec(), vec![1.0, 2.0, -0.5, 4.25, 8.0, 0.125]);
+    }
+
+    #[test]
+    fn test_try_extend_stops_on_overflow() {
+        let mut array = FixedPointArray::<16, 8>::new();
+        let result = array.try_extend(vec![1.0, 2.0, 500.0, 3.0]);
+
+        assert!(matches!(result, Err(FixedPointError::Overflow { .. })));
+        assert_eq!(array.to_f32_vec(), vec![1.0, 2.0]);
+    }
+
+    #[test]
+    #[should_panic(expected = "cannot extend")]
+    fn test_extend_f32_panics_on_overflow() {
+        let mut array = FixedPointArray::<16, 8>::new();
+        array.extend(vec![1.0f32, 500.0]);
+    }
+
+    #[test]
+    fn test_collect() {
+        let array = FixedPointArray::<16, 8>::from_iter(vec![1.0, -2.0, 3.5]).unwrap();
+        let doubled: FixedPointArray<16, 8> = array.iter().map(|v| v.add(v)).collect();
+        assert_eq!(doubled.to_f32_vec(), vec![2.0, -4.0, 7.0]);
+
+        let empty: FixedPointArray<16, 8> = std::iter::empty().collect();
+        assert!(empty.is_empty());
+    }
+
+    #[test]
+    fn test_default_and_clone() {
+        assert!(FixedPointArray::<16, 8>::default().is_empty());
+
+        let array = FixedPointArray::<16, 8>::from_iter(vec![1.0, -2.0, 3.5]).unwrap();
+        let mut copy = array.clone();
+        copy.push(FixedSmall::from_f32(4.0).unwrap()).unwrap();
+        assert_eq!(array.to_f32_vec(), vec![1.0, -2.0, 3.5]);
+        assert_eq!(copy.to_f32_vec(), vec![1.0, -2.0, 3.5, 4.0]);
+    }
+
+    #[test]
+    fn test_try_collect_fixed() {
+        let ok = [0.25f32, 1.5]
+            .into_iter()
+            .map(FixedSmall::<16, 8>::from_f32)
+            .try_collect_fixed()
+            .unwrap();
+        assert_eq!(ok.to_f32_vec(), vec![0.25, 1.5]);
+
+        let err = [1.0f32, 500.0, 2.0]
+            .into_iter()
+            .map(FixedSmall::<16, 8>::from_f32)
+            .try_collect_fixed();
+        assert!(matches!(err, Err(FixedPointError::Overflow { .. })));
+
+        // Same behavior through the standard `Result` collector
+        let via_result: Result<FixedPointArray<16, 8>, _> = [1.0f32, 500.0]
+            .into_iter()
+            .map(FixedSmall::from_f32)
+            .collect();
+        assert!(via_result.is_err());
+    }
+
+    #[test]
+    fn test_windows_moving_average() {
+        let array = FixedPointArray::<16, 8>::from_iter(vec![1.0, 2.0, 6.0, 4.0, 8.0]).unwrap();
+
+        let averages: Vec<f32> = array
+            .windows(3)
+            .map(|w| w.iter().map(|v| v.to_f32()).sum::<f32>() / 3.0)
+            .collect();
+        assert_eq!(averages, vec![3.0, 4.0, 6.0]);
+
+        assert_eq!(array.windows(5).count(), 1);
+        assert_eq!(array.windows(6).count(), 0);
+    }
+
+    #[test]
+    fn test_chunks() {
+        let array = FixedPointArray::<16, 8>::from_iter(vec![1.0, 2.0, 3.0, 4.0, 5.0]).unwrap();
+
+        let chunks: Vec<Vec<f32>> = array
+            .chunks(2)
+            .map(|c| c.iter().map(|v| v.to_f32()).collect())
+            .collect();
+        assert_eq!(chunks, vec![vec![1.0, 2.0], vec![3.0, 4.0], vec![5.0]]);
+        assert_eq!(FixedPointArray::<16, 8>::new().chunks(3).count(), 0);
+    }
+
+    #[test]
+    #[should_panic(expected = "non-zero")]
+    fn test_windows_zero_size() {
+        let array = FixedPointArray::<16, 8>::from_iter(vec![1.0]).unwrap();
+        let _ = array.windows(0);
+    }
+
+    #[test]
+    fn test_min_max() {
+        let empty = FixedPointArray::<16, 8>::new();
+        assert!(empty.min().is_none());
+        assert!(empty.max().is_none());
+        assert!(empty.argmin().is_none());
+        assert!(empty.argmax().is_none());
+
+        let single = FixedPointArray::<16, 8>::from_iter(vec![4.5]).unwrap();
+        assert_eq!(single.min(), single.max());
+        assert_eq!(single.argmin(), Some(0));
+        assert_eq!(single.argmax(), Some(0));
+
+        let equal = FixedPointArray::<16, 8>::from_iter(vec![1.25; 4]).unwrap();
+        assert_eq!(equal.min().unwrap().to_f32(), 1.25);
+        assert_eq!(equal.argmin(), Some(0));
+        assert_eq!(equal.argmax(), Some(0));
+
+        let negative = FixedPointArray::<16, 8>::from_iter(vec![-3.0, -0.5, -7.25, -0.5]).unwrap();
+        assert_eq!(negative.min().unwrap().to_f32(), -7.25);
+        assert_eq!(negative.max().unwrap().to_f32(), -0.5);
+        assert_eq!(negative.argmin(), Some(2));
+        assert_eq!(negative.argmax(), Some(1));
+    }
+
+    #[test]
+    fn test_clamp_all() {
+        let mut array = FixedPointArray::<16, 8>::from_iter(vec![-100.0, -0.5, 0.0, 2.0, 50.0]).unwrap();
+        let lo = FixedSmall::from_f32(-1.0).unwrap();
+        let hi = FixedSmall::from_f32(1.5).unwrap();
+
+        array.clamp_all(lo, hi);
+        assert_eq!(array.to_f32_vec(), vec![-1.0, -0.5, 0.0, 1.5, 1.5]);
+    }
+
+    #[test]
+    fn test_raw_bytes_roundtrip() {
+        let array = FixedPointArray::<32, 16>::from_iter(vec![1.0, -0.5, 12345.125]).unwrap();
+        let bytes = array.as_raw_bytes().to_vec();
+        assert_eq!(bytes.len(), 12);
+
+        let restored = FixedPointArray::<32, 16>::from_raw_bytes(&bytes).unwrap();
+        assert_eq!(restored.to_f32_vec(), array.to_f32_vec());
+
+        // Misaligned input is read just as well
+        let mut shifted = vec![0u8];
+        shifted.extend_from_slice(&bytes);
+        let restored = FixedPointArray::<32, 16>::from_raw_bytes(&shifted[1..]).unwrap();
+        assert_eq!(restored.to_f32_vec(), array.to_f32_vec());
+    }
+
+    #[test]
+    fn test_from_raw_bytes_invalid() {
+        assert!(matches!(
+            FixedPointArray::<16, 8>::from_raw_bytes(&[0u8; 6]),
+            Err(FixedPointError::InvalidByteLength { len: 6, element_size: 4 })
+        ));
+
+        // 0x10000 does not fit in 16 bits
+        let bytes = 0x10000i32.to_ne_bytes();
+        assert!(matches!(
+            FixedPointArray::<16, 8>::from_raw_bytes(&bytes),
+            Err(FixedPointError::Overflow { .. })
+        ));
+        assert!(FixedPointArray::<16, 8>::from_raw_bytes(&[]).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_aggregates_empty() {
+        let array: FixedPointArray<16, 8> = FixedPointArray::new();
+        assert_eq!(array.sum(), FixedSmall::zero());
+        assert_eq!(array.product(), FixedSmall::one());
+        assert!(array.mean().is_none());
+    }
+
+    #[test]
+    fn test_aggregates() {
+        let array = FixedPointArray::<16, 8>::from_iter(vec![1.5, 2.5, -1.0, 3.0]).unwrap();
+        assert_eq!(array.sum().to_f32(), 6.0);
+        assert_eq!(array.product().to_f32(), -11.25);
+        assert_eq!(array.mean().unwrap().to_f32(), 1.5);
+    }
+
+    #[test]
+    fn test_aggregates_saturation() {
+        let array = FixedPointArray::<16, 8>::from_iter(vec![100.0, 100.0, -50.0]).unwrap();
+        // 150 is out of range for <16, 8>
+        assert_eq!(array.sum(), FixedSmall::max_value());
+        assert!((array.mean().unwrap().to_f32() - 50.0).abs() < 0.01);
+
+        // Intermediate sums may leave the range as long as the total does not
+        let array = FixedPointArray::<16, 8>::from_iter(vec![100.0, 100.0, -120.0]).unwrap();
+        assert_eq!(array.sum().to_f32(), 80.0);
+
+        // 100 * 100 is out of range, but the product is scaled back in
+        let array = FixedPointArray::<16, 8>::from_iter(vec![100.0, 100.0, 0.00390625]).unwrap();
+        assert_eq!(array.product().to_f32(), 39.0625);
+    }
+
+    #[test]
+    fn test_dot_product_unit_vectors() {
+        for degrees in [0.0f32, 30.0, 45.0, 90.0, 135.0, 180.0] {
+            let angle = degrees.to_radians();
+            let a = FixedPointArray::<32, 16>::from_iter(vec![1.0, 0.0]).unwrap();
+            let b = FixedPointArray::<32, 16>::from_iter(vec![angle.cos(), angle.sin()]).unwrap();
+            let dot = a.dot_product(&b).to_f32();
+            assert!((dot - angle.cos()).abs() < 1e-4, "{degrees}°: {dot}");
+        }
+    }
+
+    #[test]
+    fn test_dot_product_saturates() {
+        let a = FixedPointArray::<16, 8>::from_iter(vec![100.0, 100.0]).unwrap();
+        assert_eq!(a.dot_product(&a), FixedSmall::max_value());
+        assert_eq!(FixedPointArray::<16, 8>::new().dot_product(&FixedPointArray::new()), FixedSmall::zero());
+    }
+
+    #[test]
+    fn test_dot_product_keeps_precision() {
+        // Each product of one LSB with itself is below one LSB, but 256 of them are not
+        let mut a = FixedPointArray::<16, 8>::new();
+        for _ in 0..256 {
+            a.push(FixedSmall::from_raw(1)).unwrap();
+        }
+        assert_eq!(a.dot_product(&a), FixedSmall::from_raw(1));
+    }
+
+    #[test]
+    fn test_dot_product_full_width_extremes() {
+        let mut a = FixedPointArray::<32, 0>::new();
+        a.push(FixedSmall::min_value()).unwrap();
+        a.push(FixedSmall::max_value()).unwrap();
+        a.push(FixedSmall::min_value()).unwrap();
+        assert_eq!(a.dot_product(&a), FixedSmall::max_value());
+
+        let mut b = FixedPointArray::<32, 0>::new();
+        b.push(FixedSmall::max_value()).unwrap();
+        b.push(FixedSmall::min_value()).unwrap();
+        b.push(FixedSmall::max_value()).unwrap();
+        assert_eq!(a.dot_product(&b), FixedSmall::min_value());
+    }
+
+    #[test]
+    #[should_panic(expected = "equal length")]
+    fn test_dot_product_length_mismatch() {
+        let a = FixedPointArray::<16, 8>::from_iter(vec![1.0, 2.0]).unwrap();
+        let b = FixedPointArray::<16, 8>::from_iter(vec![1.0]).unwrap();
+        a.dot_product(&b);
+    }
+
+    #[test]
+    fn test_f64_conversions() {
+        let values = vec![1.0, -2.5, 0.0000152587890625, 32767.5];
+        let array = FixedPointArray::<32, 16>::from_f64_iter(values.clone()).unwrap();
+        assert_eq!(array.to_f64_vec(), values);
+
+        assert!(FixedPointArray::<16, 8>::from_f64_iter(vec![1.0, 1000.0]).is_err());
+    }
+
+    #[test]
+    fn test_element_wise_ops() {
+        let a = FixedPointArray::<16, 8>::from_iter(vec![1.5, -2.0, 100.0]).unwrap();
+        let b = FixedPointArray::<16, 8>::from_iter(vec![0.5, 3.0, 2.0]).unwrap();
+
+        assert_eq!(a.element_wise_add(&b).unwrap().to_f32_vec(), vec![2.0, 1.0, 102.0]);
+        assert_eq!(a.element_wise_sub(&b).unwrap().to_f32_vec(), vec![1.0, -5.0, 98.0]);
+
+        let product = a.element_wise_mul(&b).unwrap();
+        assert_eq!(product.get(0).unwrap().to_f32(), 0.75);
+        assert_eq!(product.get(1).unwrap().to_f32(), -6.0);
+        assert_eq!(product.get(2).unwrap(), FixedSmall::max_value());
+    }
+
+    #[test]
+    fn test_element_wise_length_mismatch() {
+        let a = FixedPointArray::<16, 8>::from_iter(vec![1.0, 2.0]).unwrap();
+        let b = FixedPointArray::<16, 8>::from_iter(vec![1.0]).unwrap();
+
+        for result in [
+            a.element_wise_add(&b),
+            a.element_wise_sub(&b),
+            a.element_wise_mul(&b),
+        ] {
+            assert!(matches!(
+                result,
+                Err(FixedPointError::LengthMismatch { expected: 2, found: 1 })
+            ));
+        }
+    }
+
+    #[test]
+    fn test_map() {
+        let a = FixedPointArray::<16, 8>::from_iter(vec![1.25, -2.75, 0.0]).unwrap();
+        let doubled = a.map(|v| v + v);
+        assert_eq!(doubled.to_f32_vec(), vec![2.5, -5.5, 0.0]);
+        assert!(FixedPointArray::<16, 8>::new().map(|v| v.neg()).is_empty());
+    }
+
+    #[test]
+    fn test_sort_random_floats() {
+        // Simple LCG so the test is deterministic without extra dependencies
+        let mut state: u32 = 0x1234_5678;
+        let values: Vec<f32> = (0..200)
+            .map(|_| {
+                state = state.wrapping_mul(1_664_525).wrapping_add(1_013_904_223);
+                (state >> 8) as f32 / (1u32 << 24) as f32 * 200.0 - 100.0
+            })
+            .collect();
+
+        let mut array = FixedPointArray::<16, 8>::from_iter(values.clone()).unwrap();
+        array.sort();
+
+        let mut expected: Vec<f32> = values
+            .iter()
+            .map(|&v| FixedSmall::<16, 8>::from_f32(v).unwrap().to_f32())
+            .collect();
+        expected.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        assert_eq!(array.to_f32_vec(), expected);
+    }
+
+    #[test]
+    fn test_sort_unstable_and_sort_by() {
+        let values = vec![3.0, -1.5, 0.0, 2.25, -1.5];
+
+        let mut array = FixedPointArray::<16, 8>::from_iter(values.clone()).unwrap();
+        array.sort_unstable();
+        assert_eq!(array.to_f32_vec(), vec![-1.5, -1.5, 0.0, 2.25, 3.0]);
+
+        let mut array = FixedPointArray::<16, 8>::from_iter(values).unwrap();
+        array.sort_by(|a, b| b.cmp(a));
+        assert_eq!(array.to_f32_vec(), vec![3.0, 2.25, 0.0, -1.5, -1.5]);
+    }
 }
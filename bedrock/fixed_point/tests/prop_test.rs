@@ -1,5 +1,5 @@
 use proptest::prelude::*;
-use fixed_point::{FixedSmall, FixedPointError};
+use fixed_point::{FixedPointArray, FixedSmall, FixedPointError};
 
 /// Strategy generator for any f32 safely representable by FixedSmall<N, F>.
 fn any_representable<const N: usize, const F: usize>() -> impl Strategy<Value = f32> {
@@ -109,3 +109,86 @@ proptest! {
         prop_assert_eq!(x.abs().raw_value(), expected);
     }
 }
+
+/// Builds a FixedPointArray<16, 8> from raw values.
+fn array_from_raw(raw: &[i32]) -> FixedPointArray<16, 8> {
+    let mut array = FixedPointArray::with_capacity(raw.len());
+    for &r in raw {
+        array.push(FixedSmall::from_raw(r)).unwrap();
+    }
+    array
+}
+
+proptest! {
+
+    // --- Element-wise multiplication distributes over addition ---
+    #[test]
+    fn element_wise_mul_distributes_over_add(
+        values in prop::collection::vec((-1400i32..1400, -1400i32..1400, -1400i32..1400), 0..64)
+    ) {
+        let a = array_from_raw(&values.iter().map(|v| v.0).collect::<Vec<_>>());
+        let b = array_from_raw(&values.iter().map(|v| v.1).collect::<Vec<_>>());
+        let c = array_from_raw(&values.iter().map(|v| v.2).collect::<Vec<_>>());
+
+        let lhs = a.element_wise_mul(&b.element_wise_add(&c).unwrap()).unwrap();
+        let rhs = a
+            .element_wise_mul(&b)
+            .unwrap()
+            .element_wise_add(&a.element_wise_mul(&c).unwrap())
+            .unwrap();
+
+        // Each multiplication truncates by at most one LSB
+        for (l, r) in lhs.iter().zip(rhs.iter()) {
+            prop_assert!((l.raw_value() - r.raw_value()).abs() <= 2);
+        }
+    }
+
+    // --- element_wise_sub undoes element_wise_add ---
+    #[test]
+    fn element_wise_sub_inverts_add(
+        values in prop::collection::vec((-16000i32..16000, -16000i32..16000), 0..64)
+    ) {
+        let a = array_from_raw(&values.iter().map(|v| v.0).collect::<Vec<_>>());
+        let b = array_from_raw(&values.iter().map(|v| v.1).collect::<Vec<_>>());
+
+        let roundtrip = a.element_wise_add(&b).unwrap().element_wise_sub(&b).unwrap();
+        prop_assert_eq!(roundtrip.to_f32_vec(), a.to_f32_vec());
+    }
+}
+
+proptest! {
+
+    // --- Display -> FromStr roundtrip ---
+    // max_value() displays as "127.99" rather than the out-of-range "128.00"
+    #[test]
+    fn display_parse_roundtrip(raw in -32768i32..=32767) {
+        let v = FixedSmall::<16,8>::from_raw(raw);
+
+        // Default precision (2 digits) is within quantization error
+        let parsed = v.to_string().parse::<FixedSmall<16,8>>().unwrap();
+        prop_assert!((parsed.raw_value() - raw).abs() <= 2);
+
+        // Enough digits to be exact
+        let exact = format!("{v:.8}").parse::<FixedSmall<16,8>>().unwrap();
+        prop_assert_eq!(exact, v);
+    }
+}
+
+proptest! {
+
+    // --- wrapping_add agrees with checked_add when the sum is in range ---
+    #[test]
+    fn wrapping_add_matches_checked_add(a in -32768i32..=32767, b in -32768i32..=32767) {
+        let x = FixedSmall::<16,8>::from_raw(a);
+        let y = FixedSmall::<16,8>::from_raw(b);
+
+        match x.checked_add(y) {
+            Some(sum) => prop_assert_eq!(x.wrapping_add(y), sum),
+            // Out of range sums are off by exactly one period of 2^16
+            None => prop_assert_eq!(
+                (a + b - x.wrapping_add(y).raw_value()).abs(),
+                1 << 16
+            ),
+        }
+    }
+}
@@ -0,0 +1,46 @@
+#![cfg(feature = "serde")]
+
+use fixed_point::FixedSmall;
+
+type Q8 = FixedSmall<16, 8>;
+
+#[test]
+fn serialize_as_decimal_numbers() {
+    let values: Vec<Q8> = [1.5, -2.25, 0.00390625, 0.0, 127.99609375]
+        .iter()
+        .map(|&v| Q8::from_f64(v).unwrap())
+        .collect();
+
+    let json = serde_json::to_string(&values).unwrap();
+    assert_eq!(json, "[1.5,-2.25,0.00390625,0.0,127.99609375]");
+
+    let restored: Vec<Q8> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, values);
+}
+
+#[test]
+fn deserialize_rounds_to_nearest() {
+    let value: Q8 = serde_json::from_str("0.1").unwrap();
+    assert_eq!(value.raw_value(), 26);
+
+    let value: Q8 = serde_json::from_str("-3").unwrap();
+    assert_eq!(value.to_f64(), -3.0);
+}
+
+#[test]
+fn deserialize_rejects_out_of_range() {
+    let err = serde_json::from_str::<Q8>("128.0").unwrap_err().to_string();
+    assert!(err.contains("out of range"), "{err}");
+
+    assert!(serde_json::from_str::<Q8>("\"1.5\"").is_err());
+}
+
+#[test]
+fn full_precision_q16_16() {
+    // Values that f32 cannot hold exactly survive the round trip
+    let value = FixedSmall::<32, 16>::from_raw(i32::MAX);
+    let json = serde_json::to_string(&value).unwrap();
+    assert_eq!(json, "32767.99998474121");
+    let restored: FixedSmall<32, 16> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored, value);
+}
@@ -1,14 +1,15 @@
 //! Dynamic field access for MTF-annotated types
 
 use std::collections::HashMap;
+use std::fmt::Write as _;
 use std::fs::File;
-use std::io::{Read, Seek, SeekFrom};
+use std::io::{BufWriter, Read, Seek, SeekFrom, Write};
 use std::marker::PhantomData;
 use std::path::Path;
 use std::ptr::NonNull;
 
 use bytemuck::{Pod, from_bytes};
-use crate::{FieldDef, MTFError, Result, TypeDef, read_mtf, read_string};
+use crate::{FieldDef, MTFError, Result, TypeDef, read_mtf, read_string, write_mtf};
 
 /// A handle to a single field in a struct.
 ///
@@ -43,6 +44,11 @@ impl<'a, T> FieldHandle<'a, T> {
         self.ptr.is_some()
     }
 
+    /// Returns true if the handle is empty (no field found).
+    pub fn is_none(&self) -> bool {
+        !self.is_some()
+    }
+
     /// Get an immutable reference to the field value.
     pub fn get(&self) -> Option<&T> {
         self.ptr.map(|p| unsafe { p.as_ref() })
@@ -90,8 +96,108 @@ impl<'a, T> FieldHandle<'a, T> {
         }
         self
     }
+
+    /// Returns true if the field exists and equals `v`.
+    pub fn eq_value(&self, v: T) -> bool
+    where
+        T: PartialEq,
+    {
+        self.get().is_some_and(|x| *x == v)
+    }
+}
+
+/// Comparisons against a value. An empty handle compares false.
+impl<T: PartialOrd> FieldHandle<'_, T> {
+    /// Returns true if the field exists and is greater than `v`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mtf::dynamic::DynamicContainer;
+    /// use mtf::{MTF, MTFType};
+    ///
+    /// #[derive(MTF)]
+    /// #[repr(C)]
+    /// struct Unit {
+    ///     id: u32,
+    ///     health: u32,
+    /// }
+    ///
+    /// let mut data = Vec::new();
+    /// for (id, health) in [(1u32, 80u32), (2, 30), (3, 55)] {
+    ///     data.extend_from_slice(&id.to_ne_bytes());
+    ///     data.extend_from_slice(&health.to_ne_bytes());
+    /// }
+    /// let mut units = DynamicContainer::from_raw(data, Unit::mtf_type_blob()).unwrap();
+    ///
+    /// let healthy: Vec<usize> = (0..units.len())
+    ///     .filter(|&i| units.field_mut::<u32>(i, "health").gt_value(50))
+    ///     .collect();
+    /// assert_eq!(healthy, vec![0, 2]);
+    ///
+    /// // Missing fields never match
+    /// assert!(!units.field_mut::<u32>(0, "mana").gt_value(0));
+    /// assert!(!units.field_mut::<u32>(0, "mana").le_value(0));
+    /// ```
+    pub fn gt_value(&self, v: T) -> bool {
+        self.get().is_some_and(|x| *x > v)
+    }
+
+    /// Returns true if the field exists and is less than `v`.
+    pub fn lt_value(&self, v: T) -> bool {
+        self.get().is_some_and(|x| *x < v)
+    }
+
+    /// Returns true if the field exists and is greater than or equal to `v`.
+    pub fn ge_value(&self, v: T) -> bool {
+        self.get().is_some_and(|x| *x >= v)
+    }
+
+    /// Returns true if the field exists and is less than or equal to `v`.
+    pub fn le_value(&self, v: T) -> bool {
+        self.get().is_some_and(|x| *x <= v)
+    }
+}
+
+/// Summary statistics of an unsigned integer field.
+///
+/// For an empty container all values are zero.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldStats {
+    pub min: u64,
+    pub max: u64,
+    pub sum: u128,
+    pub count: usize,
+}
+
+impl FieldStats {
+    /// Mean value, or NaN if there are no values.
+    pub fn average(&self) -> f64 {
+        self.sum as f64 / self.count as f64
+    }
+}
+
+/// Summary statistics of a floating-point field.
+///
+/// For an empty container all values are zero.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct FloatFieldStats {
+    pub min: f64,
+    pub max: f64,
+    pub sum: f64,
+    pub count: usize,
+}
+
+impl FloatFieldStats {
+    /// Mean value, or NaN if there are no values.
+    pub fn average(&self) -> f64 {
+        self.sum / self.count as f64
+    }
 }
 
+/// Type name, size in bits and `(name, offset_bits, size_bits)` per field.
+type Layout<'a> = (&'a str, u32, Vec<(&'a str, u32, u32)>);
+
 /// Dynamic access to a slice of structs with MTF metadata.
 ///
 /// Allows field access by name at runtime, useful for:
@@ -131,6 +237,62 @@ impl DynamicContainer {
         })
     }
 
+    /// Construct from raw struct bytes, taking the layout from the type registry.
+    ///
+    /// Returns `MTFError::TypeNotFound` if `T` was never registered, e.g. a
+    /// manual `MTFType` impl that did not call [`crate::registry::register`].
+    /// The registered layout is checked like a parsed blob: its size must be
+    /// exactly `T`'s, or `MTFError::IncompatibleType` is returned, and its
+    /// fields must lie within the type without overlapping.
+    #[cfg(feature = "registry")]
+    pub fn from_bytes_registered<T: crate::MTFType + Pod>(data: Vec<u8>) -> Result<Self> {
+        let ty = crate::registry::lookup_type::<T>()
+            .ok_or_else(|| MTFError::TypeNotFound(std::any::type_name::<T>().to_string()))?;
+
+        if ty.size_bits != 8 * std::mem::size_of::<T>() {
+            return Err(MTFError::IncompatibleType {
+                expected: std::any::type_name::<T>().to_string(),
+                found: ty.name.to_string(),
+            });
+        }
+
+        let mut names = vec![ty.name];
+        names.extend(ty.fields.iter().map(|&(name, _, _)| name));
+        let (strings, string_offsets) = crate::build_string_table(&names);
+
+        let mut field_map = HashMap::new();
+        let mut fields = Vec::with_capacity(ty.fields.len());
+        for (i, &(name, offset_bits, size_bits)) in ty.fields.iter().enumerate() {
+            let out_of_bounds = || MTFError::FieldOutOfBounds {
+                field: i,
+                end_bits: offset_bits.saturating_add(size_bits) as u64,
+                size_bits: ty.size_bits as u32,
+            };
+            let def = FieldDef {
+                name_offset: string_offsets[name],
+                offset_bits: u32::try_from(offset_bits).map_err(|_| out_of_bounds())?,
+                size_bits: u32::try_from(size_bits).map_err(|_| out_of_bounds())?,
+            };
+            field_map.insert(name.to_string(), def.clone());
+            fields.push(def);
+        }
+
+        let type_def = TypeDef {
+            name_offset: string_offsets[ty.name],
+            size_bits: ty.size_bits as u32,
+            fields,
+        };
+        crate::validate_type(&type_def, &strings)?;
+
+        Ok(Self {
+            data,
+            type_def,
+            strings,
+            struct_size: std::mem::size_of::<T>(),
+            field_map,
+        })
+    }
+
     /// Construct directly from a file containing MTF-embedded data.
     ///
     /// Expects format: [DATA][METADATA_SIZE: u32][METADATA]
@@ -177,11 +339,7 @@ impl DynamicContainer {
 
     /// Returns the number of structs in the container.
     pub fn len(&self) -> usize {
-        if self.struct_size == 0 {
-            0
-        } else {
-            self.data.len() / self.struct_size
-        }
+        self.data.len().checked_div(self.struct_size).unwrap_or(0)
     }
 
     /// Returns true if the container is empty.
@@ -194,29 +352,43 @@ impl DynamicContainer {
         read_string(&self.strings, self.type_def.name_offset)
     }
 
+    /// Size of one struct in bytes.
+    pub fn struct_size(&self) -> usize {
+        self.struct_size
+    }
+
     /// List all field names.
     pub fn field_names(&self) -> Vec<String> {
         self.field_map.keys().cloned().collect()
     }
 
-    /// Immutable access to a field of a struct at index.
-    pub fn field<T: Pod>(&self, index: usize, field_name: &str) -> Option<&T> {
-        if index >= self.len() {
+    /// Byte offset of `field_name` within a struct, if the field can be viewed
+    /// as a `T`: it must span exactly `T`'s bits and start on a byte boundary
+    /// aligned for `T`.
+    fn typed_offset<T: Pod>(&self, field_name: &str) -> Option<usize> {
+        let field = self.field_map.get(field_name)?;
+        if field.size_bits as usize != 8 * std::mem::size_of::<T>()
+            || !field.offset_bits.is_multiple_of(8)
+        {
             return None;
         }
 
-        let field = self.field_map.get(field_name)?;
-
-        let field_size = (field.size_bits as usize).div_ceil(8);
-        if field_size != std::mem::size_of::<T>() {
+        let field_offset = (field.offset_bits / 8) as usize;
+        if !field_offset.is_multiple_of(std::mem::align_of::<T>()) {
             return None;
         }
+        Some(field_offset)
+    }
 
-        let field_offset = (field.offset_bits / 8) as usize;
-        if field_offset % std::mem::align_of::<T>() != 0 {
+    /// Immutable access to a field of a struct at index.
+    pub fn field<T: Pod>(&self, index: usize, field_name: &str) -> Option<&T> {
+        if index >= self.len() {
             return None;
         }
 
+        let field_offset = self.typed_offset::<T>(field_name)?;
+        let field_size = std::mem::size_of::<T>();
+
         let struct_start = index * self.struct_size;
         let field_start = struct_start + field_offset;
         let field_end = field_start + field_size;
@@ -226,26 +398,33 @@ impl DynamicContainer {
         Some(from_bytes(field_slice))
     }
 
+    /// Iterate over the values of one field, typed as `T`, for every struct.
+    ///
+    /// Returns `None` under the same conditions as [`field`](Self::field):
+    /// the field doesn't exist, it is not exactly as wide as `T`, or its
+    /// offset is not byte-aligned and aligned for `T`.
+    pub fn typed_iter<T: Pod>(&self, field_name: &str) -> Option<TypedFieldIter<'_, T>> {
+        let field_offset = self.typed_offset::<T>(field_name)?;
+
+        Some(TypedFieldIter {
+            container: self,
+            field_offset,
+            index: 0,
+            _phantom: PhantomData,
+        })
+    }
+
     /// Mutable access to a field of a struct at index.
     pub fn field_mut<T: Pod>(&mut self, index: usize, field_name: &str) -> FieldHandle<'_, T> {
         if index >= self.len() {
             return FieldHandle::none();
         }
 
-        let field = match self.field_map.get(field_name) {
-            Some(f) => f,
+        let field_offset = match self.typed_offset::<T>(field_name) {
+            Some(offset) => offset,
             None => return FieldHandle::none(),
         };
-
-        let field_size = (field.size_bits as usize).div_ceil(8);
-        if field_size != std::mem::size_of::<T>() {
-            return FieldHandle::none();
-        }
-
-        let field_offset = (field.offset_bits / 8) as usize;
-        if field_offset % std::mem::align_of::<T>() != 0 {
-            return FieldHandle::none();
-        }
+        let field_size = std::mem::size_of::<T>();
 
         let struct_start = index * self.struct_size;
         let field_start = struct_start + field_offset;
@@ -270,6 +449,303 @@ impl DynamicContainer {
         &mut self.data
     }
 
+    /// Exchange the structs at indices `i` and `j`.
+    pub fn swap(&mut self, i: usize, j: usize) -> Result<()> {
+        let len = self.len();
+        for index in [i, j] {
+            if index >= len {
+                return Err(MTFError::IndexOutOfBounds { index, len });
+            }
+        }
+        if i == j {
+            return Ok(());
+        }
+
+        let size = self.struct_size;
+        let tmp = self.data[i * size..(i + 1) * size].to_vec();
+        self.data.copy_within(j * size..(j + 1) * size, i * size);
+        self.data[j * size..(j + 1) * size].copy_from_slice(&tmp);
+        Ok(())
+    }
+
+    /// Sort the structs in ascending order of an unsigned integer field.
+    ///
+    /// The field must be byte-aligned and 8, 16, 32 or 64 bits wide. The sort
+    /// is stable.
+    pub fn sort_by_field(&mut self, field_name: &str) -> Result<()> {
+        let keys = self.uint_field_values(field_name)?;
+        self.reorder_by(|a, b| keys[a].cmp(&keys[b]));
+        Ok(())
+    }
+
+    /// Sort the structs in descending order of an unsigned integer field.
+    ///
+    /// See [`sort_by_field`](Self::sort_by_field) for the supported fields.
+    pub fn sort_by_field_desc(&mut self, field_name: &str) -> Result<()> {
+        let keys = self.uint_field_values(field_name)?;
+        self.reorder_by(|a, b| keys[b].cmp(&keys[a]));
+        Ok(())
+    }
+
+    /// Compute min, max and sum of an unsigned integer field over all structs.
+    ///
+    /// MTF metadata does not record whether a field is an integer or a float,
+    /// so use [`float_field_stats`](Self::float_field_stats) for `f32`/`f64`
+    /// fields. Supports the same fields as [`sort_by_field`](Self::sort_by_field).
+    pub fn field_stats(&self, field_name: &str) -> Result<FieldStats> {
+        let values = self.uint_field_values(field_name)?;
+        Ok(FieldStats {
+            min: values.iter().copied().min().unwrap_or(0),
+            max: values.iter().copied().max().unwrap_or(0),
+            sum: values.iter().map(|&v| v as u128).sum(),
+            count: values.len(),
+        })
+    }
+
+    /// Compute min, max and sum of an `f32` or `f64` field over all structs.
+    ///
+    /// The width is taken from the field's `size_bits` (32 or 64).
+    pub fn float_field_stats(&self, field_name: &str) -> Result<FloatFieldStats> {
+        let bits = self.uint_field_values(field_name)?;
+        let field = &self.field_map[field_name];
+        let values: Vec<f64> = match field.size_bits {
+            32 => bits.iter().map(|&b| f32::from_bits(b as u32) as f64).collect(),
+            64 => bits.iter().map(|&b| f64::from_bits(b)).collect(),
+            _ => {
+                return Err(MTFError::UnsupportedField {
+                    name: field_name.to_string(),
+                    offset_bits: field.offset_bits,
+                    size_bits: field.size_bits,
+                });
+            }
+        };
+
+        if values.is_empty() {
+            return Ok(FloatFieldStats {
+                min: 0.0,
+                max: 0.0,
+                sum: 0.0,
+                count: 0,
+            });
+        }
+        Ok(FloatFieldStats {
+            min: values.iter().copied().fold(f64::INFINITY, f64::min),
+            max: values.iter().copied().fold(f64::NEG_INFINITY, f64::max),
+            sum: values.iter().sum(),
+            count: values.len(),
+        })
+    }
+
+    /// Read an unsigned integer field from every struct, widened to u64.
+    fn uint_field_values(&self, field_name: &str) -> Result<Vec<u64>> {
+        let field = self
+            .field_map
+            .get(field_name)
+            .ok_or_else(|| MTFError::FieldNotFound(field_name.to_string()))?;
+
+        let unsupported = || MTFError::UnsupportedField {
+            name: field_name.to_string(),
+            offset_bits: field.offset_bits,
+            size_bits: field.size_bits,
+        };
+        if !field.offset_bits.is_multiple_of(8) {
+            return Err(unsupported());
+        }
+        let offset = (field.offset_bits / 8) as usize;
+
+        let read: fn(&[u8]) -> u64 = match field.size_bits {
+            8 => |b| b[0] as u64,
+            16 => |b| u16::from_ne_bytes([b[0], b[1]]) as u64,
+            32 => |b| u32::from_ne_bytes(b[..4].try_into().unwrap()) as u64,
+            64 => |b| u64::from_ne_bytes(b[..8].try_into().unwrap()),
+            _ => return Err(unsupported()),
+        };
+        let size = (field.size_bits / 8) as usize;
+        if offset + size > self.struct_size {
+            return Err(unsupported());
+        }
+
+        Ok(self
+            .data
+            .chunks_exact(self.struct_size)
+            .map(|row| read(&row[offset..offset + size]))
+            .collect())
+    }
+
+    /// Rearrange the structs into the order given by comparing their indices.
+    fn reorder_by<C: FnMut(usize, usize) -> std::cmp::Ordering>(&mut self, mut compare: C) {
+        let mut order: Vec<usize> = self.iter().collect();
+        order.sort_by(|&a, &b| compare(a, b));
+
+        let mut data = Vec::with_capacity(self.data.len());
+        for i in order {
+            let start = i * self.struct_size;
+            data.extend_from_slice(&self.data[start..start + self.struct_size]);
+        }
+        self.data = data;
+    }
+
+    /// Create a new container holding only the structs for which `predicate`
+    /// returns true.
+    ///
+    /// The result shares this container's type metadata but owns a fresh
+    /// copy of the selected data.
+    pub fn filter<F>(&self, predicate: F) -> Result<DynamicContainer>
+    where
+        F: Fn(&DynamicContainer, usize) -> bool,
+    {
+        let mut data = Vec::new();
+        for i in self.iter().filter(|&i| predicate(self, i)) {
+            let start = i * self.struct_size;
+            data.extend_from_slice(&self.data[start..start + self.struct_size]);
+        }
+
+        Ok(DynamicContainer {
+            data,
+            type_def: self.type_def.clone(),
+            strings: self.strings.clone(),
+            struct_size: self.struct_size,
+            field_map: self.field_map.clone(),
+        })
+    }
+
+    /// Create a new container holding only the named fields of each struct.
+    ///
+    /// Fields are laid out in the order given, each byte-sized field aligned
+    /// to the largest power of two (up to 8) dividing its size so that
+    /// [`field`](Self::field) can still read it; bit fields are packed at the
+    /// next free bit. The type keeps its name and gets freshly generated
+    /// metadata describing the smaller struct. Repeated names are ignored.
+    ///
+    /// # Errors
+    ///
+    /// Returns `MTFError::FieldNotFound` if a name is not a field of this type.
+    pub fn project(&self, field_names: &[&str]) -> Result<DynamicContainer> {
+        let mut selected: Vec<(&str, &FieldDef)> = Vec::new();
+        for &name in field_names {
+            let field = self
+                .field_map
+                .get(name)
+                .ok_or_else(|| MTFError::FieldNotFound(name.to_string()))?;
+            if !selected.iter().any(|&(n, _)| n == name) {
+                selected.push((name, field));
+            }
+        }
+
+        // Assign new offsets, tracking the widest alignment for the tail padding
+        let mut offsets = Vec::with_capacity(selected.len());
+        let mut cursor = 0u32;
+        let mut max_align = 1u32;
+        for (_, field) in &selected {
+            if field.size_bits.is_multiple_of(8) {
+                let bytes = field.size_bits / 8;
+                let align = if bytes == 0 {
+                    1
+                } else {
+                    (1 << bytes.trailing_zeros()).min(8)
+                };
+                max_align = max_align.max(align);
+                cursor = cursor.div_ceil(align * 8) * align * 8;
+            }
+            offsets.push(cursor);
+            cursor += field.size_bits;
+        }
+        let size_bits = cursor.div_ceil(max_align * 8) * max_align * 8;
+        let struct_size = (size_bits / 8) as usize;
+
+        let type_name = self.type_name()?;
+        let mut names = vec![type_name];
+        names.extend(selected.iter().map(|&(name, _)| name));
+        let (strings, string_offsets) = crate::build_string_table(&names);
+
+        let mut field_map = HashMap::new();
+        let mut fields = Vec::with_capacity(selected.len());
+        for (&(name, field), &offset_bits) in selected.iter().zip(&offsets) {
+            let def = FieldDef {
+                name_offset: string_offsets[name],
+                offset_bits,
+                size_bits: field.size_bits,
+            };
+            field_map.insert(name.to_string(), def.clone());
+            fields.push(def);
+        }
+
+        let mut data = vec![0u8; self.len() * struct_size];
+        if struct_size > 0 {
+            for (row, out) in self
+                .data
+                .chunks_exact(self.struct_size)
+                .zip(data.chunks_exact_mut(struct_size))
+            {
+                for ((_, field), &offset_bits) in selected.iter().zip(&offsets) {
+                    copy_bits(row, field.offset_bits, out, offset_bits, field.size_bits);
+                }
+            }
+        }
+
+        Ok(DynamicContainer {
+            data,
+            type_def: TypeDef {
+                name_offset: string_offsets[type_name],
+                size_bits,
+                fields,
+            },
+            strings,
+            struct_size,
+            field_map,
+        })
+    }
+
+    /// Append all structs from `other`, which must describe the same type.
+    ///
+    /// Types are compatible when they have the same name, size and fields
+    /// (by name, offset and size); string table offsets may differ.
+    pub fn append(&mut self, other: &DynamicContainer) -> Result<()> {
+        if self.layout()? != other.layout()? {
+            return Err(MTFError::IncompatibleType {
+                expected: self.type_name()?.to_string(),
+                found: other.type_name()?.to_string(),
+            });
+        }
+        self.data.extend_from_slice(&other.data);
+        Ok(())
+    }
+
+    /// Type name, size and resolved fields, for comparing type definitions.
+    fn layout(&self) -> Result<Layout<'_>> {
+        let fields = self
+            .type_def
+            .fields
+            .iter()
+            .map(|f| Ok((read_string(&self.strings, f.name_offset)?, f.offset_bits, f.size_bits)))
+            .collect::<Result<_>>()?;
+        Ok((self.type_name()?, self.type_def.size_bits, fields))
+    }
+
+    /// Write the data and its metadata to a file.
+    ///
+    /// Uses the same format as `write_slice_with_mtf`, so the file can be
+    /// loaded again with [`from_file`](Self::from_file).
+    pub fn save_to_file<P: AsRef<Path>>(&self, path: P) -> Result<()> {
+        let mut file = BufWriter::new(File::create(path)?);
+        self.save_to_writer(&mut file)?;
+        file.flush()?;
+        Ok(())
+    }
+
+    /// Write the data and its metadata to a writer.
+    ///
+    /// Format: [DATA][METADATA_SIZE: u32][METADATA]
+    pub fn save_to_writer<W: Write>(&self, w: &mut W) -> Result<()> {
+        let mut blob = Vec::new();
+        write_mtf(std::slice::from_ref(&self.type_def), &self.strings, &mut blob)?;
+
+        w.write_all(&self.data)?;
+        w.write_all(&(blob.len() as u32).to_le_bytes())?;
+        w.write_all(&blob)?;
+        Ok(())
+    }
+
     /// Iterator over struct indices.
     pub fn iter(&self) -> DynamicContainerIter<'_> {
         DynamicContainerIter {
@@ -277,6 +753,372 @@ impl DynamicContainer {
             index: 0,
         }
     }
+
+    /// Render the structs as a JSON array of objects keyed by field name.
+    ///
+    /// MTF metadata does not record whether a field is signed or a float, so
+    /// every field of up to 64 bits is written as an unsigned integer; use
+    /// [`to_json_with`](Self::to_json_with) to mark signed and float fields.
+    /// Wider fields (arrays) are written as arrays of bytes.
+    pub fn to_json(&self) -> String {
+        self.to_json_with(&JsonTypes::default())
+    }
+
+    /// Like [`to_json`](Self::to_json), but the named 32- and 64-bit fields
+    /// are written as `f32`/`f64` values. Non-finite floats become `null`.
+    pub fn to_json_with_floats(&self, float_fields: &[&str]) -> String {
+        self.to_json_with(&JsonTypes {
+            floats: float_fields,
+            ..JsonTypes::default()
+        })
+    }
+
+    /// Like [`to_json`](Self::to_json), with the given fields written as
+    /// floats or sign-extended integers.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use mtf::dynamic::{DynamicContainer, JsonTypes};
+    /// use mtf::{MTF, MTFType};
+    ///
+    /// #[derive(MTF)]
+    /// #[repr(C)]
+    /// struct Sample {
+    ///     delta: i32,
+    ///     value: f32,
+    /// }
+    ///
+    /// let mut data = (-5i32).to_ne_bytes().to_vec();
+    /// data.extend_from_slice(&0.5f32.to_ne_bytes());
+    /// let container = DynamicContainer::from_raw(data, Sample::mtf_type_blob()).unwrap();
+    ///
+    /// let json = container.to_json_with(&JsonTypes {
+    ///     floats: &["value"],
+    ///     signed: &["delta"],
+    /// });
+    /// assert_eq!(json, r#"[{"delta":-5,"value":0.5}]"#);
+    /// ```
+    pub fn to_json_with(&self, types: &JsonTypes<'_>) -> String {
+        // Names were validated when the container was constructed
+        let fields: Vec<(&str, &FieldDef)> = self
+            .type_def
+            .fields
+            .iter()
+            .map(|f| (read_string(&self.strings, f.name_offset).unwrap_or(""), f))
+            .collect();
+
+        let mut out = String::from("[");
+        for (i, row) in self.data.chunks_exact(self.struct_size.max(1)).enumerate() {
+            if i > 0 {
+                out.push(',');
+            }
+            out.push('{');
+            for (j, (name, field)) in fields.iter().enumerate() {
+                if j > 0 {
+                    out.push(',');
+                }
+                write_json_string(&mut out, name);
+                out.push(':');
+                let kind = if types.floats.contains(name) {
+                    JsonNumber::Float
+                } else if types.signed.contains(name) {
+                    JsonNumber::Signed
+                } else {
+                    JsonNumber::Unsigned
+                };
+                write_json_value(&mut out, row, field, kind);
+            }
+            out.push('}');
+        }
+        out.push(']');
+        out
+    }
+}
+
+/// Append `s` as a quoted JSON string.
+fn write_json_string(out: &mut String, s: &str) {
+    out.push('"');
+    for c in s.chars() {
+        match c {
+            '"' => out.push_str("\\\""),
+            '\\' => out.push_str("\\\\"),
+            '\n' => out.push_str("\\n"),
+            '\r' => out.push_str("\\r"),
+            '\t' => out.push_str("\\t"),
+            c if (c as u32) < 0x20 => {
+                let _ = write!(out, "\\u{:04x}", c as u32);
+            }
+            c => out.push(c),
+        }
+    }
+    out.push('"');
+}
+
+/// How [`DynamicContainer::to_json_with`] interprets fields of up to 64 bits.
+///
+/// Fields not named in either list are written as unsigned integers.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct JsonTypes<'a> {
+    /// 32- and 64-bit fields to write as `f32`/`f64` values.
+    pub floats: &'a [&'a str],
+    /// Fields to sign-extend from their width and write as signed integers.
+    pub signed: &'a [&'a str],
+}
+
+#[derive(Clone, Copy, PartialEq)]
+enum JsonNumber {
+    Unsigned,
+    Signed,
+    Float,
+}
+
+/// Append the value of `field` in `row` as a JSON number (or byte array).
+fn write_json_value(out: &mut String, row: &[u8], field: &FieldDef, kind: JsonNumber) {
+    if field.size_bits > 64 {
+        let start = (field.offset_bits / 8) as usize;
+        let end = (start + (field.size_bits as usize).div_ceil(8)).min(row.len());
+        out.push('[');
+        for (k, b) in row.get(start..end).unwrap_or(&[]).iter().enumerate() {
+            if k > 0 {
+                out.push(',');
+            }
+            let _ = write!(out, "{}", b);
+        }
+        out.push(']');
+        return;
+    }
+
+    let bits = read_field_bits(row, field);
+    let value = match (kind, field.size_bits) {
+        (JsonNumber::Float, 32) => f32::from_bits(bits as u32) as f64,
+        (JsonNumber::Float, 64) => f64::from_bits(bits),
+        (JsonNumber::Signed, 1..=64) => {
+            let unused = 64 - field.size_bits;
+            let _ = write!(out, "{}", ((bits << unused) as i64) >> unused);
+            return;
+        }
+        _ => {
+            let _ = write!(out, "{}", bits);
+            return;
+        }
+    };
+    if value.is_finite() {
+        let _ = write!(out, "{}", value);
+    } else {
+        out.push_str("null");
+    }
+}
+
+/// Read a field of at most 64 bits, widened to u64.
+///
+/// Byte-aligned 8/16/32/64-bit fields are read in native byte order; bit
+/// fields are read least significant bit first.
+fn read_field_bits(row: &[u8], field: &FieldDef) -> u64 {
+    let offset = (field.offset_bits / 8) as usize;
+    let aligned = field.offset_bits.is_multiple_of(8);
+    match (aligned, field.size_bits) {
+        (true, 8) => return row.get(offset).map_or(0, |&b| b as u64),
+        (true, 16) => {
+            if let Some(b) = row.get(offset..offset + 2) {
+                return u16::from_ne_bytes([b[0], b[1]]) as u64;
+            }
+        }
+        (true, 32) => {
+            if let Some(b) = row.get(offset..offset + 4) {
+                return u32::from_ne_bytes(b.try_into().unwrap()) as u64;
+            }
+        }
+        (true, 64) => {
+            if let Some(b) = row.get(offset..offset + 8) {
+                return u64::from_ne_bytes(b.try_into().unwrap());
+            }
+        }
+        _ => {}
+    }
+
+    let mut value = 0u64;
+    for k in 0..field.size_bits as usize {
+        let bit = field.offset_bits as usize + k;
+        let set = row.get(bit / 8).is_some_and(|&b| b >> (bit % 8) & 1 == 1);
+        value |= (set as u64) << k;
+    }
+    value
+}
+
+/// Copy `size_bits` bits between rows, least significant bit first.
+fn copy_bits(src: &[u8], src_bit: u32, dst: &mut [u8], dst_bit: u32, size_bits: u32) {
+    if src_bit.is_multiple_of(8) && dst_bit.is_multiple_of(8) && size_bits.is_multiple_of(8) {
+        let from = (src_bit / 8) as usize;
+        let to = (dst_bit / 8) as usize;
+        let len = (size_bits / 8) as usize;
+        dst[to..to + len].copy_from_slice(&src[from..from + len]);
+        return;
+    }
+
+    for k in 0..size_bits as usize {
+        let from = src_bit as usize + k;
+        let to = dst_bit as usize + k;
+        if src[from / 8] >> (from % 8) & 1 == 1 {
+            dst[to / 8] |= 1 << (to % 8);
+        }
+    }
+}
+
+/// Borrowed counterpart of [`DynamicContainer`].
+///
+/// Holds references to the struct data and string table instead of owning
+/// them, so payloads can be inspected in place without copying.
+pub struct DynamicContainerRef<'a> {
+    data: &'a [u8],
+    type_def: TypeDef,
+    strings: &'a [u8],
+    struct_size: usize,
+    field_map: HashMap<&'a str, FieldDef>,
+}
+
+impl DynamicContainer {
+    /// Construct a borrowed view over raw data and a complete MTF blob.
+    pub fn from_bytes_and_blob_borrowed<'a>(
+        data: &'a [u8],
+        blob: &'a [u8],
+    ) -> Result<DynamicContainerRef<'a>> {
+        let (types, strings) = read_mtf(blob)?;
+
+        let type_def = types.into_iter().next().ok_or(MTFError::UnexpectedEof)?;
+
+        DynamicContainerRef::new(data, type_def, strings)
+    }
+}
+
+impl<'a> DynamicContainerRef<'a> {
+    fn new(data: &'a [u8], type_def: TypeDef, strings: &'a [u8]) -> Result<Self> {
+        let struct_size = (type_def.size_bits as usize).div_ceil(8);
+
+        let mut field_map = HashMap::new();
+        for f in &type_def.fields {
+            let name = read_string(strings, f.name_offset)?;
+            field_map.insert(name, f.clone());
+        }
+
+        Ok(Self {
+            data,
+            type_def,
+            strings,
+            struct_size,
+            field_map,
+        })
+    }
+
+    /// Returns the number of structs in the view.
+    pub fn len(&self) -> usize {
+        self.data.len().checked_div(self.struct_size).unwrap_or(0)
+    }
+
+    /// Returns true if the view is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Get the type name.
+    pub fn type_name(&self) -> Result<&'a str> {
+        read_string(self.strings, self.type_def.name_offset)
+    }
+
+    /// List all field names.
+    pub fn field_names(&self) -> Vec<&'a str> {
+        self.field_map.keys().copied().collect()
+    }
+
+    /// Immutable access to a field of a struct at index.
+    ///
+    /// Returns `None` if the field is missing, is not exactly as wide as `T`,
+    /// does not start on a byte boundary, or is not suitably aligned in the
+    /// borrowed data.
+    pub fn field<T: Pod>(&self, index: usize, field_name: &str) -> Option<&'a T> {
+        if index >= self.len() {
+            return None;
+        }
+
+        let field = self.field_map.get(field_name)?;
+
+        let field_size = std::mem::size_of::<T>();
+        if field.size_bits as usize != 8 * field_size || !field.offset_bits.is_multiple_of(8) {
+            return None;
+        }
+
+        let field_start = index * self.struct_size + (field.offset_bits / 8) as usize;
+        let field_end = field_start + field_size;
+
+        // Borrowed data carries no alignment guarantee, so check the address
+        let data: &'a [u8] = self.data;
+        bytemuck::try_from_bytes(data.get(field_start..field_end)?).ok()
+    }
+
+    /// Get raw byte data.
+    pub fn raw(&self) -> &'a [u8] {
+        self.data
+    }
+}
+
+/// Dynamic access to several related struct types described by one MTF blob.
+///
+/// Each type definition in the blob is stored by name, and every type can be
+/// given its own slice of struct data. This allows heterogeneous records (for
+/// example `Player` and `Projectile`) to share a single metadata block.
+pub struct DynamicMultiContainer {
+    types: HashMap<String, TypeDef>,
+    strings: Vec<u8>,
+    data: HashMap<String, Vec<u8>>,
+}
+
+impl DynamicMultiContainer {
+    /// Construct from a complete MTF blob, with no struct data yet.
+    pub fn from_blob(blob: &[u8]) -> Result<Self> {
+        let (type_defs, strings) = read_mtf(blob)?;
+
+        let mut types = HashMap::new();
+        for type_def in type_defs {
+            let name = read_string(strings, type_def.name_offset)?;
+            types.insert(name.to_string(), type_def);
+        }
+
+        Ok(Self {
+            types,
+            strings: strings.to_vec(),
+            data: HashMap::new(),
+        })
+    }
+
+    /// Set the raw struct data for a named type, replacing any previous data.
+    pub fn set_data(&mut self, type_name: &str, data: Vec<u8>) -> Result<()> {
+        if !self.types.contains_key(type_name) {
+            return Err(MTFError::TypeNotFound(type_name.to_string()));
+        }
+        self.data.insert(type_name.to_string(), data);
+        Ok(())
+    }
+
+    /// List all type names.
+    pub fn type_names(&self) -> Vec<String> {
+        self.types.keys().cloned().collect()
+    }
+
+    /// Get the definition of a named type.
+    pub fn type_def(&self, type_name: &str) -> Option<&TypeDef> {
+        self.types.get(type_name)
+    }
+
+    /// Borrowed view over the structs of a named type.
+    ///
+    /// A type without data yields an empty view.
+    pub fn get_type(&self, type_name: &str) -> Option<DynamicContainerRef<'_>> {
+        let type_def = self.types.get(type_name)?.clone();
+        let data = self.data.get(type_name).map_or(&[][..], Vec::as_slice);
+
+        // Every name offset was validated by `read_mtf`
+        DynamicContainerRef::new(data, type_def, &self.strings).ok()
+    }
 }
 
 /// Iterator over the container structs (yields indices).
@@ -306,32 +1148,126 @@ impl<'a> Iterator for DynamicContainerIter<'a> {
 
 impl<'a> ExactSizeIterator for DynamicContainerIter<'a> {}
 
+/// Iterator over the values of one field, created by
+/// [`DynamicContainer::typed_iter`].
+pub struct TypedFieldIter<'a, T> {
+    container: &'a DynamicContainer,
+    field_offset: usize,
+    index: usize,
+    _phantom: PhantomData<T>,
+}
+
+impl<'a, T: Pod> Iterator for TypedFieldIter<'a, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.container.len() {
+            return None;
+        }
+        let start = self.index * self.container.struct_size + self.field_offset;
+        let bytes = self.container.data.get(start..start + std::mem::size_of::<T>())?;
+        self.index += 1;
+        Some(bytemuck::pod_read_unaligned(bytes))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.container.len() - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, T: Pod> ExactSizeIterator for TypedFieldIter<'a, T> {}
+
 
 #[cfg(test)]
 mod tests {
     use super::*;
 
     fn create_test_blob() -> Vec<u8> {
+        let type_def = TypeDef {
+            name_offset: 0,
+            size_bits: 64,
+            fields: vec![
+                FieldDef {
+                    name_offset: 5,
+                    offset_bits: 0,
+                    size_bits: 32,
+                },
+                FieldDef {
+                    name_offset: 7,
+                    offset_bits: 32,
+                    size_bits: 32,
+                },
+            ],
+        };
         let mut blob = Vec::new();
-        blob.extend_from_slice(b"MTF\0");
-        blob.extend_from_slice(&1u32.to_le_bytes());
-        blob.extend_from_slice(&1u32.to_le_bytes());
-        blob.extend_from_slice(&0u32.to_le_bytes());
-        blob.extend_from_slice(&64u32.to_le_bytes());
-        blob.extend_from_slice(&2u32.to_le_bytes());
+        write_mtf(&[type_def], b"Test\0x\0y\0", &mut blob).unwrap();
+        blob
+    }
 
-        blob.extend_from_slice(&5u32.to_le_bytes());
-        blob.extend_from_slice(&0u32.to_le_bytes());
-        blob.extend_from_slice(&32u32.to_le_bytes());
+    /// The types of `create_test_blob` as a version 1 blob, for borrowed views.
+    #[rustfmt::skip]
+    static TEST_BLOB: [u8; 61] = [
+        b'M', b'T', b'F', 0,
+        1, 0, 0, 0, // version
+        1, 0, 0, 0, // type count
+        0, 0, 0, 0, 64, 0, 0, 0, 2, 0, 0, 0, // "Test", 64 bits, 2 fields
+        5, 0, 0, 0, 0, 0, 0, 0, 32, 0, 0, 0, // "x" at bit 0, 32 bits
+        7, 0, 0, 0, 32, 0, 0, 0, 32, 0, 0, 0, // "y" at bit 32, 32 bits
+        9, 0, 0, 0, // string table length
+        b'T', b'e', b's', b't', 0, b'x', 0, b'y', 0,
+    ];
 
-        blob.extend_from_slice(&7u32.to_le_bytes());
-        blob.extend_from_slice(&32u32.to_le_bytes());
-        blob.extend_from_slice(&32u32.to_le_bytes());
+    #[repr(C, align(4))]
+    struct Aligned([u8; 16]);
 
-        blob.extend_from_slice(&9u32.to_le_bytes());
-        blob.extend_from_slice(b"Test\0x\0y\0");
+    static TEST_DATA: Aligned = Aligned([1, 0, 0, 0, 2, 0, 0, 0, 3, 0, 0, 0, 4, 0, 0, 0]);
 
-        blob
+    #[test]
+    fn test_static_blob_matches_written_blob() {
+        let written = create_test_blob();
+        assert_eq!(read_mtf(&TEST_BLOB).unwrap(), read_mtf(&written).unwrap());
+    }
+
+    #[test]
+    fn test_borrowed_container() {
+        let view =
+            DynamicContainer::from_bytes_and_blob_borrowed(&TEST_DATA.0, &TEST_BLOB).unwrap();
+        assert_eq!(view.len(), 2);
+        assert!(!view.is_empty());
+        assert_eq!(view.type_name().unwrap(), "Test");
+
+        let mut names = view.field_names();
+        names.sort();
+        assert_eq!(names, vec!["x", "y"]);
+
+        let y: &'static u32 = view.field(1, "y").unwrap();
+        assert_eq!(*y, 4);
+        assert_eq!(view.field::<u32>(0, "x"), Some(&1));
+        assert_eq!(view.field::<u32>(2, "x"), None);
+        assert_eq!(view.field::<u64>(0, "x"), None);
+        assert_eq!(view.field::<u32>(0, "z"), None);
+        assert_eq!(view.raw().as_ptr(), TEST_DATA.0.as_ptr());
+    }
+
+    #[test]
+    fn test_borrowed_container_misaligned() {
+        let view =
+            DynamicContainer::from_bytes_and_blob_borrowed(&TEST_DATA.0[1..9], &TEST_BLOB).unwrap();
+        assert_eq!(view.len(), 1);
+        assert_eq!(view.field::<u32>(0, "x"), None);
+    }
+
+    #[test]
+    fn test_copy_bits() {
+        // A 3-bit field at bit 5, straddling a byte boundary
+        let src = [0b1010_0000u8, 0b0000_0001];
+        let mut dst = [0u8; 2];
+        copy_bits(&src, 5, &mut dst, 2, 3);
+        assert_eq!(dst, [0b0001_0100, 0]);
+
+        copy_bits(&src, 0, &mut dst, 8, 8);
+        assert_eq!(dst[1], 0b1010_0000);
     }
 
     #[test]
@@ -412,6 +1348,24 @@ mod tests {
         // Out of bounds index
         let handle = container.field_mut::<u32>(99, "x");
         assert!(!handle.is_some());
+        assert!(handle.is_none());
+        assert!(!handle.eq_value(0));
+        assert!(!handle.ge_value(0));
+    }
+
+    #[test]
+    fn test_field_handle_comparisons() {
+        let data = vec![0x0A, 0x00, 0x00, 0x00, 0x14, 0x00, 0x00, 0x00];
+        let blob = create_test_blob();
+
+        let mut container = DynamicContainer::from_raw(data, &blob).unwrap();
+        let x = container.field_mut::<u32>(0, "x");
+        assert!(x.eq_value(10));
+        assert!(!x.eq_value(11));
+        assert!(x.gt_value(9) && !x.gt_value(10));
+        assert!(x.ge_value(10) && !x.ge_value(11));
+        assert!(x.lt_value(11) && !x.lt_value(10));
+        assert!(x.le_value(10) && !x.le_value(9));
     }
 
     #[test]
@@ -432,4 +1386,305 @@ mod tests {
         
         assert_eq!(container.iter().len(), 3);
     }
+
+    /// Blob for `Player { x: f32, y: f32, health: u32, score: u32 }`.
+    fn create_player_blob() -> Vec<u8> {
+        let (strings, offsets) =
+            crate::build_string_table(&["Player", "x", "y", "health", "score"]);
+        let fields = ["x", "y", "health", "score"]
+            .iter()
+            .enumerate()
+            .map(|(i, name)| FieldDef {
+                name_offset: offsets[*name],
+                offset_bits: i as u32 * 32,
+                size_bits: 32,
+            })
+            .collect();
+        let type_def = TypeDef {
+            name_offset: offsets["Player"],
+            size_bits: 128,
+            fields,
+        };
+
+        let mut blob = Vec::new();
+        write_mtf(&[type_def], &strings, &mut blob).unwrap();
+        blob
+    }
+
+    fn create_multi_blob() -> Vec<u8> {
+        let (strings, offsets) = crate::build_string_table(&[
+            "Player", "x", "y", "health", "score", "Projectile", "owner", "speed",
+        ]);
+        let field = |name: &str, offset_bits: u32| FieldDef {
+            name_offset: offsets[name],
+            offset_bits,
+            size_bits: 32,
+        };
+        let player = TypeDef {
+            name_offset: offsets["Player"],
+            size_bits: 128,
+            fields: vec![field("x", 0), field("y", 32), field("health", 64), field("score", 96)],
+        };
+        let projectile = TypeDef {
+            name_offset: offsets["Projectile"],
+            size_bits: 64,
+            fields: vec![field("owner", 0), field("speed", 32)],
+        };
+
+        let mut blob = Vec::new();
+        write_mtf(&[player, projectile], &strings, &mut blob).unwrap();
+        blob
+    }
+
+    #[test]
+    fn test_multi_container() {
+        let mut multi = DynamicMultiContainer::from_blob(&create_multi_blob()).unwrap();
+
+        let mut names = multi.type_names();
+        names.sort();
+        assert_eq!(names, vec!["Player", "Projectile"]);
+
+        let mut players = Vec::new();
+        for v in [1.0f32, 2.0] {
+            players.extend_from_slice(&v.to_ne_bytes());
+        }
+        for v in [100u32, 7] {
+            players.extend_from_slice(&v.to_ne_bytes());
+        }
+        multi.set_data("Player", players).unwrap();
+
+        let mut projectiles = Vec::new();
+        for (owner, speed) in [(0u32, 1.5f32), (0, 2.5), (1, 9.0)] {
+            projectiles.extend_from_slice(&owner.to_ne_bytes());
+            projectiles.extend_from_slice(&speed.to_ne_bytes());
+        }
+        multi.set_data("Projectile", projectiles).unwrap();
+
+        let player = multi.get_type("Player").unwrap();
+        assert_eq!(player.type_name().unwrap(), "Player");
+        assert_eq!(player.len(), 1);
+        assert_eq!(player.field::<f32>(0, "y"), Some(&2.0));
+        assert_eq!(player.field::<u32>(0, "score"), Some(&7));
+        assert_eq!(player.field::<u32>(0, "owner"), None);
+
+        let projectile = multi.get_type("Projectile").unwrap();
+        assert_eq!(projectile.len(), 3);
+        assert_eq!(projectile.field::<u32>(2, "owner"), Some(&1));
+        assert_eq!(projectile.field::<f32>(1, "speed"), Some(&2.5));
+
+        assert_eq!(multi.type_def("Projectile").unwrap().size_bits, 64);
+        assert!(multi.get_type("Enemy").is_none());
+        assert!(matches!(
+            multi.set_data("Enemy", vec![]),
+            Err(MTFError::TypeNotFound(name)) if name == "Enemy"
+        ));
+    }
+
+    #[test]
+    fn test_multi_container_without_data() {
+        let multi = DynamicMultiContainer::from_blob(&create_multi_blob()).unwrap();
+        let projectile = multi.get_type("Projectile").unwrap();
+        assert!(projectile.is_empty());
+        assert_eq!(projectile.field::<u32>(0, "owner"), None);
+    }
+
+    fn create_players(players: &[(f32, f32, u32, u32)]) -> DynamicContainer {
+        let mut data = Vec::new();
+        for &(x, y, health, score) in players {
+            data.extend_from_slice(&x.to_ne_bytes());
+            data.extend_from_slice(&y.to_ne_bytes());
+            data.extend_from_slice(&health.to_ne_bytes());
+            data.extend_from_slice(&score.to_ne_bytes());
+        }
+        DynamicContainer::from_raw(data, &create_player_blob()).unwrap()
+    }
+
+    fn scores(container: &DynamicContainer) -> Vec<u32> {
+        container
+            .iter()
+            .map(|i| *container.field::<u32>(i, "score").unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_sort_by_field() {
+        let mut players = create_players(&[
+            (1.0, 1.0, 100, 50),
+            (2.0, 2.0, 80, 120),
+            (3.0, 3.0, 60, 10),
+            (4.0, 4.0, 40, 50),
+        ]);
+
+        players.sort_by_field("score").unwrap();
+        assert_eq!(scores(&players), vec![10, 50, 50, 120]);
+        // Rows move as a whole, and equal keys keep their order
+        let xs: Vec<f32> = players.iter().map(|i| *players.field(i, "x").unwrap()).collect();
+        assert_eq!(xs, vec![3.0, 1.0, 4.0, 2.0]);
+
+        players.sort_by_field_desc("score").unwrap();
+        assert_eq!(scores(&players), vec![120, 50, 50, 10]);
+        let xs: Vec<f32> = players.iter().map(|i| *players.field(i, "x").unwrap()).collect();
+        assert_eq!(xs, vec![2.0, 1.0, 4.0, 3.0]);
+    }
+
+    #[test]
+    fn test_sort_by_field_errors() {
+        let mut players = create_players(&[(1.0, 1.0, 100, 50)]);
+
+        assert!(matches!(
+            players.sort_by_field("missing"),
+            Err(MTFError::FieldNotFound(name)) if name == "missing"
+        ));
+
+        let blob = {
+            // Single 4-bit field
+            let (strings, offsets) = crate::build_string_table(&["Nibble", "v"]);
+            let type_def = TypeDef {
+                name_offset: offsets["Nibble"],
+                size_bits: 8,
+                fields: vec![FieldDef {
+                    name_offset: offsets["v"],
+                    offset_bits: 0,
+                    size_bits: 4,
+                }],
+            };
+            let mut blob = Vec::new();
+            write_mtf(&[type_def], &strings, &mut blob).unwrap();
+            blob
+        };
+        let mut nibbles = DynamicContainer::from_raw(vec![1, 2], &blob).unwrap();
+        assert!(matches!(
+            nibbles.sort_by_field_desc("v"),
+            Err(MTFError::UnsupportedField { size_bits: 4, .. })
+        ));
+    }
+
+    #[test]
+    fn test_field_stats() {
+        let players = create_players(&[
+            (1.5, -2.0, 100, 50),
+            (-3.0, 4.0, 80, 120),
+            (2.5, 0.5, 60, 10),
+        ]);
+
+        let stats = players.field_stats("score").unwrap();
+        assert_eq!(stats.min, 10);
+        assert_eq!(stats.max, 120);
+        assert_eq!(stats.sum, 180);
+        assert_eq!(stats.count, 3);
+        assert_eq!(stats.average(), 60.0);
+
+        let stats = players.float_field_stats("x").unwrap();
+        assert_eq!(stats.min, -3.0);
+        assert_eq!(stats.max, 2.5);
+        assert_eq!(stats.sum, 1.0);
+        assert_eq!(stats.count, 3);
+        assert!((stats.average() - 1.0 / 3.0).abs() < 1e-12);
+
+        assert!(matches!(
+            players.field_stats("missing"),
+            Err(MTFError::FieldNotFound(_))
+        ));
+    }
+
+    #[test]
+    fn test_field_stats_empty() {
+        let players = create_players(&[]);
+
+        let stats = players.field_stats("health").unwrap();
+        assert_eq!(stats, FieldStats { min: 0, max: 0, sum: 0, count: 0 });
+        assert!(stats.average().is_nan());
+
+        let stats = players.float_field_stats("y").unwrap();
+        assert_eq!(stats.count, 0);
+        assert!(stats.average().is_nan());
+    }
+
+    #[test]
+    fn test_append() {
+        let mut a = create_players(&[(1.0, 1.0, 100, 50), (2.0, 2.0, 80, 120)]);
+        let b = create_players(&[(3.0, 3.0, 60, 10)]);
+
+        a.append(&b).unwrap();
+        assert_eq!(a.len(), 3);
+        assert_eq!(scores(&a), vec![50, 120, 10]);
+        assert_eq!(*a.field::<f32>(2, "x").unwrap(), 3.0);
+        assert_eq!(*a.field::<u32>(2, "health").unwrap(), 60);
+        assert_eq!(b.len(), 1);
+    }
+
+    #[test]
+    fn test_append_incompatible() {
+        let mut players = create_players(&[(1.0, 1.0, 100, 50)]);
+        let other = DynamicContainer::from_raw(vec![0; 8], &create_test_blob()).unwrap();
+
+        assert!(matches!(
+            players.append(&other),
+            Err(MTFError::IncompatibleType { expected, found })
+                if expected == "Player" && found == "Test"
+        ));
+        assert_eq!(players.len(), 1);
+    }
+
+    #[test]
+    fn test_swap() {
+        let mut players = create_players(&[(1.0, 2.0, 100, 50), (3.0, 4.0, 80, 120)]);
+
+        players.swap(0, 1).unwrap();
+        assert_eq!(scores(&players), vec![120, 50]);
+        assert_eq!(*players.field::<f32>(0, "x").unwrap(), 3.0);
+        assert_eq!(*players.field::<f32>(1, "y").unwrap(), 2.0);
+
+        players.swap(1, 1).unwrap();
+        assert_eq!(scores(&players), vec![120, 50]);
+
+        assert!(matches!(
+            players.swap(0, 2),
+            Err(MTFError::IndexOutOfBounds { index: 2, len: 2 })
+        ));
+        assert!(matches!(
+            players.swap(5, 5),
+            Err(MTFError::IndexOutOfBounds { index: 5, len: 2 })
+        ));
+    }
+
+    #[test]
+    fn test_filter() {
+        let mut data = Vec::new();
+        for (x, y) in [(10u32, 1u32), (60, 2), (90, 3)] {
+            data.extend_from_slice(&x.to_le_bytes());
+            data.extend_from_slice(&y.to_le_bytes());
+        }
+        let blob = create_test_blob();
+
+        let container = DynamicContainer::from_raw(data, &blob).unwrap();
+        let healthy = container
+            .filter(|c, i| *c.field::<u32>(i, "x").unwrap() > 50)
+            .unwrap();
+
+        assert_eq!(healthy.len(), 2);
+        assert_eq!(healthy.type_name().unwrap(), "Test");
+        assert_eq!(*healthy.field::<u32>(0, "y").unwrap(), 2);
+        assert_eq!(*healthy.field::<u32>(1, "y").unwrap(), 3);
+
+        // The source container is unchanged
+        assert_eq!(container.len(), 3);
+
+        let none = container.filter(|_, _| false).unwrap();
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn test_save_to_writer() {
+        let data = vec![0x01, 0x02, 0x03, 0x04, 0x05, 0x06, 0x07, 0x08];
+        let blob = create_test_blob();
+
+        let container = DynamicContainer::from_raw(data.clone(), &blob).unwrap();
+        let mut out = Vec::new();
+        container.save_to_writer(&mut out).unwrap();
+
+        assert_eq!(&out[..8], &data[..]);
+        assert_eq!(u32::from_le_bytes(out[8..12].try_into().unwrap()) as usize, blob.len());
+        assert_eq!(&out[12..], &blob[..]);
+    }
 }
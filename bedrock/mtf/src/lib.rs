@@ -6,14 +6,191 @@ use std::io::{self, Write};
 use thiserror::Error;
 
 // Re-export the derive macro
+/// Derive macro for [`MTFType`].
+///
+/// Named, tuple and unit structs are supported. Tuple struct fields are
+/// named after their position (`_0`, `_1`, ...):
+///
+/// ```
+/// use mtf::{MTF, MTFType};
+///
+/// #[derive(MTF)]
+/// #[repr(C)]
+/// struct Rgb(u8, u8, u8);
+///
+/// let (types, strings) = mtf::read_mtf(Rgb::mtf_type_blob()).unwrap();
+/// assert_eq!(mtf::read_string(strings, types[0].fields[2].name_offset).unwrap(), "_2");
+/// ```
+///
+/// Fields must be primitives or fixed-size arrays of primitives:
+///
+/// ```compile_fail
+/// use mtf::MTF;
+///
+/// #[derive(MTF)]
+/// #[repr(C)]
+/// struct Named {
+///     name: String,
+/// }
+/// ```
+///
+/// ```compile_fail
+/// use mtf::MTF;
+///
+/// #[derive(MTF)]
+/// #[repr(C)]
+/// struct Wrapper(Vec<u8>);
+/// ```
+///
+/// Integer fields can be narrowed, or split into sub-fields that share the
+/// integer's storage:
+///
+/// ```
+/// use mtf::{MTF, MTFType};
+///
+/// #[derive(MTF)]
+/// #[repr(C)]
+/// struct Status {
+///     #[mtf(bits(low = 4, high = 4))]
+///     nibbles: u8,
+///     #[mtf(bits = 1)]
+///     ready: u8,
+/// }
+///
+/// let (types, _) = mtf::read_mtf(Status::mtf_type_blob()).unwrap();
+/// let layout: Vec<_> = types[0].fields.iter().map(|f| (f.offset_bits, f.size_bits)).collect();
+/// assert_eq!(layout, vec![(0, 4), (4, 4), (8, 1)]);
+/// ```
+///
+/// Sub-fields may not exceed the size of the integer they are packed into:
+///
+/// ```compile_fail
+/// use mtf::MTF;
+///
+/// #[derive(MTF)]
+/// #[repr(C)]
+/// struct Nibbles {
+///     #[mtf(bits(a = 4, b = 5))]
+///     raw: u8,
+/// }
+/// ```
+///
+/// Sub-fields in separate fields are not packed together, since each Rust
+/// field has its own storage, so this struct describes two bytes:
+///
+/// ```
+/// use mtf::{MTF, MTFType};
+///
+/// #[derive(MTF)]
+/// #[repr(C)]
+/// struct Separate {
+///     #[mtf(bits = 4)]
+///     a: u8,
+///     #[mtf(bits = 4)]
+///     b: u8,
+/// }
+///
+/// let (types, _) = mtf::read_mtf(Separate::mtf_type_blob()).unwrap();
+/// assert_eq!(types[0].fields[1].offset_bits, 8);
+/// ```
+///
+/// The struct may not contain padding, since MTF offsets assume the fields
+/// are laid out back to back:
+///
+/// ```compile_fail
+/// use mtf::MTF;
+///
+/// #[derive(MTF)]
+/// #[repr(C)]
+/// struct Padded {
+///     flag: u8,
+///     value: u32,
+/// }
+/// ```
+///
+/// The layout must be `#[repr(C)]`:
+///
+/// ```compile_fail
+/// use mtf::MTF;
+///
+/// #[derive(MTF)]
+/// struct Point(f32, f32);
+/// ```
 #[cfg(feature = "derive")]
 pub use mtf_derive::MTF;
 
 // Re-export bytemuck for users
 pub use bytemuck;
 
+mod crc;
+pub use crc::crc32;
+
+/// Whether blobs carry a trailing CRC-32, read by the derive's generated code.
+#[doc(hidden)]
+pub const __CRC_ENABLED: bool = cfg!(feature = "crc");
+
+/// Turn a version-1 blob into its checksummed version-2 form, used by the
+/// derive's generated code. `M` must be `N + 4`.
+#[doc(hidden)]
+pub const fn __append_crc<const N: usize, const M: usize>(body: &[u8; N]) -> [u8; M] {
+    assert!(M == N + 4 && N >= 8);
+    let mut out = [0u8; M];
+    let mut i = 0;
+    while i < N {
+        out[i] = body[i];
+        i += 1;
+    }
+    let version = MTF_VERSION_CRC.to_le_bytes();
+    let mut i = 0;
+    while i < 4 {
+        out[4 + i] = version[i];
+        i += 1;
+    }
+    let (head, _) = out.split_at(N);
+    let checksum = crc32(head).to_le_bytes();
+    let mut i = 0;
+    while i < 4 {
+        out[N + i] = checksum[i];
+        i += 1;
+    }
+    out
+}
+
+#[cfg(feature = "registry")]
+pub mod registry;
+
+#[cfg(feature = "registry")]
+#[doc(hidden)]
+pub use ctor as __ctor;
+
+/// Registers a derived type at startup, invoked by the derive's generated code.
+#[cfg(feature = "registry")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __register_type {
+    ($ty:ty, $info:expr) => {
+        const _: () = {
+            #[$crate::__ctor::ctor(crate_path = $crate::__ctor)]
+            unsafe fn register() {
+                $crate::registry::register(::core::any::TypeId::of::<$ty>(), $info);
+            }
+        };
+    };
+}
+
+/// Without the `registry` feature, derived types are not registered.
+#[cfg(not(feature = "registry"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __register_type {
+    ($($tt:tt)*) => {};
+}
+
 const MTF_MAGIC: &[u8; 4] = b"MTF\0";
+/// Blobs without a checksum.
 const MTF_VERSION: u32 = 1;
+/// Blobs followed by a CRC-32 of everything before it.
+const MTF_VERSION_CRC: u32 = 2;
 
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct FieldDef {
@@ -41,8 +218,32 @@ pub enum MTFError {
     InvalidUtf8,
     #[error("String offset {0} out of bounds")]
     InvalidStringOffset(u32),
+    #[error("Fields {first} and {second} have overlapping bit ranges")]
+    OverlappingFields { first: usize, second: usize },
+    #[error("Field {field} ends at bit {end_bits}, beyond the type size of {size_bits} bits")]
+    FieldOutOfBounds {
+        field: usize,
+        end_bits: u64,
+        size_bits: u32,
+    },
     #[error("IO error: {0}")]
     Io(#[from] io::Error),
+    #[error("Checksum mismatch: expected {expected:#010x}, found {found:#010x}")]
+    ChecksumMismatch { expected: u32, found: u32 },
+    #[error("Incompatible type: expected layout of `{expected}`, found `{found}`")]
+    IncompatibleType { expected: String, found: String },
+    #[error("Index {index} out of bounds for length {len}")]
+    IndexOutOfBounds { index: usize, len: usize },
+    #[error("Type not found: {0}")]
+    TypeNotFound(String),
+    #[error("Field not found: {0}")]
+    FieldNotFound(String),
+    #[error("Field `{name}` has unsupported layout ({size_bits} bits at bit offset {offset_bits})")]
+    UnsupportedField {
+        name: String,
+        offset_bits: u32,
+        size_bits: u32,
+    },
 }
 
 pub type Result<T> = std::result::Result<T, MTFError>;
@@ -53,33 +254,52 @@ pub trait MTFType {
 }
 
 /// Write MTF metadata blob: [MAGIC][VERSION][TYPE_COUNT][TYPES][STRING_TABLE_SIZE][STRING_TABLE]
+///
+/// With the `crc` feature, the blob is written as version 2 and a CRC-32 of
+/// everything before it is appended: [..][CRC32]
 pub fn write_mtf(types: &[TypeDef], strings: &[u8], mut out: impl Write) -> Result<()> {
-    out.write_all(MTF_MAGIC)?;
-    out.write_all(&MTF_VERSION.to_le_bytes())?;
+    let version = if __CRC_ENABLED {
+        MTF_VERSION_CRC
+    } else {
+        MTF_VERSION
+    };
+    let mut blob = Vec::new();
+    blob.extend_from_slice(MTF_MAGIC);
+    blob.extend_from_slice(&version.to_le_bytes());
 
     let count = types.len() as u32;
-    out.write_all(&count.to_le_bytes())?;
+    blob.extend_from_slice(&count.to_le_bytes());
 
     for t in types {
-        out.write_all(&t.name_offset.to_le_bytes())?;
-        out.write_all(&t.size_bits.to_le_bytes())?;
+        blob.extend_from_slice(&t.name_offset.to_le_bytes());
+        blob.extend_from_slice(&t.size_bits.to_le_bytes());
         let fcount = t.fields.len() as u32;
-        out.write_all(&fcount.to_le_bytes())?;
+        blob.extend_from_slice(&fcount.to_le_bytes());
         for f in &t.fields {
-            out.write_all(&f.name_offset.to_le_bytes())?;
-            out.write_all(&f.offset_bits.to_le_bytes())?;
-            out.write_all(&f.size_bits.to_le_bytes())?;
+            blob.extend_from_slice(&f.name_offset.to_le_bytes());
+            blob.extend_from_slice(&f.offset_bits.to_le_bytes());
+            blob.extend_from_slice(&f.size_bits.to_le_bytes());
         }
     }
 
     let string_len = strings.len() as u32;
-    out.write_all(&string_len.to_le_bytes())?;
-    out.write_all(strings)?;
+    blob.extend_from_slice(&string_len.to_le_bytes());
+    blob.extend_from_slice(strings);
+
+    if version == MTF_VERSION_CRC {
+        let checksum = crc32(&blob);
+        blob.extend_from_slice(&checksum.to_le_bytes());
+    }
 
+    out.write_all(&blob)?;
     Ok(())
 }
 
 /// Read MTF blob, returning type definitions and string table.
+///
+/// Both versions are accepted whatever the `crc` feature says: a version-2
+/// blob's trailing CRC-32 is required and must match before the type
+/// definitions are validated.
 pub fn read_mtf(data: &[u8]) -> Result<(Vec<TypeDef>, &[u8])> {
     let mut pos = 0;
 
@@ -94,7 +314,7 @@ pub fn read_mtf(data: &[u8]) -> Result<(Vec<TypeDef>, &[u8])> {
 
     let version = u32::from_le_bytes(data[pos..pos + 4].try_into().unwrap());
     pos += 4;
-    if version != MTF_VERSION {
+    if version != MTF_VERSION && version != MTF_VERSION_CRC {
         return Err(MTFError::UnsupportedVersion(version));
     }
 
@@ -150,9 +370,68 @@ pub fn read_mtf(data: &[u8]) -> Result<(Vec<TypeDef>, &[u8])> {
     }
     let strings = &data[pos..pos + string_len];
 
+    if version == MTF_VERSION_CRC {
+        let end = pos + string_len;
+        let stored = data.get(end..end + 4).ok_or(MTFError::UnexpectedEof)?;
+        let expected = u32::from_le_bytes(stored.try_into().unwrap());
+        let found = crc32(&data[..end]);
+        if expected != found {
+            return Err(MTFError::ChecksumMismatch { expected, found });
+        }
+    }
+
+    for type_def in &types {
+        validate_type(type_def, strings)?;
+    }
+
     Ok((types, strings))
 }
 
+/// Check that a type's name offsets point into the string table and that its
+/// fields lie within the type and do not overlap each other.
+fn validate_type(type_def: &TypeDef, strings: &[u8]) -> Result<()> {
+    let check_offset = |offset: u32| {
+        if (offset as usize) < strings.len() {
+            Ok(())
+        } else {
+            Err(MTFError::InvalidStringOffset(offset))
+        }
+    };
+
+    check_offset(type_def.name_offset)?;
+    for (i, f) in type_def.fields.iter().enumerate() {
+        check_offset(f.name_offset)?;
+
+        let end_bits = f.offset_bits as u64 + f.size_bits as u64;
+        if end_bits > type_def.size_bits as u64 {
+            return Err(MTFError::FieldOutOfBounds {
+                field: i,
+                end_bits,
+                size_bits: type_def.size_bits,
+            });
+        }
+    }
+
+    // Once sorted by offset, non-overlapping fields each end before the next
+    // begins. Empty fields occupy no bits and cannot overlap.
+    let mut order: Vec<usize> = (0..type_def.fields.len())
+        .filter(|&i| type_def.fields[i].size_bits > 0)
+        .collect();
+    order.sort_by_key(|&i| type_def.fields[i].offset_bits);
+
+    for pair in order.windows(2) {
+        let (a, b) = (&type_def.fields[pair[0]], &type_def.fields[pair[1]]);
+        if a.offset_bits as u64 + a.size_bits as u64 > b.offset_bits as u64 {
+            return Err(MTFError::OverlappingFields {
+                first: pair[0].min(pair[1]),
+                second: pair[0].max(pair[1]),
+            });
+        }
+    }
+
+    Ok(())
+}
+
 pub fn read_string(strings: &[u8], offset: u32) -> Result<&str> {
     let start = offset as usize;
     if start >= strings.len() {
@@ -215,6 +494,7 @@ mod tests {
     fn test_mtf_magic() {
         assert_eq!(MTF_MAGIC, b"MTF\0");
         assert_eq!(MTF_VERSION, 1);
+        assert_eq!(MTF_VERSION_CRC, 2);
     }
 
     #[test]
@@ -269,4 +549,158 @@ mod tests {
         assert_eq!(parsed_types[0], type_def);
         assert_eq!(parsed_strings, strings);
     }
+
+    /// Re-encode `blob` as `version`, adding or dropping the trailing CRC-32.
+    fn with_version(blob: &[u8], version: u32) -> Vec<u8> {
+        let body = if blob[4..8] == MTF_VERSION_CRC.to_le_bytes() {
+            &blob[..blob.len() - 4]
+        } else {
+            blob
+        };
+        let mut out = body.to_vec();
+        out[4..8].copy_from_slice(&version.to_le_bytes());
+        if version == MTF_VERSION_CRC {
+            let checksum = crc32(&out);
+            out.extend_from_slice(&checksum.to_le_bytes());
+        }
+        out
+    }
+
+    #[test]
+    fn test_write_mtf_version_follows_feature() {
+        let blob = blob_with_fields(32, &[(0, 32)]);
+        let expected = if cfg!(feature = "crc") {
+            MTF_VERSION_CRC
+        } else {
+            MTF_VERSION
+        };
+        assert_eq!(blob[4..8], expected.to_le_bytes());
+    }
+
+    #[test]
+    fn test_read_mtf_accepts_both_versions() {
+        let blob = blob_with_fields(32, &[(0, 32)]);
+        let plain = with_version(&blob, MTF_VERSION);
+        let checked = with_version(&blob, MTF_VERSION_CRC);
+        assert_eq!(checked.len(), plain.len() + 4);
+        assert_eq!(read_mtf(&plain).unwrap(), read_mtf(&checked).unwrap());
+
+        assert!(matches!(
+            read_mtf(&with_version(&blob, 3)),
+            Err(MTFError::UnsupportedVersion(3))
+        ));
+    }
+
+    #[test]
+    fn test_append_crc_matches_write_mtf() {
+        let plain = with_version(&blob_with_fields(32, &[(0, 32)]), MTF_VERSION);
+        let body: [u8; 47] = plain.as_slice().try_into().unwrap();
+        let checked: [u8; 51] = __append_crc(&body);
+        assert_eq!(&checked[..], &with_version(&plain, MTF_VERSION_CRC)[..]);
+    }
+
+    #[test]
+    fn test_read_mtf_detects_corruption() {
+        let mut blob = with_version(&blob_with_fields(32, &[(0, 32)]), MTF_VERSION_CRC);
+        assert!(read_mtf(&blob).is_ok());
+
+        // Flip one bit of the type's size_bits
+        blob[16] ^= 0x01;
+        assert!(matches!(read_mtf(&blob), Err(MTFError::ChecksumMismatch { .. })));
+        blob[16] ^= 0x01;
+
+        let last = blob.len() - 5;
+        blob[last] = b'y';
+        assert!(matches!(read_mtf(&blob), Err(MTFError::ChecksumMismatch { .. })));
+
+        blob.truncate(blob.len() - 4);
+        assert!(matches!(read_mtf(&blob), Err(MTFError::UnexpectedEof)));
+    }
+
+    /// Serialize a single type whose fields are `(offset_bits, size_bits)`
+    /// pairs, all named "x".
+    fn blob_with_fields(size_bits: u32, fields: &[(u32, u32)]) -> Vec<u8> {
+        let type_def = TypeDef {
+            name_offset: 0,
+            size_bits,
+            fields: fields
+                .iter()
+                .map(|&(offset_bits, size_bits)| FieldDef {
+                    name_offset: 5,
+                    offset_bits,
+                    size_bits,
+                })
+                .collect(),
+        };
+        let mut output = Vec::new();
+        write_mtf(&[type_def], b"Test\0x\0", &mut output).unwrap();
+        output
+    }
+
+    #[test]
+    fn test_read_mtf_accepts_adjacent_and_empty_fields() {
+        let blob = blob_with_fields(64, &[(32, 32), (0, 4), (4, 28), (8, 0), (64, 0)]);
+        assert!(read_mtf(&blob).is_ok());
+    }
+
+    #[test]
+    fn test_read_mtf_rejects_overlapping_fields() {
+        let blob = blob_with_fields(64, &[(0, 32), (16, 32)]);
+        assert!(matches!(
+            read_mtf(&blob),
+            Err(MTFError::OverlappingFields { first: 0, second: 1 })
+        ));
+
+        // A field nested inside an earlier, wider one
+        let blob = blob_with_fields(64, &[(40, 4), (0, 64)]);
+        assert!(matches!(
+            read_mtf(&blob),
+            Err(MTFError::OverlappingFields { first: 0, second: 1 })
+        ));
+    }
+
+    #[test]
+    fn test_read_mtf_rejects_field_beyond_type_size() {
+        let blob = blob_with_fields(64, &[(0, 32), (40, 32)]);
+        assert!(matches!(
+            read_mtf(&blob),
+            Err(MTFError::FieldOutOfBounds {
+                field: 1,
+                end_bits: 72,
+                size_bits: 64
+            })
+        ));
+
+        // The end is computed without overflowing u32
+        let blob = blob_with_fields(64, &[(u32::MAX, 2)]);
+        assert!(matches!(
+            read_mtf(&blob),
+            Err(MTFError::FieldOutOfBounds { field: 0, .. })
+        ));
+    }
+
+    #[test]
+    fn test_read_mtf_rejects_bad_name_offsets() {
+        let mut output = Vec::new();
+        let type_def = TypeDef {
+            name_offset: 9,
+            size_bits: 0,
+            fields: vec![],
+        };
+        write_mtf(&[type_def], b"Test\0", &mut output).unwrap();
+        assert!(matches!(read_mtf(&output), Err(MTFError::InvalidStringOffset(9))));
+
+        let mut output = Vec::new();
+        let type_def = TypeDef {
+            name_offset: 0,
+            size_bits: 8,
+            fields: vec![FieldDef {
+                name_offset: 5,
+                offset_bits: 0,
+                size_bits: 8,
+            }],
+        };
+        write_mtf(&[type_def], b"Test\0", &mut output).unwrap();
+        assert!(matches!(read_mtf(&output), Err(MTFError::InvalidStringOffset(5))));
+    }
 }
\ No newline at end of file
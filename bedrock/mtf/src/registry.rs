@@ -0,0 +1,51 @@
+//! Process-wide registry of `#[derive(MTF)]` layouts, keyed by [`TypeId`].
+//!
+//! With the `registry` feature, every derived type registers itself before
+//! `main` runs, so raw struct bytes can be opened with
+//! [`DynamicContainer::from_bytes_registered`] without carrying a blob.
+//!
+//! The registry is shared by all threads: startup initializers only run on
+//! the main thread, so per-thread storage would be empty everywhere else.
+//!
+//! [`DynamicContainer::from_bytes_registered`]: crate::dynamic::DynamicContainer::from_bytes_registered
+
+use std::any::TypeId;
+use std::collections::HashMap;
+use std::sync::{LazyLock, PoisonError, RwLock};
+
+/// Layout of a registered type, as described by its derived blob.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RegisteredType {
+    pub name: &'static str,
+    pub size_bits: usize,
+    /// `(name, offset_bits, size_bits)` for each field, in declaration order.
+    pub fields: &'static [(&'static str, usize, usize)],
+}
+
+static REGISTRY: LazyLock<RwLock<HashMap<TypeId, RegisteredType>>> =
+    LazyLock::new(|| RwLock::new(HashMap::new()));
+
+/// Register (or replace) the layout for `id`.
+///
+/// Derived types call this automatically; manual `MTFType` impls may call it
+/// themselves to become visible to [`lookup`].
+pub fn register(id: TypeId, ty: RegisteredType) {
+    REGISTRY
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .insert(id, ty);
+}
+
+/// The layout registered for `id`, if any.
+pub fn lookup(id: TypeId) -> Option<RegisteredType> {
+    REGISTRY
+        .read()
+        .unwrap_or_else(PoisonError::into_inner)
+        .get(&id)
+        .copied()
+}
+
+/// The layout registered for `T`, if any.
+pub fn lookup_type<T: 'static>() -> Option<RegisteredType> {
+    lookup(TypeId::of::<T>())
+}
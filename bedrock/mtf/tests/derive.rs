@@ -0,0 +1,186 @@
+//! Tests for the layouts generated by `#[derive(MTF)]`.
+
+use mtf::dynamic::DynamicContainer;
+use mtf::{MTF, MTFType, read_mtf, read_string};
+
+#[derive(MTF, Copy, Clone)]
+#[repr(C)]
+#[allow(dead_code)]
+struct ArrayStruct {
+    tag: [u8; 4],
+    coords: [f32; 3],
+}
+
+#[derive(MTF, Copy, Clone)]
+#[repr(C)]
+#[allow(dead_code)]
+struct MixedArrays {
+    short: [u16; 2],
+    ids: [u32; 3],
+    pair: [f32; 2],
+    grid: [[i8; 2]; 2],
+}
+
+/// Decode a derived blob into `(type name, size_bits, [(field, offset_bits, size_bits)])`.
+fn layout<T: MTFType>() -> (String, u32, Vec<(String, u32, u32)>) {
+    let (types, strings) = read_mtf(T::mtf_type_blob()).unwrap();
+    assert_eq!(types.len(), 1);
+    let ty = &types[0];
+
+    let fields = ty
+        .fields
+        .iter()
+        .map(|f| {
+            let name = read_string(strings, f.name_offset).unwrap().to_string();
+            (name, f.offset_bits, f.size_bits)
+        })
+        .collect();
+
+    (
+        read_string(strings, ty.name_offset).unwrap().to_string(),
+        ty.size_bits,
+        fields,
+    )
+}
+
+#[test]
+fn test_array_fields() {
+    let (name, size_bits, fields) = layout::<ArrayStruct>();
+
+    assert_eq!(name, "ArrayStruct");
+    assert_eq!(size_bits, 16 * 8);
+    assert_eq!(
+        fields,
+        vec![("tag".to_string(), 0, 32), ("coords".to_string(), 32, 96)]
+    );
+    assert_eq!(size_bits as usize, std::mem::size_of::<ArrayStruct>() * 8);
+}
+
+#[test]
+fn test_mixed_array_fields() {
+    let (_, size_bits, fields) = layout::<MixedArrays>();
+
+    assert_eq!(
+        fields,
+        vec![
+            ("short".to_string(), 0, 32),
+            ("ids".to_string(), 32, 96),
+            ("pair".to_string(), 128, 64),
+            ("grid".to_string(), 192, 32),
+        ]
+    );
+    assert_eq!(size_bits as usize, std::mem::size_of::<MixedArrays>() * 8);
+}
+
+#[derive(MTF, Copy, Clone)]
+#[repr(C)]
+#[allow(dead_code)]
+struct Rgb(u8, u8, u8);
+
+#[derive(MTF, Copy, Clone)]
+#[repr(C, packed)]
+#[allow(dead_code)]
+struct TupleArrays([u8; 2], f64);
+
+#[derive(MTF, Copy, Clone)]
+#[repr(C)]
+struct Marker;
+
+#[test]
+fn test_tuple_struct() {
+    let (name, size_bits, fields) = layout::<Rgb>();
+
+    assert_eq!(name, "Rgb");
+    assert_eq!(size_bits, 24);
+    assert_eq!(
+        fields,
+        vec![
+            ("_0".to_string(), 0, 8),
+            ("_1".to_string(), 8, 8),
+            ("_2".to_string(), 16, 8),
+        ]
+    );
+}
+
+#[test]
+fn test_tuple_struct_with_array() {
+    let (_, size_bits, fields) = layout::<TupleArrays>();
+
+    assert_eq!(size_bits, 80);
+    assert_eq!(
+        fields,
+        vec![("_0".to_string(), 0, 16), ("_1".to_string(), 16, 64)]
+    );
+}
+
+#[test]
+fn test_unit_struct() {
+    let (name, size_bits, fields) = layout::<Marker>();
+
+    assert_eq!(name, "Marker");
+    assert_eq!(size_bits, 0);
+    assert!(fields.is_empty());
+}
+
+#[derive(MTF, Copy, Clone)]
+#[repr(C)]
+#[allow(dead_code)]
+struct Register {
+    #[mtf(bits(low = 4, mid = 3, flag = 1))]
+    control: u8,
+    #[mtf(bits = 5)]
+    level: u8,
+    count: u16,
+    #[mtf(bits(a = 10, b = 2))]
+    config: u16,
+    #[mtf(bits = 1)]
+    ready: bool,
+    #[mtf(bits = 6)]
+    id: u8,
+}
+
+#[test]
+fn test_bit_fields() {
+    let (_, size_bits, fields) = layout::<Register>();
+
+    assert_eq!(
+        fields,
+        vec![
+            // Sub-fields share their backing `u8`, narrowed fields keep their offset
+            ("low".to_string(), 0, 4),
+            ("mid".to_string(), 4, 3),
+            ("flag".to_string(), 7, 1),
+            ("level".to_string(), 8, 5),
+            ("count".to_string(), 16, 16),
+            ("a".to_string(), 32, 10),
+            ("b".to_string(), 42, 2),
+            ("ready".to_string(), 48, 1),
+            ("id".to_string(), 56, 6),
+        ]
+    );
+    assert_eq!(size_bits as usize, std::mem::size_of::<Register>() * 8);
+}
+
+#[test]
+fn test_bit_fields_match_memory_layout() {
+    // The bytes of `Register { control: 0b1011_0110, level: 21, count: 4000,
+    // config: 3 << 10 | 700, ready: true, id: 42 }`; `bool` is not `Pod`
+    let mut data = vec![0b1011_0110, 21];
+    data.extend_from_slice(&4000u16.to_ne_bytes());
+    data.extend_from_slice(&((3u16 << 10) | 700).to_ne_bytes());
+    data.extend_from_slice(&[1, 42]);
+    assert_eq!(data.len(), std::mem::size_of::<Register>());
+    let container = DynamicContainer::from_raw(data, Register::mtf_type_blob()).unwrap();
+
+    let json: serde_json::Value = serde_json::from_str(&container.to_json()).unwrap();
+    let row = &json[0];
+    assert_eq!(row["low"], 0b0110);
+    assert_eq!(row["mid"], 0b011);
+    assert_eq!(row["flag"], 1);
+    assert_eq!(row["level"], 21);
+    assert_eq!(row["count"], 4000);
+    assert_eq!(row["a"], 700);
+    assert_eq!(row["b"], 3);
+    assert_eq!(row["ready"], 1);
+    assert_eq!(row["id"], 42);
+}
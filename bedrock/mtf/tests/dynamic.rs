@@ -0,0 +1,236 @@
+//! Round-trip tests for `DynamicContainer` files.
+
+use std::path::PathBuf;
+
+use mtf::dynamic::DynamicContainer;
+use mtf::{MTF, MTFType, write_slice_with_mtf};
+
+#[derive(MTF, Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+struct Entity {
+    x: f32,
+    y: f32,
+    health: u32,
+}
+
+unsafe impl bytemuck::Pod for Entity {}
+unsafe impl bytemuck::Zeroable for Entity {}
+
+/// A unique path in the system temp directory, removed on drop.
+struct TempPath(PathBuf);
+
+impl TempPath {
+    fn new(name: &str) -> Self {
+        Self(std::env::temp_dir().join(format!("mtf_{}_{}.mtf", name, std::process::id())))
+    }
+}
+
+impl Drop for TempPath {
+    fn drop(&mut self) {
+        let _ = std::fs::remove_file(&self.0);
+    }
+}
+
+fn entities() -> Vec<Entity> {
+    vec![
+        Entity { x: 1.0, y: 2.0, health: 100 },
+        Entity { x: 3.0, y: 4.0, health: 40 },
+        Entity { x: 5.0, y: 6.0, health: 75 },
+    ]
+}
+
+#[test]
+fn test_save_and_reload_mutation() {
+    let original = TempPath::new("save_original");
+    let saved = TempPath::new("save_modified");
+
+    let mut file = std::fs::File::create(&original.0).unwrap();
+    write_slice_with_mtf(&mut file, &entities()).unwrap();
+    drop(file);
+
+    let mut container = DynamicContainer::from_file(&original.0).unwrap();
+    container.field_mut::<u32>(1, "health").set(90);
+    container.field_mut::<f32>(2, "x").add(0.5);
+    container.save_to_file(&saved.0).unwrap();
+
+    let reloaded = DynamicContainer::from_file(&saved.0).unwrap();
+    assert_eq!(reloaded.type_name().unwrap(), "Entity");
+    assert_eq!(reloaded.len(), 3);
+    assert_eq!(*reloaded.field::<u32>(0, "health").unwrap(), 100);
+    assert_eq!(*reloaded.field::<u32>(1, "health").unwrap(), 90);
+    assert_eq!(*reloaded.field::<f32>(2, "x").unwrap(), 5.5);
+
+    // The original file is untouched
+    let untouched = DynamicContainer::from_file(&original.0).unwrap();
+    assert_eq!(*untouched.field::<u32>(1, "health").unwrap(), 40);
+}
+
+#[test]
+fn test_save_to_writer_matches_write_slice_with_mtf() {
+    let mut expected = Vec::new();
+    write_slice_with_mtf(&mut expected, &entities()).unwrap();
+
+    let path = TempPath::new("save_writer");
+    std::fs::write(&path.0, &expected).unwrap();
+    let container = DynamicContainer::from_file(&path.0).unwrap();
+
+    let mut out = Vec::new();
+    container.save_to_writer(&mut out).unwrap();
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn test_to_json_is_valid_json() {
+    let path = TempPath::new("to_json");
+    let mut file = std::fs::File::create(&path.0).unwrap();
+    write_slice_with_mtf(&mut file, &entities()).unwrap();
+    drop(file);
+    let container = DynamicContainer::from_file(&path.0).unwrap();
+
+    let json: serde_json::Value = serde_json::from_str(&container.to_json()).unwrap();
+    let rows = json.as_array().unwrap();
+    assert_eq!(rows.len(), 3);
+    assert_eq!(rows[1]["health"], 40);
+    assert_eq!(rows[1]["x"], 3.0f32.to_bits());
+
+    let json: serde_json::Value =
+        serde_json::from_str(&container.to_json_with_floats(&["x", "y"])).unwrap();
+    assert_eq!(json[2]["x"], 5.0);
+    assert_eq!(json[2]["y"], 6.0);
+    assert_eq!(json[2]["health"], 75);
+}
+
+#[derive(MTF, Copy, Clone)]
+#[repr(C)]
+struct Reading {
+    offset: i64,
+    value: f64,
+    delta: i16,
+    raw: u16,
+    level: i32,
+}
+
+unsafe impl bytemuck::Pod for Reading {}
+unsafe impl bytemuck::Zeroable for Reading {}
+
+#[test]
+fn test_to_json_with_types() {
+    let readings = [Reading {
+        offset: i64::MIN,
+        value: -2.5,
+        delta: -1,
+        raw: u16::MAX,
+        level: -70_000,
+    }];
+    let data = bytemuck::cast_slice(&readings).to_vec();
+    let container = DynamicContainer::from_raw(data, Reading::mtf_type_blob()).unwrap();
+
+    // Without type hints every field is unsigned
+    let json: serde_json::Value = serde_json::from_str(&container.to_json()).unwrap();
+    assert_eq!(json[0]["delta"], u16::MAX);
+    assert_eq!(json[0]["level"], (-70_000i32) as u32);
+
+    let types = mtf::dynamic::JsonTypes {
+        floats: &["value"],
+        signed: &["offset", "delta", "level"],
+    };
+    let json: serde_json::Value = serde_json::from_str(&container.to_json_with(&types)).unwrap();
+    assert_eq!(json[0]["offset"], i64::MIN);
+    assert_eq!(json[0]["value"], -2.5);
+    assert_eq!(json[0]["delta"], -1);
+    assert_eq!(json[0]["raw"], u16::MAX);
+    assert_eq!(json[0]["level"], -70_000);
+}
+
+#[test]
+fn test_project() {
+    let path = TempPath::new("project");
+    let mut file = std::fs::File::create(&path.0).unwrap();
+    write_slice_with_mtf(&mut file, &entities()).unwrap();
+    drop(file);
+    let container = DynamicContainer::from_file(&path.0).unwrap();
+
+    let projected = container.project(&["health", "x"]).unwrap();
+    assert_eq!(projected.len(), 3);
+    assert_eq!(projected.struct_size(), 8);
+    assert_eq!(projected.type_name().unwrap(), "Entity");
+    let mut names = projected.field_names();
+    names.sort();
+    assert_eq!(names, vec!["health", "x"]);
+    assert_eq!(*projected.field::<u32>(1, "health").unwrap(), 40);
+    assert_eq!(*projected.field::<f32>(2, "x").unwrap(), 5.0);
+    assert_eq!(projected.field::<f32>(0, "y"), None);
+    // Fields are packed in the requested order
+    assert_eq!(&projected.raw()[..4], &100u32.to_ne_bytes());
+
+    // The regenerated metadata survives a save and reload
+    let saved = TempPath::new("project_saved");
+    projected.save_to_file(&saved.0).unwrap();
+    let reloaded = DynamicContainer::from_file(&saved.0).unwrap();
+    assert_eq!(reloaded.struct_size(), 8);
+    assert_eq!(*reloaded.field::<u32>(2, "health").unwrap(), 75);
+    assert_eq!(*reloaded.field::<f32>(0, "x").unwrap(), 1.0);
+
+    let single = container.project(&["y"]).unwrap();
+    assert_eq!(single.struct_size(), 4);
+    assert_eq!(*single.field::<f32>(1, "y").unwrap(), 4.0);
+
+    let identity = container.project(&["x", "y", "health"]).unwrap();
+    assert_eq!(identity.struct_size(), container.struct_size());
+    assert_eq!(identity.raw(), container.raw());
+
+    assert!(matches!(
+        container.project(&["x", "mana"]),
+        Err(mtf::MTFError::FieldNotFound(name)) if name == "mana"
+    ));
+}
+
+#[test]
+fn test_typed_iter() {
+    let path = TempPath::new("typed_iter");
+    let mut file = std::fs::File::create(&path.0).unwrap();
+    write_slice_with_mtf(&mut file, &entities()).unwrap();
+    drop(file);
+    let container = DynamicContainer::from_file(&path.0).unwrap();
+
+    let health = container.typed_iter::<u32>("health").unwrap();
+    assert_eq!(health.len(), 3);
+    assert_eq!(health.collect::<Vec<_>>(), vec![100, 40, 75]);
+    assert_eq!(container.typed_iter::<f32>("y").unwrap().sum::<f32>(), 12.0);
+
+    assert!(container.typed_iter::<u32>("mana").is_none());
+    assert!(container.typed_iter::<u64>("health").is_none());
+    assert!(container.typed_iter::<u16>("x").is_none());
+}
+
+#[derive(MTF, Copy, Clone)]
+#[repr(C)]
+struct Packed {
+    #[mtf(bits(low = 4, high = 4))]
+    raw: u8,
+    #[mtf(bits = 4)]
+    level: u8,
+    count: u16,
+}
+
+unsafe impl bytemuck::Pod for Packed {}
+unsafe impl bytemuck::Zeroable for Packed {}
+
+#[test]
+fn test_typed_access_rejects_bit_fields() {
+    let path = TempPath::new("typed_bit_fields");
+    let mut file = std::fs::File::create(&path.0).unwrap();
+    let data = [Packed { raw: 0x21, level: 3, count: 7 }];
+    write_slice_with_mtf(&mut file, &data).unwrap();
+    drop(file);
+    let mut container = DynamicContainer::from_file(&path.0).unwrap();
+
+    // Narrower than a byte, or not starting on one
+    for name in ["low", "high", "level"] {
+        assert!(container.typed_iter::<u8>(name).is_none(), "{name}");
+        assert!(container.field::<u8>(0, name).is_none(), "{name}");
+        assert!(container.field_mut::<u8>(0, name).is_none(), "{name}");
+    }
+    let counts: Vec<u16> = container.typed_iter("count").unwrap().collect();
+    assert_eq!(counts, vec![7]);
+}
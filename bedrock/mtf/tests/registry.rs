@@ -0,0 +1,188 @@
+//! Tests for the startup type registry (`registry` feature).
+#![cfg(feature = "registry")]
+
+use mtf::dynamic::DynamicContainer;
+use mtf::registry::{self, RegisteredType};
+use mtf::{MTF, MTFType};
+
+#[derive(MTF, Copy, Clone, Debug, PartialEq)]
+#[repr(C)]
+struct Particle {
+    x: f32,
+    y: f32,
+    ttl: u32,
+}
+
+unsafe impl bytemuck::Pod for Particle {}
+unsafe impl bytemuck::Zeroable for Particle {}
+
+/// Declares a `u32` wrapper with a hand-written, empty `MTFType` impl.
+///
+/// Each test that touches the registry gets its own type, since tests run in
+/// parallel and share one registry.
+macro_rules! manual_type {
+    ($(#[$doc:meta])* $name:ident) => {
+        $(#[$doc])*
+        #[derive(Copy, Clone)]
+        #[repr(C)]
+        struct $name(u32);
+
+        unsafe impl bytemuck::Pod for $name {}
+        unsafe impl bytemuck::Zeroable for $name {}
+
+        impl MTFType for $name {
+            fn mtf_type_blob() -> &'static [u8] {
+                &[]
+            }
+
+            fn mtf_string_table() -> &'static [u8] {
+                &[]
+            }
+        }
+    };
+}
+
+manual_type!(
+    /// Never registered.
+    Manual
+);
+manual_type!(
+    /// Registered by `test_manual_registration`.
+    ManualRegistered
+);
+manual_type!(
+    /// Registered with a layout that does not match the type.
+    Mismatched
+);
+
+#[test]
+fn test_derived_types_are_registered() {
+    assert_eq!(
+        registry::lookup_type::<Particle>(),
+        Some(RegisteredType {
+            name: "Particle",
+            size_bits: 96,
+            fields: &[("x", 0, 32), ("y", 32, 32), ("ttl", 64, 32)],
+        })
+    );
+    assert_eq!(registry::lookup_type::<Manual>(), None);
+}
+
+#[test]
+fn test_from_bytes_registered() {
+    let particles = [
+        Particle {
+            x: 1.0,
+            y: 2.0,
+            ttl: 30,
+        },
+        Particle {
+            x: -4.0,
+            y: 0.5,
+            ttl: 7,
+        },
+    ];
+    let data = bytemuck::cast_slice(&particles).to_vec();
+    let container = DynamicContainer::from_bytes_registered::<Particle>(data).unwrap();
+
+    assert_eq!(container.type_name().unwrap(), "Particle");
+    assert_eq!(container.len(), 2);
+    assert_eq!(container.struct_size(), 12);
+    assert_eq!(*container.field::<f32>(1, "x").unwrap(), -4.0);
+    assert_eq!(*container.field::<u32>(0, "ttl").unwrap(), 30);
+
+    // The rebuilt metadata matches the derived blob
+    let mut expected = Vec::new();
+    mtf::write_slice_with_mtf(&mut expected, &particles).unwrap();
+    let mut out = Vec::new();
+    container.save_to_writer(&mut out).unwrap();
+    assert_eq!(out, expected);
+}
+
+#[test]
+fn test_unregistered_type() {
+    let result = DynamicContainer::from_bytes_registered::<Manual>(vec![0; 4]);
+    assert!(matches!(result, Err(mtf::MTFError::TypeNotFound(name)) if name.ends_with("Manual")));
+}
+
+#[test]
+fn test_manual_registration() {
+    registry::register(
+        std::any::TypeId::of::<ManualRegistered>(),
+        RegisteredType {
+            name: "ManualRegistered",
+            size_bits: 32,
+            fields: &[("value", 0, 32)],
+        },
+    );
+    let container =
+        DynamicContainer::from_bytes_registered::<ManualRegistered>(9u32.to_ne_bytes().to_vec())
+            .unwrap();
+    assert_eq!(*container.field::<u32>(0, "value").unwrap(), 9);
+}
+
+#[test]
+fn test_registered_layout_is_validated() {
+    let id = std::any::TypeId::of::<Mismatched>();
+    let open = || DynamicContainer::from_bytes_registered::<Mismatched>(vec![0; 4]);
+
+    // Only this test registers `Mismatched`, so each replacement is seen in order
+    registry::register(
+        id,
+        RegisteredType {
+            name: "Mismatched",
+            size_bits: 64,
+            fields: &[("value", 0, 32)],
+        },
+    );
+    assert!(matches!(
+        open(),
+        Err(mtf::MTFError::IncompatibleType { .. })
+    ));
+
+    registry::register(
+        id,
+        RegisteredType {
+            name: "Mismatched",
+            size_bits: 32,
+            fields: &[("low", 0, 16), ("high", 8, 24)],
+        },
+    );
+    assert!(matches!(
+        open(),
+        Err(mtf::MTFError::OverlappingFields {
+            first: 0,
+            second: 1
+        })
+    ));
+
+    registry::register(
+        id,
+        RegisteredType {
+            name: "Mismatched",
+            size_bits: 32,
+            fields: &[("value", 16, 32)],
+        },
+    );
+    assert!(matches!(
+        open(),
+        Err(mtf::MTFError::FieldOutOfBounds {
+            field: 0,
+            end_bits: 48,
+            ..
+        })
+    ));
+
+    registry::register(
+        id,
+        RegisteredType {
+            name: "Mismatched",
+            size_bits: 32,
+            fields: &[("value", usize::MAX, 1)],
+        },
+    );
+    assert!(matches!(
+        open(),
+        Err(mtf::MTFError::FieldOutOfBounds { field: 0, .. })
+    ));
+}
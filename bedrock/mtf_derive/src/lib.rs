@@ -1,8 +1,8 @@
 
 extern crate proc_macro;
 use proc_macro::TokenStream;
-use quote::{ToTokens, quote};
-use syn::{Data, DeriveInput, Expr, ExprLit, Fields, Lit, Type, TypePath, parse_macro_input};
+use quote::{ToTokens, quote, quote_spanned};
+use syn::{Data, DeriveInput, Expr, ExprLit, Lit, Type, TypePath, parse_macro_input};
 
 /// Get the size in bytes for primitive types.
 fn primitive_size_bytes(ident: &str) -> Option<usize> {
@@ -17,28 +17,92 @@ fn primitive_size_bytes(ident: &str) -> Option<usize> {
 }
 
 /// Calculate the size of a type in bytes.
+///
+/// Supports primitives and (possibly nested) fixed-size arrays of primitives,
+/// such as `[u8; 4]`, `[f32; 3]` or `[[u16; 2]; 2]`.
 fn type_size_and_check(ty: &Type) -> Result<usize, String> {
     match ty {
-        Type::Path(TypePath { path, .. }) => {
+        Type::Path(TypePath { qself: None, path }) => {
             let ident = path.segments.last().unwrap().ident.to_string();
             primitive_size_bytes(&ident).ok_or_else(|| format!("unsupported type `{}`", ident))
         }
+        // Types passed through `macro_rules!` fragments arrive wrapped in groups
+        Type::Group(group) => type_size_and_check(&group.elem),
+        Type::Paren(paren) => type_size_and_check(&paren.elem),
         Type::Array(arr) => {
             let elem_size = type_size_and_check(&arr.elem)?;
-            if let Expr::Lit(ExprLit {
-                lit: Lit::Int(len), ..
-            }) = &arr.len
-            {
-                let count = len.base10_parse::<usize>().map_err(|e| e.to_string())?;
-                Ok(elem_size * count)
-            } else {
-                Err("array length must be a literal integer".to_string())
-            }
+            let count = array_len(&arr.len)?;
+            elem_size
+                .checked_mul(count)
+                .ok_or_else(|| "array size overflows usize".to_string())
         }
         _ => Err(format!("unsupported type: {}", quote! { #ty })),
     }
 }
 
+/// Parse a literal array length, looking through invisible groups.
+fn array_len(expr: &Expr) -> Result<usize, String> {
+    match expr {
+        Expr::Lit(ExprLit {
+            lit: Lit::Int(len), ..
+        }) => len.base10_parse::<usize>().map_err(|e| e.to_string()),
+        Expr::Group(group) => array_len(&group.expr),
+        Expr::Paren(paren) => array_len(&paren.expr),
+        _ => Err("array length must be a literal integer".to_string()),
+    }
+}
+
+/// How a field is narrowed by its `#[mtf(..)]` attribute.
+enum FieldBits {
+    /// `bits = N`: the field keeps its offset but only its low `N` bits are used.
+    Narrow(usize),
+    /// `bits(a = N, ..)`: the field is split into named sub-fields, packed
+    /// from its lowest bit upwards.
+    Split(Vec<(syn::Ident, usize)>),
+}
+
+/// Parse the `#[mtf(bits = N)]` or `#[mtf(bits(a = N, ..))]` attribute of a
+/// field, if present.
+fn field_bits(field: &syn::Field) -> syn::Result<Option<FieldBits>> {
+    let mut bits = None;
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("mtf")) {
+        attr.parse_nested_meta(|meta| {
+            if !meta.path.is_ident("bits") {
+                return Err(meta.error(
+                    "unsupported mtf attribute, expected `bits = N` or `bits(name = N, ..)`",
+                ));
+            }
+            if meta.input.peek(syn::Token![=]) {
+                let lit: syn::LitInt = meta.value()?.parse()?;
+                bits = Some(FieldBits::Narrow(lit.base10_parse()?));
+                return Ok(());
+            }
+            let mut parts = Vec::new();
+            meta.parse_nested_meta(|sub| {
+                let ident = sub
+                    .path
+                    .get_ident()
+                    .cloned()
+                    .ok_or_else(|| sub.error("expected a sub-field name"))?;
+                let lit: syn::LitInt = sub.value()?.parse()?;
+                parts.push((ident, lit.base10_parse()?));
+                Ok(())
+            })?;
+            bits = Some(FieldBits::Split(parts));
+            Ok(())
+        })?;
+    }
+    Ok(bits)
+}
+
+/// Types that may be narrowed with `#[mtf(bits = N)]`.
+fn is_bit_field_type(ty_name: &str) -> bool {
+    matches!(
+        ty_name,
+        "u8" | "i8" | "bool" | "u16" | "i16" | "u32" | "i32" | "u64" | "i64" | "u128" | "i128"
+    )
+}
+
 /// Check if the type has #[repr(C)] or #[repr(C, packed)]
 fn check_repr_c(input: &DeriveInput) -> bool {
     input.attrs.iter().any(|attr| {
@@ -50,7 +114,33 @@ fn check_repr_c(input: &DeriveInput) -> bool {
     })
 }
 
-#[proc_macro_derive(MTF)]
+/// Derives `mtf::MTFType`, embedding the struct layout as an MTF blob.
+///
+/// The struct must be `#[repr(C)]` (or `#[repr(C, packed)]`) and every field
+/// must be a primitive or a fixed-size array of primitives.
+///
+/// - Named fields keep their names.
+/// - Tuple struct fields are named after their position: `_0`, `_1`, ...
+/// - Unit structs produce a type with `size_bits = 0` and no fields.
+///
+/// An integer or `bool` field can be narrowed with `#[mtf(bits = N)]`; it
+/// keeps its offset, but only its low `N` bits are described. To pack several
+/// values into one integer, split it into named sub-fields with
+/// `#[mtf(bits(name = N, ..))]`. They are laid out from the lowest bit up and
+/// their widths may not add up to more than the integer's size:
+///
+/// ```ignore
+/// #[derive(MTF)]
+/// #[repr(C)]
+/// struct Status {
+///     #[mtf(bits(low = 4, high = 4))]
+///     nibbles: u8, // `low` at offset 0, `high` at offset 4, 4 bits each
+///     #[mtf(bits = 1)]
+///     ready: u8,   // offset 8, 1 bit
+///     count: u16,  // offset 16, 16 bits
+/// }
+/// ```
+#[proc_macro_derive(MTF, attributes(mtf))]
 pub fn derive_mtf(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
@@ -65,31 +155,121 @@ pub fn derive_mtf(input: TokenStream) -> TokenStream {
         .into();
     }
 
-    let mut fields_info = Vec::<(String, usize)>::new();
-    let mut total_size = 0usize;
-
-    if let Data::Struct(ds) = &input.data {
-        if let Fields::Named(named) = &ds.fields {
-            for f in named.named.iter() {
-                let fname = f.ident.as_ref().unwrap().to_string();
-                match type_size_and_check(&f.ty) {
-                    Ok(sz) => {
-                        total_size += sz;
-                        fields_info.push((fname, sz));
-                    }
-                    Err(e) => return syn::Error::new_spanned(&f.ty, e).to_compile_error().into(),
-                }
-            }
-        } else {
-            return syn::Error::new_spanned(&input.ident, "Only named fields supported")
+    let fields = match &input.data {
+        Data::Struct(ds) => &ds.fields,
+        _ => {
+            return syn::Error::new_spanned(&input.ident, "Only structs supported")
                 .to_compile_error()
                 .into();
         }
-    } else {
-        return syn::Error::new_spanned(&input.ident, "Only structs supported")
+    };
+
+    // (name, offset_bits, size_bits)
+    let mut fields_info = Vec::<(String, usize, usize)>::new();
+    let mut offset_bits = 0usize;
+    // Sub-fields may not reuse the name of any real field, before or after them
+    let field_names: Vec<String> = fields
+        .iter()
+        .filter_map(|f| f.ident.as_ref().map(|ident| ident.to_string()))
+        .collect();
+
+    // Named fields keep their names, tuple fields become `_0`, `_1`, ...
+    // and unit structs have no fields at all.
+    for (i, f) in fields.iter().enumerate() {
+        let fname = match &f.ident {
+            Some(ident) => ident.to_string(),
+            None => format!("_{}", i),
+        };
+        let sz = match type_size_and_check(&f.ty) {
+            Ok(sz) => sz,
+            Err(e) => return syn::Error::new_spanned(&f.ty, e).to_compile_error().into(),
+        };
+        let bits = match field_bits(f) {
+            Ok(bits) => bits,
+            Err(e) => return e.to_compile_error().into(),
+        };
+
+        let ty = &f.ty;
+        let ty_name = quote! { #ty }.to_string();
+        if bits.is_some() && !is_bit_field_type(&ty_name) {
+            return syn::Error::new_spanned(
+                f,
+                format!(
+                    "#[mtf(bits ..)] is only supported on integer and bool fields, not `{}`",
+                    ty_name
+                ),
+            )
             .to_compile_error()
             .into();
+        }
+
+        match bits {
+            None => fields_info.push((fname, offset_bits, sz * 8)),
+            Some(FieldBits::Narrow(bits)) => {
+                if bits == 0 || bits > sz * 8 {
+                    return syn::Error::new_spanned(
+                        f,
+                        format!(
+                            "`bits = {}` must be between 1 and the {} bits of `{}`",
+                            bits,
+                            sz * 8,
+                            ty_name
+                        ),
+                    )
+                    .to_compile_error()
+                    .into();
+                }
+                fields_info.push((fname, offset_bits, bits));
+            }
+            Some(FieldBits::Split(parts)) => {
+                if ty_name == "bool" {
+                    return syn::Error::new_spanned(f, "a `bool` cannot be split into sub-fields")
+                        .to_compile_error()
+                        .into();
+                }
+                let mut used_bits = 0;
+                for (ident, bits) in parts {
+                    let sub_name = ident.to_string();
+                    if bits == 0 {
+                        return syn::Error::new_spanned(
+                            &ident,
+                            format!("sub-field `{}` needs at least 1 bit", sub_name),
+                        )
+                        .to_compile_error()
+                        .into();
+                    }
+                    if used_bits + bits > sz * 8 {
+                        return syn::Error::new_spanned(
+                            &ident,
+                            format!(
+                                "sub-fields of `{}` need {} bits, but a `{}` only has {}",
+                                fname,
+                                used_bits + bits,
+                                ty_name,
+                                sz * 8
+                            ),
+                        )
+                        .to_compile_error()
+                        .into();
+                    }
+                    if field_names.contains(&sub_name)
+                        || fields_info.iter().any(|(n, _, _)| *n == sub_name)
+                    {
+                        return syn::Error::new_spanned(
+                            &ident,
+                            format!("duplicate field name `{}`", sub_name),
+                        )
+                        .to_compile_error()
+                        .into();
+                    }
+                    fields_info.push((sub_name, offset_bits + used_bits, bits));
+                    used_bits += bits;
+                }
+            }
+        }
+        offset_bits += sz * 8;
     }
+    let total_bits = offset_bits;
 
     // Build string table
     let mut strings = Vec::new();
@@ -99,7 +279,7 @@ pub fn derive_mtf(input: TokenStream) -> TokenStream {
     strings.push(0);
 
     let mut field_name_offsets = Vec::new();
-    for (fname, _) in &fields_info {
+    for (fname, _, _) in &fields_info {
         let offset = strings.len() as u32;
         field_name_offsets.push(offset);
         strings.extend_from_slice(fname.as_bytes());
@@ -112,29 +292,58 @@ pub fn derive_mtf(input: TokenStream) -> TokenStream {
     blob.extend_from_slice(&1u32.to_le_bytes());
     blob.extend_from_slice(&1u32.to_le_bytes());
     blob.extend_from_slice(&type_name_offset.to_le_bytes());
-    blob.extend_from_slice(&((total_size * 8) as u32).to_le_bytes());
+    blob.extend_from_slice(&(total_bits as u32).to_le_bytes());
     blob.extend_from_slice(&(fields_info.len() as u32).to_le_bytes());
 
-    let mut offset_bits = 0usize;
-    for (i, (_fname, sz)) in fields_info.iter().enumerate() {
+    for (i, (_fname, field_offset, size_bits)) in fields_info.iter().enumerate() {
         let name_off = field_name_offsets[i];
         blob.extend_from_slice(&name_off.to_le_bytes());
-        blob.extend_from_slice(&(offset_bits as u32).to_le_bytes());
-        blob.extend_from_slice(&((sz * 8) as u32).to_le_bytes());
-        offset_bits += sz * 8;
+        blob.extend_from_slice(&(*field_offset as u32).to_le_bytes());
+        blob.extend_from_slice(&(*size_bits as u32).to_le_bytes());
     }
 
     blob.extend_from_slice(&(strings.len() as u32).to_le_bytes());
     blob.extend_from_slice(&strings);
 
+    let blob_len = blob.len();
+    let checked_len = blob_len + 4;
     let blob_bytes = blob.iter().map(|b| quote! { #b }).collect::<Vec<_>>();
 
     let ident = &input.ident;
 
+    let message = format!(
+        "`{}` contains padding: MTF fields cover {} bytes, but the struct is larger",
+        name,
+        total_bits / 8
+    );
+    let size_check = quote_spanned! {ident.span()=>
+        const _: () = assert!(::core::mem::size_of::<#ident>() * 8 == #total_bits, #message);
+    };
+
+    let registered_fields = fields_info.iter().map(|(fname, field_offset, size_bits)| {
+        quote! { (#fname, #field_offset, #size_bits) }
+    });
+
     let expanded = quote! {
+        #size_check
+
+        mtf::__register_type!(#ident, mtf::registry::RegisteredType {
+            name: #name,
+            size_bits: #total_bits,
+            fields: &[ #( #registered_fields ),* ],
+        });
+
         impl mtf::MTFType for #ident {
             fn mtf_type_blob() -> &'static [u8] {
-                &[ #( #blob_bytes ),* ]
+                // The checksummed form is only used when the `mtf` crate
+                // was built with its `crc` feature.
+                const BLOB: [u8; #blob_len] = [ #( #blob_bytes ),* ];
+                const CHECKED: [u8; #checked_len] = mtf::__append_crc(&BLOB);
+                if mtf::__CRC_ENABLED {
+                    &CHECKED
+                } else {
+                    &BLOB
+                }
             }
 
             fn mtf_string_table() -> &'static [u8] {
@@ -169,4 +378,20 @@ mod tests {
         assert_eq!(primitive_size_bytes("String"), None);
         assert_eq!(primitive_size_bytes("Vec"), None);
     }
+
+    #[test]
+    fn test_array_sizes() {
+        let size = |s: &str| type_size_and_check(&syn::parse_str::<Type>(s).unwrap());
+        assert_eq!(size("[u8; 4]"), Ok(4));
+        assert_eq!(size("[u16; 3]"), Ok(6));
+        assert_eq!(size("[u32; 3]"), Ok(12));
+        assert_eq!(size("[f32; 2]"), Ok(8));
+        assert_eq!(size("[i64; 1]"), Ok(8));
+        assert_eq!(size("[bool; 0]"), Ok(0));
+        assert_eq!(size("[[u16; 2]; 3]"), Ok(12));
+        assert_eq!(size("(u32)"), Ok(4));
+        assert_eq!(size("[u8; (4)]"), Ok(4));
+        assert!(size("[String; 2]").is_err());
+        assert!(size("[u8; LEN]").is_err());
+    }
 }
\ No newline at end of file
@@ -0,0 +1,79 @@
+// benches/bit_ops.rs
+
+use criterion::{Criterion, black_box, criterion_group, criterion_main};
+use packed_bits::bit_ops::{count_set_bits, get_bits, get_bits_batch, set_bits};
+use packed_bits::{FlagsContainer, PackedBitsContainer};
+
+const COUNT: usize = 10_000;
+const WIDTH: usize = 12;
+
+/// A buffer holding `COUNT` packed 12-bit values.
+fn create_buffer() -> Vec<u8> {
+    let mut buf = vec![0u8; (COUNT * WIDTH).div_ceil(8)];
+    for i in 0..COUNT {
+        set_bits(&mut buf, i * WIDTH, WIDTH, (i % 4096) as u64);
+    }
+    buf
+}
+
+fn bench_read_all(c: &mut Criterion) {
+    let buf = create_buffer();
+
+    let mut group = c.benchmark_group("read_10k_12bit");
+
+    group.bench_function("get_bits_loop", |b| {
+        let mut out = Vec::with_capacity(COUNT);
+        b.iter(|| {
+            out.clear();
+            for i in 0..COUNT {
+                out.push(get_bits(black_box(&buf), i * WIDTH, WIDTH) as u32);
+            }
+            out.len()
+        });
+    });
+
+    group.bench_function("get_bits_batch", |b| {
+        let mut out = Vec::with_capacity(COUNT);
+        b.iter(|| {
+            get_bits_batch(black_box(&buf), 0, WIDTH, COUNT, &mut out);
+            out.len()
+        });
+    });
+
+    group.finish();
+}
+
+fn bench_count_set(c: &mut Criterion) {
+    let mut flags = FlagsContainer::<16>::with_capacity(100_000).unwrap();
+    for i in 0..100_000u32 {
+        flags.push(i.wrapping_mul(2_654_435_761) & 0xFFFF).unwrap();
+    }
+
+    let packed = flags.packed_bits();
+    let start = PackedBitsContainer::<16>::data_bit_offset_static(0) / 8;
+    let data = &packed.storage().as_slice()[start..start + (packed.len() * 16).div_ceil(8)];
+    assert_eq!(
+        count_set_bits(data),
+        flags.iter().map(|f| f.as_raw().count_ones() as u64).sum::<u64>()
+    );
+
+    let mut group = c.benchmark_group("count_set_100k_16bit");
+
+    group.bench_function("per_element", |b| {
+        b.iter(|| {
+            black_box(&flags)
+                .iter()
+                .map(|f| f.as_raw().count_ones() as u64)
+                .sum::<u64>()
+        });
+    });
+
+    group.bench_function("count_set_bits", |b| {
+        b.iter(|| count_set_bits(black_box(data)));
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_read_all, bench_count_set);
+criterion_main!(benches);
@@ -1,3 +1,5 @@
+//! Low-level bit reading and writing on byte slices.
+
 //     #[inline(always)]
 //     pub(crate) fn set_bits(slice: &mut [u8], bit_pos: usize, n: usize, value: u32) {
 //         let byte_pos = bit_pos / 8;
@@ -41,6 +43,9 @@
 //     ((val >> bit_offset) & mask) as u32
 // }
 
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
 // slow but hopefully correct version
 pub fn set_bits(slice: &mut [u8], bit_offset: usize, bit_width: usize, value: u64) {
     let masked = value & ((1u64 << bit_width) - 1);
@@ -74,6 +79,101 @@ pub fn get_bits(slice: &[u8], bit_offset: usize, bit_width: usize) -> u64 {
     value
 }
 
+/// Reads `count` consecutive `bit_width`-bit values starting at `bit_offset` into `out`.
+///
+/// `out` is cleared first. Each value is extracted from a little-endian 64-bit window,
+/// so the per-bit loop of [`get_bits`] is avoided.
+///
+/// # Panics
+///
+/// Panics if `bit_width > 32` or if the values extend past the end of `slice`.
+pub fn get_bits_batch(
+    slice: &[u8],
+    bit_offset: usize,
+    bit_width: usize,
+    count: usize,
+    out: &mut Vec<u32>,
+) {
+    assert!(bit_width <= 32, "bit width must be at most 32");
+    assert!(
+        bit_offset + bit_width * count <= slice.len() * 8,
+        "values extend past the end of the slice"
+    );
+
+    out.clear();
+    out.reserve(count);
+
+    let mask = if bit_width == 32 {
+        u32::MAX as u64
+    } else {
+        (1u64 << bit_width) - 1
+    };
+
+    let mut pos = bit_offset;
+    for _ in 0..count {
+        let byte = pos / 8;
+        let shift = pos % 8;
+
+        let window = match slice.get(byte..byte + 8) {
+            Some(bytes) => u64::from_le_bytes(bytes.try_into().unwrap()),
+            None => {
+                // Near the end of the slice: gather the remaining bytes one by one
+                let mut buf = [0u8; 8];
+                let tail = &slice[byte..];
+                buf[..tail.len()].copy_from_slice(tail);
+                u64::from_le_bytes(buf)
+            }
+        };
+
+        out.push(((window >> shift) & mask) as u32);
+        pos += bit_width;
+    }
+}
+
+/// Counts the set bits in `slice`.
+///
+/// Bytes are processed eight at a time as `u64` words, which lets LLVM emit its
+/// population count instruction where available.
+pub fn count_set_bits(slice: &[u8]) -> u64 {
+    let mut chunks = slice.chunks_exact(8);
+    let mut total: u64 = chunks
+        .by_ref()
+        .map(|chunk| u64::from_le_bytes(chunk.try_into().unwrap()).count_ones() as u64)
+        .sum();
+
+    total += chunks
+        .remainder()
+        .iter()
+        .map(|byte| byte.count_ones() as u64)
+        .sum::<u64>();
+    total
+}
+
+/// Returns the position of the first set bit at or after `start_bit`.
+///
+/// Bits are numbered LSB-first within each byte, matching [`get_bits`]. Returns
+/// `None` if no bit is set in that range or `start_bit` is past the end of `slice`.
+pub fn find_first_set_from(slice: &[u8], start_bit: usize) -> Option<usize> {
+    let first = start_bit / 8;
+    if first >= slice.len() {
+        return None;
+    }
+
+    // Ignore the bits below `start_bit` in the first byte
+    let head = slice[first] & (0xFFu8 << (start_bit % 8));
+    if head != 0 {
+        return Some(first * 8 + head.trailing_zeros() as usize);
+    }
+
+    slice[first + 1..]
+        .iter()
+        .position(|&byte| byte != 0)
+        .map(|offset| {
+            let byte = first + 1 + offset;
+            byte * 8 + slice[byte].trailing_zeros() as usize
+        })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -84,4 +184,94 @@ mod tests {
         set_bits(&mut buf, 3, 5, 0b10101);
         assert_eq!(get_bits(&buf, 3, 5), 0b10101);
     }
+
+    #[test]
+    fn batch_matches_get_bits() {
+        let mut buf = [0u8; 32];
+        for (i, value) in (0..20u64).map(|i| i * 197 % 4096).enumerate() {
+            set_bits(&mut buf, 5 + i * 12, 12, value);
+        }
+
+        let mut out = vec![99];
+        get_bits_batch(&buf, 5, 12, 20, &mut out);
+
+        let expected: Vec<u32> = (0..20)
+            .map(|i| get_bits(&buf, 5 + i * 12, 12) as u32)
+            .collect();
+        assert_eq!(out, expected);
+    }
+
+    #[test]
+    fn batch_full_width_at_end() {
+        let buf = [0xFFu8, 0x00, 0x12, 0x34, 0x56, 0x78];
+        let mut out = Vec::new();
+        get_bits_batch(&buf, 16, 32, 1, &mut out);
+        assert_eq!(out, vec![0x7856_3412]);
+
+        get_bits_batch(&buf, 0, 8, 0, &mut out);
+        assert!(out.is_empty());
+    }
+
+    #[test]
+    #[should_panic(expected = "past the end")]
+    fn batch_out_of_range() {
+        let mut out = Vec::new();
+        get_bits_batch(&[0u8; 3], 0, 12, 3, &mut out);
+    }
+
+    #[test]
+    fn count_set_bits_all_zero() {
+        assert_eq!(count_set_bits(&[]), 0);
+        assert_eq!(count_set_bits(&[0u8; 37]), 0);
+    }
+
+    #[test]
+    fn count_set_bits_all_one() {
+        assert_eq!(count_set_bits(&[0xFF; 1]), 8);
+        assert_eq!(count_set_bits(&[0xFF; 37]), 37 * 8);
+    }
+
+    #[test]
+    fn count_set_bits_mixed() {
+        let buf: Vec<u8> = (0..=255u8).collect();
+        let expected: u64 = buf.iter().map(|b| b.count_ones() as u64).sum();
+        assert_eq!(count_set_bits(&buf), expected);
+        assert_eq!(count_set_bits(&[0b1010_0001, 0x80, 0, 0x0F]), 8);
+    }
+
+    #[test]
+    fn find_first_set_all_zero() {
+        assert_eq!(find_first_set_from(&[0u8; 16], 0), None);
+        assert_eq!(find_first_set_from(&[], 0), None);
+    }
+
+    #[test]
+    fn find_first_set_single_bit() {
+        let mut buf = [0u8; 16];
+        buf[9] = 0b0001_0000;
+        assert_eq!(find_first_set_from(&buf, 0), Some(76));
+        assert_eq!(find_first_set_from(&buf, 76), Some(76));
+        assert_eq!(find_first_set_from(&buf, 77), None);
+    }
+
+    #[test]
+    fn find_first_set_first_and_last_bit() {
+        let mut buf = [0u8; 4];
+        buf[0] = 0x01;
+        buf[3] = 0x80;
+        assert_eq!(find_first_set_from(&buf, 0), Some(0));
+        assert_eq!(find_first_set_from(&buf, 1), Some(31));
+        assert_eq!(find_first_set_from(&buf, 31), Some(31));
+        assert_eq!(find_first_set_from(&buf, 32), None);
+    }
+
+    #[test]
+    fn find_first_set_unaligned_start() {
+        // Bits 1 and 5 in the first byte, bit 2 in the second
+        let buf = [0b0010_0010u8, 0b0000_0100];
+        assert_eq!(find_first_set_from(&buf, 2), Some(5));
+        assert_eq!(find_first_set_from(&buf, 5), Some(5));
+        assert_eq!(find_first_set_from(&buf, 6), Some(10));
+        assert_eq!(find_first_set_from(&buf, 11), None);
+    }
 }
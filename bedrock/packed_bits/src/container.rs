@@ -36,33 +36,86 @@
 //!
 use crate::PackedBitsError;
 use crate::bit_ops;
+use core::ops::{Bound, RangeBounds};
 pub use raw_bytes::Container;
+#[cfg(feature = "mmap")]
+use raw_bytes::ContainerError;
 
 #[cfg(not(feature = "std"))]
 use alloc::vec;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
 
 const MAGIC: &[u8; 4] = b"PKBT";
 const HEADER_SIZE: usize = 12;
 
-#[derive(Debug)]
+/// Where the container's bytes live, which decides what mutation is allowed.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Backing {
+    InMemory,
+    #[cfg(feature = "mmap")]
+    MmapReadOnly,
+    #[cfg(feature = "mmap")]
+    MmapReadWrite,
+}
+
 pub struct PackedBitsContainer<const N: usize> {
     storage: Container<u8>,
     len: usize,
+    backing: Backing,
 }
 
-type Result<T, PackedBitsError> = core::result::Result<T, PackedBitsError>;
+/// Number of elements shown by the `Debug` output before it is truncated.
+const DEBUG_PREVIEW: usize = 8;
 
-/// Validates the bit width N.
-#[inline(always)]
-fn validate_n<const N: usize>() -> Result<(), PackedBitsError> {
-    if (1..=32).contains(&N) {
-        Ok(())
-    } else {
-        Err(PackedBitsError::InvalidBitWidth(N))
+/// Formats `Name<N> { len: .., values: [0x.., ..] }`, listing at most
+/// [`DEBUG_PREVIEW`] values so formatting large containers stays cheap.
+pub(crate) fn debug_preview<const N: usize>(
+    f: &mut core::fmt::Formatter<'_>,
+    name: &str,
+    container: &PackedBitsContainer<N>,
+) -> core::fmt::Result {
+    struct Hex(u32);
+    impl core::fmt::Debug for Hex {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            write!(f, "{:#X}", self.0)
+        }
     }
+
+    struct Values<'a, const N: usize>(&'a PackedBitsContainer<N>);
+    impl<const N: usize> core::fmt::Debug for Values<'_, N> {
+        fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+            let mut list = f.debug_list();
+            list.entries(self.0.iter().take(DEBUG_PREVIEW).map(Hex));
+            if self.0.len() > DEBUG_PREVIEW {
+                list.entry(&format_args!("..."));
+            }
+            list.finish()
+        }
+    }
+
+    write!(f, "{}<{}>", name, N)?;
+    f.debug_struct("")
+        .field("len", &container.len())
+        .field("values", &Values(container))
+        .finish()
 }
 
+impl<const N: usize> core::fmt::Debug for PackedBitsContainer<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        debug_preview(f, "PackedBitsContainer", self)
+    }
+}
+
+type Result<T, PackedBitsError> = core::result::Result<T, PackedBitsError>;
+
 impl<const N: usize> PackedBitsContainer<N> {
+    /// Validates the bit width N.
+    ///
+    /// Constructors evaluate this, so an out-of-range `N` fails to compile
+    /// wherever the container is first instantiated.
+    const VALID_N: () = assert!(N > 0 && N <= 32, "N must be 1..=32");
+
     /// Creates a new in-memory container.
     ///
     /// # Examples
@@ -73,27 +126,38 @@ impl<const N: usize> PackedBitsContainer<N> {
     /// let container = PackedBitsContainer::<8>::new_in_memory().expect("failed to create container");
     /// assert_eq!(container.len(), 0);
     /// ```
+    ///
+    /// The bit width is checked at compile time: `PackedBitsContainer::<0>`
+    /// and `PackedBitsContainer::<33>` fail to build.
     pub fn new_in_memory() -> Result<Self, PackedBitsError> {
         //assert!(N > 0 && N <= 32, "N must be 1..=32");
-        validate_n::<N>()?;
+        let () = Self::VALID_N;
         let mut storage = Container::from_slice(&vec![0u8; HEADER_SIZE]);
         Self::write_header(&mut storage, 0).expect("failed to write header");
-        Ok(Self { storage, len: 0 })
+        Ok(Self {
+            storage,
+            len: 0,
+            backing: Backing::InMemory,
+        })
     }
 
     pub fn with_capacity(capacity: usize) -> Result<Self, PackedBitsError> {
         //assert!(N > 0 && N <= 32, "N must be 1..=32");
-        validate_n::<N>()?;
+        let () = Self::VALID_N;
         let data_bytes = (capacity * N).div_ceil(8);
         let total_bytes = HEADER_SIZE + data_bytes;
         let mut storage = Container::from_slice(&vec![0u8; total_bytes]);
         Self::write_header(&mut storage, 0).expect("failed to write header");
-        Ok(Self { storage, len: 0 })
+        Ok(Self {
+            storage,
+            len: 0,
+            backing: Backing::InMemory,
+        })
     }
 
     pub fn from_storage(storage: Container<u8>) -> Result<Self, PackedBitsError> {
         //assert!(N > 0 && N <= 32, "N must be 1..=32");
-        validate_n::<N>()?;
+        let () = Self::VALID_N;
         if storage.len() < HEADER_SIZE {
             return Err(PackedBitsError::StorageTooSmall);
         }
@@ -109,15 +173,65 @@ impl<const N: usize> PackedBitsContainer<N> {
             });
         }
         let len = u32::from_le_bytes([slice[8], slice[9], slice[10], slice[11]]) as usize;
-        Ok(Self { storage, len })
+        if slice.len() < HEADER_SIZE + (len * N).div_ceil(8) {
+            return Err(PackedBitsError::InsufficientBytes(len));
+        }
+        Ok(Self {
+            storage,
+            len,
+            backing: Backing::InMemory,
+        })
+    }
+
+    /// Memory-maps a file written from a container's storage, read-only.
+    ///
+    /// The file must start with the same header as [`from_storage`](Self::from_storage)
+    /// expects. Mutating methods return [`PackedBitsError::StorageReadOnly`].
+    #[cfg(feature = "mmap")]
+    pub fn mmap_readonly<P: AsRef<std::path::Path>>(path: P) -> Result<Self, PackedBitsError> {
+        let mut container = Self::from_storage(Container::mmap_readonly(path)?)?;
+        container.backing = Backing::MmapReadOnly;
+        Ok(container)
+    }
+
+    /// Memory-maps a file written from a container's storage for reading and writing.
+    ///
+    /// `set` writes through to the file. The mapping cannot grow, so `push`
+    /// is not supported.
+    #[cfg(feature = "mmap")]
+    pub fn mmap_readwrite<P: AsRef<std::path::Path>>(path: P) -> Result<Self, PackedBitsError> {
+        let mut container = Self::from_storage(Container::mmap_readwrite(path)?)?;
+        container.backing = Backing::MmapReadWrite;
+        Ok(container)
     }
 
     pub fn from_storage_raw(storage: Container<u8>) -> Self {
+        let () = Self::VALID_N;
         let len_elements = (storage.len() * 8) / N;
         Self {
             storage,
             len: len_elements,
+            backing: Backing::InMemory,
+        }
+    }
+
+    /// Fails if the storage cannot be written to.
+    fn check_writable(&self) -> Result<(), PackedBitsError> {
+        match self.backing {
+            #[cfg(feature = "mmap")]
+            Backing::MmapReadOnly => Err(PackedBitsError::StorageReadOnly),
+            _ => Ok(()),
+        }
+    }
+
+    /// Mappings cannot grow, so only in-memory containers accept new elements.
+    fn check_growable(&self) -> Result<(), PackedBitsError> {
+        self.check_writable()?;
+        #[cfg(feature = "mmap")]
+        if self.backing == Backing::MmapReadWrite {
+            return Err(ContainerError::NotSupported("push on memory-mapped storage").into());
         }
+        Ok(())
     }
 
     fn write_header(storage: &mut Container<u8>, len: usize) -> Result<(), PackedBitsError> {
@@ -188,6 +302,7 @@ impl<const N: usize> PackedBitsContainer<N> {
     /// assert_eq!(container.get(0), Some(15));
     /// ```
     pub fn push(&mut self, value: u32) -> Result<(), PackedBitsError> {
+        self.check_growable()?;
         let max_val = if N == 32 { u32::MAX } else { (1u32 << N) - 1 };
         assert!(value <= max_val, "value must fit in {} bits", N);
         let bit_pos = self.len * N;
@@ -205,8 +320,8 @@ impl<const N: usize> PackedBitsContainer<N> {
         // }
         //bit_ops::set_bits(self.storage_mut().as_mut_slice()?, HEADER_SIZE + bit_pos, N, value);
 
+        let bit_offset = Self::data_bit_offset_static(self.len); // <-- immutable borrow first
         let slice = self.storage_mut().as_mut_slice()?;
-        let bit_offset = Self::data_bit_offset_static(bit_pos); // <-- immutable borrow first
 
         //bit_ops::set_bits(slice, HEADER_SIZE * 8 + bit_pos, N, value);
         bit_ops::set_bits(slice, bit_offset, N, value as u64);
@@ -245,11 +360,11 @@ impl<const N: usize> PackedBitsContainer<N> {
     }
 
     pub fn set(&mut self, index: usize, value: u32) -> Result<(), PackedBitsError> {
+        self.check_writable()?;
         assert!(index < self.len, "index out of bounds");
         let max_val = if N == 32 { u32::MAX } else { (1u32 << N) - 1 };
         assert!(value <= max_val, "value must fit in {} bits", N);
 
-        let bit_pos = index * N;
         // let byte_pos = HEADER_SIZE + bit_pos / 8;
         // let bit_offset = bit_pos % 8;
 
@@ -277,7 +392,7 @@ impl<const N: usize> PackedBitsContainer<N> {
         //bit_ops::set_bits(self.storage_mut().as_mut_slice()?, HEADER_SIZE + bit_pos, N, value);
         
         let slice = self.storage_mut().as_mut_slice()?;
-        let bit_offset = Self::data_bit_offset_static(bit_pos);
+        let bit_offset = Self::data_bit_offset_static(index);
        
         //bit_ops::set_bits(slice, HEADER_SIZE * 8 + bit_pos, N, value);
         //bit_ops::set_bits(slice, HEADER_SIZE * 8 + bit_pos, N, value as u64);
@@ -295,13 +410,114 @@ impl<const N: usize> PackedBitsContainer<N> {
     }
 
     pub fn clear(&mut self) -> Result<(), PackedBitsError> {
+        self.check_writable()?;
         self.len = 0;
+        // A mapping cannot shrink; only reset the stored length
+        #[cfg(feature = "mmap")]
+        if self.backing == Backing::MmapReadWrite {
+            return self.update_len_in_header();
+        }
         // Recreate storage with just the header
         self.storage = Container::from_slice(&vec![0u8; HEADER_SIZE]);
         self.update_len_in_header()?;
         Ok(())
     }
 
+    /// Appends every element of `other` to the end of `self`.
+    ///
+    /// Storage for the combined length is allocated up front.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if `self` is memory-mapped.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use packed_bits::PackedBitsContainer;
+    ///
+    /// let mut a = PackedBitsContainer::<5>::new_in_memory().unwrap();
+    /// a.push(1).unwrap();
+    /// let mut b = PackedBitsContainer::<5>::new_in_memory().unwrap();
+    /// b.push(2).unwrap();
+    ///
+    /// a.append(&b).unwrap();
+    /// assert_eq!(a.iter().collect::<Vec<_>>(), vec![1, 2]);
+    /// ```
+    pub fn append(&mut self, other: &PackedBitsContainer<N>) -> Result<(), PackedBitsError> {
+        self.check_growable()?;
+        self.ensure_capacity((self.len + other.len) * N)?;
+        for value in other.iter() {
+            self.push(value)?;
+        }
+        Ok(())
+    }
+
+    /// Returns a new in-memory container holding the elements of `a`
+    /// followed by those of `b`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the output container cannot be created.
+    pub fn merge(
+        a: &PackedBitsContainer<N>,
+        b: &PackedBitsContainer<N>,
+    ) -> Result<PackedBitsContainer<N>, PackedBitsError> {
+        let mut merged = Self::with_capacity(a.len + b.len)?;
+        merged.append(a)?;
+        merged.append(b)?;
+        Ok(merged)
+    }
+
+    /// Removes consecutive duplicate values in place, like [`Vec::dedup`].
+    ///
+    /// The packed data is compacted in a single forward pass, and the freed
+    /// tail is zeroed. On a sorted container this leaves only unique values.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PackedBitsError::StorageReadOnly` for read-only mappings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use packed_bits::PackedBitsContainer;
+    ///
+    /// let mut container = PackedBitsContainer::<4>::new_in_memory().unwrap();
+    /// for v in [1, 1, 2, 2, 2, 1] {
+    ///     container.push(v).unwrap();
+    /// }
+    /// container.dedup().unwrap();
+    /// assert_eq!(container.iter().collect::<Vec<_>>(), vec![1, 2, 1]);
+    /// ```
+    pub fn dedup(&mut self) -> Result<(), PackedBitsError> {
+        self.check_writable()?;
+        let len = self.len;
+        if len < 2 {
+            return Ok(());
+        }
+
+        let slice = self.storage.as_mut_slice()?;
+        let mut last = bit_ops::get_bits(slice, Self::data_bit_offset_static(0), N);
+        let mut write = 1;
+        for read in 1..len {
+            let value = bit_ops::get_bits(slice, Self::data_bit_offset_static(read), N);
+            if value != last {
+                if write != read {
+                    bit_ops::set_bits(slice, Self::data_bit_offset_static(write), N, value);
+                }
+                last = value;
+                write += 1;
+            }
+        }
+        for index in write..len {
+            bit_ops::set_bits(slice, Self::data_bit_offset_static(index), N, 0);
+        }
+
+        self.len = write;
+        self.update_len_in_header()
+    }
+
     pub fn capacity(&self) -> usize {
         let data_bytes = self.storage.len().saturating_sub(HEADER_SIZE);
         (data_bytes * 8) / N
@@ -314,6 +530,527 @@ impl<const N: usize> PackedBitsContainer<N> {
         }
     }
 
+    /// Iterates over every `step`-th element, starting with the first.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `step` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use packed_bits::PackedBitsContainer;
+    ///
+    /// let mut container = PackedBitsContainer::<8>::new_in_memory().unwrap();
+    /// for v in [10, 20, 30, 40, 50] {
+    ///     container.push(v).unwrap();
+    /// }
+    /// assert_eq!(container.iter_step(2).collect::<Vec<_>>(), vec![10, 30, 50]);
+    /// ```
+    pub fn iter_step(&self, step: usize) -> StepIter<'_, N> {
+        assert!(step > 0, "step must be non-zero");
+        StepIter {
+            container: self,
+            index: 0,
+            step,
+        }
+    }
+
+    /// Iterates over the elements in `start..end`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `end > len()` or `start > end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use packed_bits::PackedBitsContainer;
+    ///
+    /// let mut container = PackedBitsContainer::<8>::new_in_memory().unwrap();
+    /// for v in [10, 20, 30, 40] {
+    ///     container.push(v).unwrap();
+    /// }
+    /// assert_eq!(container.iter_range(1, 3).collect::<Vec<_>>(), vec![20, 30]);
+    /// ```
+    pub fn iter_range(&self, start: usize, end: usize) -> RangeIter<'_, N> {
+        assert!(
+            start <= end && end <= self.len,
+            "range {}..{} out of bounds for length {}",
+            start,
+            end,
+            self.len
+        );
+        RangeIter {
+            container: self,
+            index: start,
+            end,
+        }
+    }
+
+    /// Iterates over `(self[i], other[i])` pairs.
+    ///
+    /// Containers of different lengths yield an empty iterator rather than
+    /// panicking; [`map2`](Self::map2) reports the mismatch as an error.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use packed_bits::PackedBitsContainer;
+    ///
+    /// let mut a = PackedBitsContainer::<4>::new_in_memory().unwrap();
+    /// let mut b = PackedBitsContainer::<4>::new_in_memory().unwrap();
+    /// for (x, y) in [(1, 2), (3, 4)] {
+    ///     a.push(x).unwrap();
+    ///     b.push(y).unwrap();
+    /// }
+    /// assert_eq!(a.zip_with(&b).collect::<Vec<_>>(), vec![(1, 2), (3, 4)]);
+    /// ```
+    pub fn zip_with<'a>(&'a self, other: &'a PackedBitsContainer<N>) -> ZipIter<'a, N> {
+        ZipIter {
+            a: self,
+            b: other,
+            index: 0,
+            len: if self.len == other.len { self.len } else { 0 },
+        }
+    }
+
+    /// Combines the elements of `self` and `other` pairwise into a new
+    /// in-memory container.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PackedBitsError::LengthMismatch` if the lengths differ, and
+    /// `PackedBitsError::ValueOverflow` if `f` returns a value that does not
+    /// fit in N bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use packed_bits::PackedBitsContainer;
+    ///
+    /// let mut a = PackedBitsContainer::<6>::new_in_memory().unwrap();
+    /// let mut b = PackedBitsContainer::<6>::new_in_memory().unwrap();
+    /// for (x, y) in [(1, 2), (30, 3)] {
+    ///     a.push(x).unwrap();
+    ///     b.push(y).unwrap();
+    /// }
+    /// let sums = a.map2(&b, |x, y| x + y).unwrap();
+    /// assert_eq!(sums.iter().collect::<Vec<_>>(), vec![3, 33]);
+    /// ```
+    pub fn map2(
+        &self,
+        other: &PackedBitsContainer<N>,
+        f: impl Fn(u32, u32) -> u32,
+    ) -> Result<PackedBitsContainer<N>, PackedBitsError> {
+        if self.len != other.len {
+            return Err(PackedBitsError::LengthMismatch {
+                expected: self.len,
+                found: other.len,
+            });
+        }
+
+        let max_val = if N == 32 { u32::MAX } else { (1u32 << N) - 1 };
+        let mut out = Self::with_capacity(self.len)?;
+        for (a, b) in self.zip_with(other) {
+            let value = f(a, b);
+            if value > max_val {
+                return Err(PackedBitsError::ValueOverflow(value, N));
+            }
+            out.push(value)?;
+        }
+        Ok(out)
+    }
+
+    /// Run-length encodes the values as `(value, run_length)` pairs.
+    ///
+    /// Adjacent equal values collapse into one pair, so containers with long flat
+    /// sections encode far smaller than their packed form.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use packed_bits::PackedBitsContainer;
+    ///
+    /// let mut container = PackedBitsContainer::<4>::new_in_memory().unwrap();
+    /// for v in [0, 0, 0, 7, 7, 0] {
+    ///     container.push(v).unwrap();
+    /// }
+    /// assert_eq!(container.rle_encode(), vec![(0, 3), (7, 2), (0, 1)]);
+    /// ```
+    pub fn rle_encode(&self) -> Vec<(u32, usize)> {
+        let mut runs: Vec<(u32, usize)> = Vec::new();
+        for value in self.iter() {
+            match runs.last_mut() {
+                Some((last, count)) if *last == value => *count += 1,
+                _ => runs.push((value, 1)),
+            }
+        }
+        runs
+    }
+
+    /// Rebuilds an in-memory container from `(value, run_length)` pairs.
+    ///
+    /// The inverse of [`rle_encode`](Self::rle_encode).
+    ///
+    /// # Errors
+    ///
+    /// Returns `PackedBitsError::ValueOverflow` if a value does not fit in N bits,
+    /// or `PackedBitsError::Overflow` if the run lengths add up to more than
+    /// `usize::MAX`.
+    pub fn from_rle(runs: &[(u32, usize)]) -> Result<Self, PackedBitsError> {
+        let max_val = Self::value_mask();
+        if let Some(&(value, _)) = runs.iter().find(|(value, _)| *value > max_val) {
+            return Err(PackedBitsError::ValueOverflow(value, N));
+        }
+
+        let total = runs
+            .iter()
+            .try_fold(0usize, |total, &(_, count)| total.checked_add(count))
+            .ok_or(PackedBitsError::Overflow)?;
+        let mut container = Self::with_capacity(total)?;
+        for &(value, count) in runs {
+            for _ in 0..count {
+                container.push(value)?;
+            }
+        }
+        Ok(container)
+    }
+
+    /// Delta encodes the values into a new in-memory container.
+    ///
+    /// Element `i` of the result stores `value[i] - value[i - 1]`, and the first
+    /// element is stored as-is. Subtraction wraps modulo `2^N`, so decreasing
+    /// sequences encode losslessly too.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use packed_bits::PackedBitsContainer;
+    ///
+    /// let mut container = PackedBitsContainer::<8>::new_in_memory().unwrap();
+    /// for v in [10, 12, 15, 15] {
+    ///     container.push(v).unwrap();
+    /// }
+    ///
+    /// let deltas = container.delta_encode();
+    /// assert_eq!(deltas.iter().collect::<Vec<_>>(), vec![10, 2, 3, 0]);
+    ///
+    /// let decoded = PackedBitsContainer::delta_decode(&deltas).unwrap();
+    /// assert!(decoded.iter().eq(container.iter()));
+    /// ```
+    pub fn delta_encode(&self) -> PackedBitsContainer<N> {
+        let mask = Self::value_mask();
+        let mut encoded =
+            Self::with_capacity(self.len).expect("N was validated when creating self");
+
+        let mut prev = 0;
+        for value in self.iter() {
+            encoded
+                .push(value.wrapping_sub(prev) & mask)
+                .expect("in-memory push cannot fail");
+            prev = value;
+        }
+        encoded
+    }
+
+    /// Reverses [`delta_encode`](Self::delta_encode), producing the original values.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the output container cannot be created or grown.
+    pub fn delta_decode(
+        encoded: &PackedBitsContainer<N>,
+    ) -> Result<PackedBitsContainer<N>, PackedBitsError> {
+        let mask = Self::value_mask();
+        let mut decoded = Self::with_capacity(encoded.len())?;
+
+        let mut prev: u32 = 0;
+        for delta in encoded.iter() {
+            prev = prev.wrapping_add(delta) & mask;
+            decoded.push(prev)?;
+        }
+        Ok(decoded)
+    }
+
+    /// Returns the running totals of the values in a new 32-bit container.
+    ///
+    /// Element `i` holds the sum of elements `0..=i`. The output is 32 bits wide
+    /// because sums outgrow N bits; sums beyond `u32::MAX` wrap around.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if the output container cannot be created or grown.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use packed_bits::PackedBitsContainer;
+    ///
+    /// let mut container = PackedBitsContainer::<4>::new_in_memory().unwrap();
+    /// for v in [3, 15, 1] {
+    ///     container.push(v).unwrap();
+    /// }
+    ///
+    /// let sums = container.prefix_sum().unwrap();
+    /// assert_eq!(sums.iter().collect::<Vec<_>>(), vec![3, 18, 19]);
+    /// ```
+    pub fn prefix_sum(&self) -> Result<PackedBitsContainer<32>, PackedBitsError> {
+        let mut sums = PackedBitsContainer::<32>::with_capacity(self.len)?;
+
+        let mut total: u32 = 0;
+        for value in self.iter() {
+            total = total.wrapping_add(value);
+            sums.push(total)?;
+        }
+        Ok(sums)
+    }
+
+    /// Binary searches a container sorted in ascending order for `value`.
+    ///
+    /// Mirrors [`slice::binary_search`]: returns `Ok(index)` of a matching element,
+    /// or `Err(index)` where `value` could be inserted to keep the order. If the
+    /// container is not sorted the result is unspecified.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use packed_bits::PackedBitsContainer;
+    ///
+    /// let mut container = PackedBitsContainer::<10>::new_in_memory().unwrap();
+    /// for v in [1, 4, 9, 16] {
+    ///     container.push(v).unwrap();
+    /// }
+    /// assert_eq!(container.binary_search(9), Ok(2));
+    /// assert_eq!(container.binary_search(5), Err(2));
+    /// ```
+    pub fn binary_search(&self, value: u32) -> Result<usize, usize> {
+        self.binary_search_by(|probe| probe.cmp(&value))
+    }
+
+    /// Binary searches with a comparator, mirroring [`slice::binary_search_by`].
+    ///
+    /// `f` returns the ordering of a probed element relative to the target.
+    pub fn binary_search_by<F>(&self, mut f: F) -> Result<usize, usize>
+    where
+        F: FnMut(u32) -> core::cmp::Ordering,
+    {
+        let slice = self.storage.as_slice();
+        let mut low = 0;
+        let mut high = self.len;
+
+        while low < high {
+            let mid = low + (high - low) / 2;
+            let probe = bit_ops::get_bits(slice, Self::data_bit_offset_static(mid), N) as u32;
+            match f(probe) {
+                core::cmp::Ordering::Less => low = mid + 1,
+                core::cmp::Ordering::Greater => high = mid,
+                core::cmp::Ordering::Equal => return Ok(mid),
+            }
+        }
+        Err(low)
+    }
+
+    /// Copies the elements in `start..end` into a vector.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PackedBitsError::IndexOutOfBounds` if `end > len()` or
+    /// `start > end`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use packed_bits::PackedBitsContainer;
+    ///
+    /// let mut container = PackedBitsContainer::<9>::new_in_memory().unwrap();
+    /// for v in [10, 20, 30, 40] {
+    ///     container.push(v).unwrap();
+    /// }
+    /// assert_eq!(container.slice_to_vec(1, 3).unwrap(), vec![20, 30]);
+    /// ```
+    pub fn slice_to_vec(&self, start: usize, end: usize) -> Result<Vec<u32>, PackedBitsError> {
+        self.check_range(start, end)?;
+        let mut out = Vec::new();
+        bit_ops::get_bits_batch(
+            self.storage.as_slice(),
+            Self::data_bit_offset_static(start),
+            N,
+            end - start,
+            &mut out,
+        );
+        Ok(out)
+    }
+
+    /// Copies the elements in `start..end` into a new in-memory container.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PackedBitsError::IndexOutOfBounds` if `end > len()` or
+    /// `start > end`.
+    pub fn copy_to_container(
+        &self,
+        start: usize,
+        end: usize,
+    ) -> Result<PackedBitsContainer<N>, PackedBitsError> {
+        let values = self.slice_to_vec(start, end)?;
+        let mut container = Self::with_capacity(values.len())?;
+        for value in values {
+            container.push(value)?;
+        }
+        Ok(container)
+    }
+
+    /// Copies the elements in `src` to the positions starting at `dst`,
+    /// like [`slice::copy_within`]. The ranges may overlap.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PackedBitsError::IndexOutOfBounds` if `src` is not within
+    /// `0..len()` or `dst + src.len()` exceeds `len()`, and
+    /// `PackedBitsError::StorageReadOnly` for read-only mappings.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use packed_bits::PackedBitsContainer;
+    ///
+    /// let mut container = PackedBitsContainer::<5>::new_in_memory().unwrap();
+    /// for v in [1, 2, 3, 4, 5] {
+    ///     container.push(v).unwrap();
+    /// }
+    /// container.copy_within(0..3, 2).unwrap();
+    /// assert_eq!(container.iter().collect::<Vec<_>>(), vec![1, 2, 1, 2, 3]);
+    /// ```
+    pub fn copy_within(
+        &mut self,
+        src: impl RangeBounds<usize>,
+        dst: usize,
+    ) -> Result<(), PackedBitsError> {
+        let start = match src.start_bound() {
+            Bound::Included(&s) => s,
+            Bound::Excluded(&s) => s.saturating_add(1),
+            Bound::Unbounded => 0,
+        };
+        let end = match src.end_bound() {
+            Bound::Included(&e) => e.saturating_add(1),
+            Bound::Excluded(&e) => e,
+            Bound::Unbounded => self.len,
+        };
+        self.check_range(start, end)?;
+
+        let count = end - start;
+        let dst_end = dst.saturating_add(count);
+        if dst_end > self.len {
+            return Err(PackedBitsError::IndexOutOfBounds(dst_end, self.len));
+        }
+        self.check_writable()?;
+
+        // Copy back to front when moving right so overlapping sources are
+        // read before they are overwritten
+        let copy = |this: &mut Self, i: usize| -> Result<(), PackedBitsError> {
+            let value = this.get(start + i).expect("index checked above");
+            this.set(dst + i, value)
+        };
+        if dst > start {
+            for i in (0..count).rev() {
+                copy(self, i)?;
+            }
+        } else {
+            for i in 0..count {
+                copy(self, i)?;
+            }
+        }
+        Ok(())
+    }
+
+    fn check_range(&self, start: usize, end: usize) -> Result<(), PackedBitsError> {
+        if end > self.len {
+            return Err(PackedBitsError::IndexOutOfBounds(end, self.len));
+        }
+        if start > end {
+            return Err(PackedBitsError::IndexOutOfBounds(start, end));
+        }
+        Ok(())
+    }
+
+    /// Returns the index of the first element equal to `value`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use packed_bits::PackedBitsContainer;
+    ///
+    /// let mut container = PackedBitsContainer::<6>::new_in_memory().unwrap();
+    /// for v in [4, 9, 4, 1] {
+    ///     container.push(v).unwrap();
+    /// }
+    /// assert_eq!(container.find(4), Some(0));
+    /// assert_eq!(container.rfind(4), Some(2));
+    /// assert_eq!(container.find_all(4).collect::<Vec<_>>(), vec![0, 2]);
+    /// assert_eq!(container.find(5), None);
+    /// ```
+    pub fn find(&self, value: u32) -> Option<usize> {
+        self.iter().position(|v| v == value)
+    }
+
+    /// Returns the index of the last element equal to `value`.
+    pub fn rfind(&self, value: u32) -> Option<usize> {
+        (0..self.len).rev().find(|&i| self.get(i) == Some(value))
+    }
+
+    /// Lazily yields the index of every element equal to `value`, in order.
+    pub fn find_all(&self, value: u32) -> impl Iterator<Item = usize> + '_ {
+        self.iter()
+            .enumerate()
+            .filter(move |&(_, v)| v == value)
+            .map(|(i, _)| i)
+    }
+
+    /// Counts the values falling into each of `num_bins` equal-width bins.
+    ///
+    /// Bin `i` covers `[i * 2^N / num_bins, (i + 1) * 2^N / num_bins)`, so
+    /// `num_bins == 1 << N` yields one bucket per possible value.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `num_bins` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use packed_bits::PackedBitsContainer;
+    ///
+    /// let mut container = PackedBitsContainer::<8>::new_in_memory().unwrap();
+    /// for v in [0, 10, 128, 255] {
+    ///     container.push(v).unwrap();
+    /// }
+    /// assert_eq!(container.histogram(2), vec![2, 2]);
+    /// ```
+    pub fn histogram(&self, num_bins: usize) -> Vec<usize> {
+        assert!(num_bins > 0, "histogram needs at least one bin");
+
+        let mut bins = vec![0; num_bins];
+        for value in self.iter() {
+            // value < 2^32 and num_bins < 2^64, so the product fits in u128
+            bins[((value as u128 * num_bins as u128) >> N) as usize] += 1;
+        }
+        bins
+    }
+
+    /// Counts occurrences of every possible N-bit value.
+    ///
+    /// Allocates `2^N` buckets, so this is only practical for small N.
+    pub fn exact_histogram(&self) -> Vec<usize> {
+        self.histogram(1usize << N)
+    }
+
+    /// All-ones mask covering an N-bit value.
+    #[inline]
+    const fn value_mask() -> u32 {
+        if N == 32 { u32::MAX } else { (1u32 << N) - 1 }
+    }
+
     // #[inline]
     // fn data_bit_offset(&self, index: usize) -> usize {
     //     debug_assert!(index < self.len);
@@ -358,6 +1095,86 @@ impl<'a, const N: usize> Iterator for Iter<'a, N> {
 
 impl<'a, const N: usize> ExactSizeIterator for Iter<'a, N> {}
 
+/// Iterator over every `step`-th element, created by
+/// [`PackedBitsContainer::iter_step`].
+pub struct StepIter<'a, const N: usize> {
+    container: &'a PackedBitsContainer<N>,
+    index: usize,
+    step: usize,
+}
+
+impl<'a, const N: usize> Iterator for StepIter<'a, N> {
+    type Item = u32;
+    fn next(&mut self) -> Option<Self::Item> {
+        let val = self.container.get(self.index)?;
+        self.index = self.index.saturating_add(self.step);
+        Some(val)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self
+            .container
+            .len()
+            .saturating_sub(self.index)
+            .div_ceil(self.step);
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, const N: usize> ExactSizeIterator for StepIter<'a, N> {}
+
+/// Iterator over a contiguous range of elements, created by
+/// [`PackedBitsContainer::iter_range`].
+pub struct RangeIter<'a, const N: usize> {
+    container: &'a PackedBitsContainer<N>,
+    index: usize,
+    end: usize,
+}
+
+impl<'a, const N: usize> Iterator for RangeIter<'a, N> {
+    type Item = u32;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.end {
+            return None;
+        }
+        let val = self.container.get(self.index);
+        self.index += 1;
+        val
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.end - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, const N: usize> ExactSizeIterator for RangeIter<'a, N> {}
+
+/// Iterator over element pairs of two containers, created by
+/// [`PackedBitsContainer::zip_with`].
+pub struct ZipIter<'a, const N: usize> {
+    a: &'a PackedBitsContainer<N>,
+    b: &'a PackedBitsContainer<N>,
+    index: usize,
+    len: usize,
+}
+
+impl<'a, const N: usize> Iterator for ZipIter<'a, N> {
+    type Item = (u32, u32);
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.index >= self.len {
+            return None;
+        }
+        let pair = (self.a.get(self.index)?, self.b.get(self.index)?);
+        self.index += 1;
+        Some(pair)
+    }
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.len - self.index;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<'a, const N: usize> ExactSizeIterator for ZipIter<'a, N> {}
+
 impl<'a, const N: usize> IntoIterator for &'a PackedBitsContainer<N> {
     type Item = u32;
     type IntoIter = Iter<'a, N>;
@@ -370,6 +1187,394 @@ impl<'a, const N: usize> IntoIterator for &'a PackedBitsContainer<N> {
 mod tests {
     use super::*;
 
+    #[test]
+    fn rle_roundtrip_sparse() -> Result<(), PackedBitsError> {
+        let mut pb = PackedBitsContainer::<12>::new_in_memory()?;
+        for _ in 0..1000 {
+            pb.push(0)?;
+        }
+        for v in [1, 2, 2, 4095, 7] {
+            pb.push(v)?;
+        }
+
+        let runs = pb.rle_encode();
+        assert_eq!(runs, vec![(0, 1000), (1, 1), (2, 2), (4095, 1), (7, 1)]);
+
+        // Five pairs against 1005 packed 12-bit values
+        let rle_bytes = runs.len() * core::mem::size_of::<(u32, usize)>();
+        assert!(rle_bytes * 10 < pb.storage().len());
+
+        let restored = PackedBitsContainer::<12>::from_rle(&runs)?;
+        assert_eq!(restored.len(), pb.len());
+        assert!(restored.iter().eq(pb.iter()));
+        Ok(())
+    }
+
+    #[test]
+    fn rle_edge_cases() -> Result<(), PackedBitsError> {
+        let empty = PackedBitsContainer::<5>::new_in_memory()?;
+        assert!(empty.rle_encode().is_empty());
+        assert!(PackedBitsContainer::<5>::from_rle(&[])?.is_empty());
+
+        assert!(matches!(
+            PackedBitsContainer::<5>::from_rle(&[(3, 2), (32, 1)]),
+            Err(PackedBitsError::ValueOverflow(32, 5))
+        ));
+        assert!(matches!(
+            PackedBitsContainer::<5>::from_rle(&[(1, usize::MAX), (2, 1)]),
+            Err(PackedBitsError::Overflow)
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn delta_roundtrip_increasing() -> Result<(), PackedBitsError> {
+        let mut pb = PackedBitsContainer::<16>::new_in_memory()?;
+        for i in 0..200u32 {
+            pb.push(1000 + i * 3 + i % 2)?;
+        }
+
+        let deltas = pb.delta_encode();
+        assert_eq!(deltas.len(), pb.len());
+        assert_eq!(deltas.get(0), Some(1000));
+        // Every later delta is small
+        assert!(deltas.iter().skip(1).all(|d| d <= 4));
+
+        let decoded = PackedBitsContainer::delta_decode(&deltas)?;
+        assert!(decoded.iter().eq(pb.iter()));
+        Ok(())
+    }
+
+    #[test]
+    fn delta_wraps_on_decrease() -> Result<(), PackedBitsError> {
+        let mut pb = PackedBitsContainer::<4>::new_in_memory()?;
+        for v in [15, 0, 3, 1] {
+            pb.push(v)?;
+        }
+
+        let deltas = pb.delta_encode();
+        assert_eq!(deltas.iter().collect::<Vec<_>>(), vec![15, 1, 3, 14]);
+
+        let decoded = PackedBitsContainer::delta_decode(&deltas)?;
+        assert!(decoded.iter().eq(pb.iter()));
+        Ok(())
+    }
+
+    #[test]
+    fn prefix_sum_differences() -> Result<(), PackedBitsError> {
+        let mut pb = PackedBitsContainer::<6>::new_in_memory()?;
+        for i in 0..100u32 {
+            pb.push(i * 37 % 64)?;
+        }
+
+        let sums = pb.prefix_sum()?;
+        assert_eq!(sums.len(), pb.len());
+        assert_eq!(sums.get(0), pb.get(0));
+        for i in 1..pb.len() {
+            let step = sums.get(i).unwrap() - sums.get(i - 1).unwrap();
+            assert_eq!(step, pb.get(i).unwrap());
+        }
+
+        let empty = PackedBitsContainer::<6>::new_in_memory()?;
+        assert!(empty.prefix_sum()?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn binary_search_sorted() -> Result<(), PackedBitsError> {
+        let mut pb = PackedBitsContainer::<12>::new_in_memory()?;
+        let values: Vec<u32> = (0..300).map(|i| i * 10).collect();
+        for &v in &values {
+            pb.push(v)?;
+        }
+
+        for probe in [0, 5, 10, 1495, 1500, 2990, 2995, 4000] {
+            assert_eq!(pb.binary_search(probe), values.binary_search(&probe));
+        }
+
+        // The comparator form locates the same positions
+        assert_eq!(pb.binary_search_by(|v| v.cmp(&250)), Ok(25));
+        assert_eq!(pb.binary_search_by(|v| v.cmp(&251)), Err(26));
+
+        let empty = PackedBitsContainer::<12>::new_in_memory()?;
+        assert_eq!(empty.binary_search(3), Err(0));
+        Ok(())
+    }
+
+    #[test]
+    fn find_positions() -> Result<(), PackedBitsError> {
+        let mut pb = PackedBitsContainer::<3>::new_in_memory()?;
+        for v in [7, 2, 7, 0, 2, 7] {
+            pb.push(v)?;
+        }
+
+        assert_eq!(pb.find(7), Some(0));
+        assert_eq!(pb.rfind(7), Some(5));
+        assert_eq!(pb.find(0), Some(3));
+        assert_eq!(pb.rfind(0), Some(3));
+        assert_eq!(pb.find_all(2).collect::<Vec<_>>(), vec![1, 4]);
+
+        assert_eq!(pb.find(5), None);
+        assert_eq!(pb.rfind(5), None);
+        assert_eq!(pb.find_all(5).count(), 0);
+
+        let empty = PackedBitsContainer::<3>::new_in_memory()?;
+        assert_eq!(empty.find(0), None);
+        assert_eq!(empty.rfind(0), None);
+        Ok(())
+    }
+
+    #[test]
+    fn dedup_edge_cases() -> Result<(), PackedBitsError> {
+        let collect = |values: &[u32]| -> Result<Vec<u32>, PackedBitsError> {
+            let mut pb = PackedBitsContainer::<5>::new_in_memory()?;
+            for &v in values {
+                pb.push(v)?;
+            }
+            pb.dedup()?;
+            Ok(pb.iter().collect())
+        };
+
+        assert_eq!(collect(&[])?, Vec::<u32>::new());
+        assert_eq!(collect(&[9])?, vec![9]);
+        assert_eq!(collect(&[4, 4, 4, 4])?, vec![4]);
+        assert_eq!(collect(&[1, 2, 3, 31])?, vec![1, 2, 3, 31]);
+        assert_eq!(collect(&[0, 0, 5, 5, 0, 31, 31])?, vec![0, 5, 0, 31]);
+        Ok(())
+    }
+
+    #[test]
+    fn dedup_persists_length_and_zeroes_tail() -> Result<(), PackedBitsError> {
+        let mut pb = PackedBitsContainer::<8>::new_in_memory()?;
+        for v in [7, 7, 7, 200] {
+            pb.push(v)?;
+        }
+        pb.dedup()?;
+
+        let bytes = pb.storage().as_slice().to_vec();
+        assert_eq!(&bytes[HEADER_SIZE..], &[7, 200, 0, 0]);
+
+        let restored = PackedBitsContainer::<8>::from_storage(Container::from_slice(&bytes))?;
+        assert_eq!(restored.iter().collect::<Vec<_>>(), vec![7, 200]);
+        Ok(())
+    }
+
+    #[test]
+    fn slice_and_copy_ranges() -> Result<(), PackedBitsError> {
+        let mut pb = PackedBitsContainer::<11>::new_in_memory()?;
+        let values: Vec<u32> = (0..50).map(|i| i * 41 % 2048).collect();
+        for &v in &values {
+            pb.push(v)?;
+        }
+
+        assert_eq!(pb.slice_to_vec(0, 50)?, values);
+        assert_eq!(pb.slice_to_vec(7, 23)?, values[7..23]);
+        assert!(pb.slice_to_vec(20, 20)?.is_empty());
+
+        let copy = pb.copy_to_container(13, 40)?;
+        assert_eq!(copy.len(), 27);
+        assert!(copy.iter().eq(values[13..40].iter().copied()));
+        // The source is untouched
+        assert_eq!(pb.len(), 50);
+
+        assert!(matches!(
+            pb.slice_to_vec(10, 51),
+            Err(PackedBitsError::IndexOutOfBounds(51, 50))
+        ));
+        assert!(matches!(
+            pb.copy_to_container(30, 20),
+            Err(PackedBitsError::IndexOutOfBounds(30, 20))
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn copy_within_ranges() -> Result<(), PackedBitsError> {
+        let fill = || -> Result<PackedBitsContainer<6>, PackedBitsError> {
+            let mut pb = PackedBitsContainer::<6>::new_in_memory()?;
+            for v in 0..10 {
+                pb.push(v)?;
+            }
+            Ok(pb)
+        };
+        let values = |pb: &PackedBitsContainer<6>| pb.iter().collect::<Vec<_>>();
+
+        // Non-overlapping
+        let mut pb = fill()?;
+        pb.copy_within(0..3, 5)?;
+        assert_eq!(values(&pb), vec![0, 1, 2, 3, 4, 0, 1, 2, 8, 9]);
+
+        // Overlapping, moving right
+        let mut pb = fill()?;
+        pb.copy_within(2..=6, 4)?;
+        assert_eq!(values(&pb), vec![0, 1, 2, 3, 2, 3, 4, 5, 6, 9]);
+
+        // Overlapping, moving left
+        let mut pb = fill()?;
+        pb.copy_within(3.., 1)?;
+        assert_eq!(values(&pb), vec![0, 3, 4, 5, 6, 7, 8, 9, 8, 9]);
+
+        // From the end to the beginning
+        let mut pb = fill()?;
+        pb.copy_within(7.., 0)?;
+        assert_eq!(values(&pb), vec![7, 8, 9, 3, 4, 5, 6, 7, 8, 9]);
+
+        // Empty and full ranges are no-ops
+        let mut pb = fill()?;
+        pb.copy_within(4..4, 10)?;
+        pb.copy_within(.., 0)?;
+        assert_eq!(values(&pb), (0..10).collect::<Vec<_>>());
+
+        assert!(matches!(
+            pb.copy_within(0..3, 8),
+            Err(PackedBitsError::IndexOutOfBounds(11, 10))
+        ));
+        assert!(matches!(
+            pb.copy_within(5..11, 0),
+            Err(PackedBitsError::IndexOutOfBounds(11, 10))
+        ));
+        assert_eq!(values(&pb), (0..10).collect::<Vec<_>>());
+        Ok(())
+    }
+
+    #[test]
+    fn debug_preview() -> Result<(), PackedBitsError> {
+        let mut pb = PackedBitsContainer::<4>::new_in_memory()?;
+        for v in [0xA, 0x3, 0xF] {
+            pb.push(v)?;
+        }
+        assert_eq!(
+            format!("{:?}", pb),
+            "PackedBitsContainer<4> { len: 3, values: [0xA, 0x3, 0xF] }"
+        );
+
+        for v in 0..7 {
+            pb.push(v)?;
+        }
+        let debug = format!("{:?}", pb);
+        assert!(debug.starts_with("PackedBitsContainer<4> { len: 10, values: [0xA, 0x3, 0xF, 0x0,"));
+        assert!(debug.ends_with("0x4, ...] }"));
+        Ok(())
+    }
+
+    #[test]
+    fn step_and_range_iterators() -> Result<(), PackedBitsError> {
+        let mut pb = PackedBitsContainer::<7>::new_in_memory()?;
+        for v in [11, 22, 33, 44] {
+            pb.push(v)?;
+        }
+
+        assert_eq!(pb.iter_step(2).collect::<Vec<_>>(), vec![11, 33]);
+        assert_eq!(pb.iter_step(1).collect::<Vec<_>>(), vec![11, 22, 33, 44]);
+        assert_eq!(pb.iter_step(3).collect::<Vec<_>>(), vec![11, 44]);
+        assert_eq!(pb.iter_step(10).collect::<Vec<_>>(), vec![11]);
+        for step in 1..6 {
+            assert_eq!(pb.iter_step(step).len(), pb.len().div_ceil(step));
+        }
+        let mut step = pb.iter_step(3);
+        step.next();
+        assert_eq!(step.len(), 1);
+
+        assert_eq!(pb.iter_range(1, 3).collect::<Vec<_>>(), vec![22, 33]);
+        assert_eq!(pb.iter_range(0, 4).len(), 4);
+        assert_eq!(pb.iter_range(4, 4).next(), None);
+
+        let empty = PackedBitsContainer::<7>::new_in_memory()?;
+        assert_eq!(empty.iter_step(2).len(), 0);
+        assert_eq!(empty.iter_step(2).next(), None);
+        Ok(())
+    }
+
+    #[test]
+    #[should_panic]
+    fn iter_range_out_of_bounds() {
+        let mut pb = PackedBitsContainer::<7>::new_in_memory().unwrap();
+        pb.push(1).unwrap();
+        let _ = pb.iter_range(0, 2);
+    }
+
+    #[test]
+    fn zip_and_map2() -> Result<(), PackedBitsError> {
+        let mut a = PackedBitsContainer::<5>::new_in_memory()?;
+        let mut b = PackedBitsContainer::<5>::new_in_memory()?;
+        for (x, y) in [(1, 10), (20, 2), (31, 2)] {
+            a.push(x)?;
+            b.push(y)?;
+        }
+
+        let mut zip = a.zip_with(&b);
+        assert_eq!(zip.len(), 3);
+        assert_eq!(zip.next(), Some((1, 10)));
+        assert_eq!(zip.len(), 2);
+        assert_eq!(zip.collect::<Vec<_>>(), vec![(20, 2), (31, 2)]);
+
+        let max = a.map2(&b, u32::max)?;
+        assert_eq!(max.iter().collect::<Vec<_>>(), vec![10, 20, 31]);
+        assert!(matches!(
+            a.map2(&b, |x, y| x + y),
+            Err(PackedBitsError::ValueOverflow(33, 5))
+        ));
+
+        b.push(4)?;
+        assert_eq!(a.zip_with(&b).len(), 0);
+        assert!(a.zip_with(&b).next().is_none());
+        assert!(matches!(
+            a.map2(&b, u32::min),
+            Err(PackedBitsError::LengthMismatch {
+                expected: 3,
+                found: 4
+            })
+        ));
+        Ok(())
+    }
+
+    #[test]
+    fn merge_and_append() -> Result<(), PackedBitsError> {
+        let mut a = PackedBitsContainer::<7>::new_in_memory()?;
+        let mut b = PackedBitsContainer::<7>::new_in_memory()?;
+        for v in [1, 2, 3] {
+            a.push(v)?;
+        }
+        for v in [100, 127] {
+            b.push(v)?;
+        }
+
+        let merged = PackedBitsContainer::merge(&a, &b)?;
+        assert_eq!(merged.len(), a.len() + b.len());
+        assert_eq!(merged.iter().collect::<Vec<_>>(), vec![1, 2, 3, 100, 127]);
+        assert!(merged.capacity() >= merged.len());
+
+        a.append(&b)?;
+        assert!(a.iter().eq(merged.iter()));
+
+        let empty = PackedBitsContainer::<7>::new_in_memory()?;
+        a.append(&empty)?;
+        assert_eq!(a.len(), 5);
+        Ok(())
+    }
+
+    #[test]
+    fn histogram_counts() -> Result<(), PackedBitsError> {
+        let mut pb = PackedBitsContainer::<2>::new_in_memory()?;
+        for v in [0, 1, 2, 3, 0, 1] {
+            pb.push(v)?;
+        }
+
+        assert_eq!(pb.exact_histogram(), vec![2, 2, 1, 1]);
+        assert_eq!(pb.histogram(4), vec![2, 2, 1, 1]);
+        assert_eq!(pb.histogram(2), vec![4, 2]);
+        assert_eq!(pb.histogram(1), vec![6]);
+        // More bins than values leaves gaps
+        assert_eq!(pb.histogram(8), vec![2, 0, 2, 0, 1, 0, 1, 0]);
+
+        let mut wide = PackedBitsContainer::<32>::new_in_memory()?;
+        for v in [0, u32::MAX / 3, u32::MAX / 3 + 1, u32::MAX] {
+            wide.push(v)?;
+        }
+        assert_eq!(wide.histogram(3), vec![2, 1, 1]);
+        Ok(())
+    }
+
     #[test]
     fn basic_in_memory() -> Result<(), PackedBitsError> {
         let mut pb = PackedBitsContainer::<12>::new_in_memory()?;
@@ -38,6 +38,15 @@ pub enum PackedBitsError {
     #[cfg_attr(feature = "std", error("failed to resize storage"))]
     ResizeFailed,
 
+    #[cfg_attr(feature = "std", error("fixed capacity exhausted"))]
+    Overflow,
+
+    #[cfg_attr(
+        feature = "std",
+        error("Length mismatch: expected {expected}, found {found}")
+    )]
+    LengthMismatch { expected: usize, found: usize },
+
     #[cfg_attr(feature = "std", error("storage error: {0}"))]
     Container(#[from] ContainerError),
 
@@ -68,6 +77,10 @@ impl core::fmt::Display for PackedBitsError {
             PackedBitsError::StorageTooSmall => write!(f, "storage too small for header"),
             PackedBitsError::StorageReadOnly => write!(f, "storage is read-only"),
             PackedBitsError::ResizeFailed => write!(f, "failed to resize storage"),
+            PackedBitsError::Overflow => write!(f, "fixed capacity exhausted"),
+            PackedBitsError::LengthMismatch { expected, found } => {
+                write!(f, "Length mismatch: expected {}, found {}", expected, found)
+            }
             PackedBitsError::Storage(e) => write!(f, "storage error: {}", e),
             PackedBitsError::Unexpected => write!(f, "Unexpected error"),
         }
@@ -28,11 +28,16 @@ use crate::{PackedBitsContainer, PackedBitsError};
 
 type Result<T> = core::result::Result<T, PackedBitsError>;
 
-#[derive(Debug)]
 pub struct FlagsContainer<const N: usize> {
     bits: PackedBitsContainer<N>,
 }
 
+impl<const N: usize> core::fmt::Debug for FlagsContainer<N> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        crate::container::debug_preview(f, "FlagsContainer", &self.bits)
+    }
+}
+
 impl<const N: usize> FlagsContainer<N> {
     pub fn new_in_memory() -> Result<Self> {
         Ok(Self {
@@ -46,6 +51,26 @@ impl<const N: usize> FlagsContainer<N> {
         })
     }
 
+    /// Memory-maps a file written from a container's storage, read-only.
+    ///
+    /// See [`PackedBitsContainer::mmap_readonly`].
+    #[cfg(feature = "mmap")]
+    pub fn mmap_readonly<P: AsRef<std::path::Path>>(path: P) -> Result<Self> {
+        Ok(Self {
+            bits: PackedBitsContainer::<N>::mmap_readonly(path)?,
+        })
+    }
+
+    /// Memory-maps a file for reading and writing; mask updates persist to the file.
+    ///
+    /// See [`PackedBitsContainer::mmap_readwrite`].
+    #[cfg(feature = "mmap")]
+    pub fn mmap_readwrite<P: AsRef<std::path::Path>>(path: P) -> Result<Self> {
+        Ok(Self {
+            bits: PackedBitsContainer::<N>::mmap_readwrite(path)?,
+        })
+    }
+
     pub fn push(&mut self, flags: u32) -> Result<()> {
         self.bits.push(flags)?;
         Ok(())
@@ -99,8 +124,24 @@ impl<const N: usize> FlagsContainer<N> {
         self.bits.clear()
     }
 
-    pub fn iter(&self) -> impl Iterator<Item = u32> + '_ {
-        self.bits.iter()
+    /// Iterates over the flag sets, one per element.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use packed_bits::FlagsContainer;
+    ///
+    /// let mut flags = FlagsContainer::<4>::new_in_memory().unwrap();
+    /// flags.push(0b0101).unwrap();
+    /// flags.push(0b0010).unwrap();
+    ///
+    /// let first = flags.iter().next().unwrap();
+    /// assert!(first.contains(0b0100));
+    /// assert_eq!(first.as_raw(), 0b0101);
+    /// assert_eq!(first.iter_set_bits().collect::<Vec<_>>(), vec![0, 2]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = FlagSet<N>> + '_ {
+        self.bits.iter().map(FlagSet)
     }
 
     pub fn packed_bits(&self) -> &PackedBitsContainer<N> {
@@ -110,6 +151,111 @@ impl<const N: usize> FlagsContainer<N> {
     pub fn iter_flags(&self, index: usize) -> Option<FlagsIter> {
         self.get(index).map(FlagsIter::new)
     }
+
+    /// Element-wise `self[i] & other[i]`, e.g. entities present in both groups.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PackedBitsError::LengthMismatch` if the lengths differ.
+    pub fn and(&self, other: &FlagsContainer<N>) -> Result<FlagsContainer<N>> {
+        self.zip_with(other, |a, b| a & b)
+    }
+
+    /// Element-wise `self[i] | other[i]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PackedBitsError::LengthMismatch` if the lengths differ.
+    pub fn or(&self, other: &FlagsContainer<N>) -> Result<FlagsContainer<N>> {
+        self.zip_with(other, |a, b| a | b)
+    }
+
+    /// Element-wise `self[i] ^ other[i]`.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PackedBitsError::LengthMismatch` if the lengths differ.
+    pub fn xor(&self, other: &FlagsContainer<N>) -> Result<FlagsContainer<N>> {
+        self.zip_with(other, |a, b| a ^ b)
+    }
+
+    /// Flips every flag within the N-bit width of each element.
+    pub fn not(&self) -> FlagsContainer<N> {
+        let mask = if N == 32 { u32::MAX } else { (1u32 << N) - 1 };
+        let mut out = Self::with_capacity(self.len()).expect("N was validated when creating self");
+        for flags in self.bits.iter() {
+            out.push(!flags & mask).expect("in-memory push cannot fail");
+        }
+        out
+    }
+
+    fn zip_with(
+        &self,
+        other: &FlagsContainer<N>,
+        op: impl Fn(u32, u32) -> u32,
+    ) -> Result<FlagsContainer<N>> {
+        if self.len() != other.len() {
+            return Err(PackedBitsError::LengthMismatch {
+                expected: self.len(),
+                found: other.len(),
+            });
+        }
+
+        let mut out = Self::with_capacity(self.len())?;
+        for (a, b) in self.bits.iter().zip(other.bits.iter()) {
+            out.push(op(a, b))?;
+        }
+        Ok(out)
+    }
+
+    /// A human-readable serialization view listing the set bit positions
+    /// of each element, e.g. `[[0,2],[1]]` instead of `[5,2]`.
+    ///
+    /// Deserializing a [`FlagsContainer`] accepts this form as well.
+    #[cfg(feature = "serde")]
+    pub fn bit_positions(&self) -> BitPositions<'_, N> {
+        BitPositions(self)
+    }
+}
+
+/// Serializes a [`FlagsContainer`] as arrays of set bit positions.
+///
+/// Created by [`FlagsContainer::bit_positions`].
+#[cfg(feature = "serde")]
+pub struct BitPositions<'a, const N: usize>(pub(crate) &'a FlagsContainer<N>);
+
+/// The flags of one element of a [`FlagsContainer<N>`].
+///
+/// Wrapping the raw value keeps it from being mistaken for an index or count.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct FlagSet<const N: usize>(u32);
+
+impl<const N: usize> FlagSet<N> {
+    /// Returns true if every flag in `mask` is set.
+    pub fn contains(&self, mask: u32) -> bool {
+        (self.0 & mask) == mask
+    }
+
+    /// Returns true if any flag in `mask` is set.
+    pub fn intersects(&self, mask: u32) -> bool {
+        (self.0 & mask) != 0
+    }
+
+    /// The raw bitmask.
+    pub fn as_raw(&self) -> u32 {
+        self.0
+    }
+
+    /// Yields the positions of the set bits, lowest first.
+    pub fn iter_set_bits(&self) -> impl Iterator<Item = u32> + use<N> {
+        FlagsIter::new(self.0).map(u32::trailing_zeros)
+    }
+}
+
+impl<const N: usize> PartialEq<u32> for FlagSet<N> {
+    fn eq(&self, other: &u32) -> bool {
+        self.0 == *other
+    }
 }
 
 pub struct FlagsIter {
@@ -157,6 +303,73 @@ mod tests {
         Ok(())
     }
 
+    fn flags(values: &[u32]) -> FlagsContainer<3> {
+        let mut fc = FlagsContainer::<3>::new_in_memory().unwrap();
+        for &v in values {
+            fc.push(v).unwrap();
+        }
+        fc
+    }
+
+    #[test]
+    fn bitwise_ops() -> Result<()> {
+        let a = flags(&[FLAG0 | FLAG1, FLAG2, 0]);
+        let b = flags(&[FLAG1, FLAG1 | FLAG2, FLAG0]);
+
+        assert!(a.and(&b)?.iter().eq([FLAG1, FLAG2, 0]));
+        assert!(a.or(&b)?.iter().eq([FLAG0 | FLAG1, FLAG1 | FLAG2, FLAG0]));
+        assert!(a.xor(&b)?.iter().eq([FLAG0, FLAG1, FLAG0]));
+        assert!(
+            a.not()
+                .iter()
+                .eq([FLAG2, FLAG0 | FLAG1, FLAG0 | FLAG1 | FLAG2])
+        );
+        Ok(())
+    }
+
+    #[test]
+    fn debug_shows_values() {
+        let fc = flags(&[FLAG0 | FLAG2, FLAG1]);
+        assert_eq!(
+            format!("{:?}", fc),
+            "FlagsContainer<3> { len: 2, values: [0x5, 0x2] }"
+        );
+    }
+
+    #[test]
+    fn typed_iter() {
+        let fc = flags(&[FLAG0 | FLAG2, 0]);
+        let sets: Vec<FlagSet<3>> = fc.iter().collect();
+
+        assert!(sets[0].contains(FLAG2));
+        assert!(sets[0].contains(FLAG0 | FLAG2));
+        assert!(!sets[0].contains(FLAG0 | FLAG1));
+        assert!(!sets[0].contains(FLAG1));
+        assert!(sets[0].intersects(FLAG0 | FLAG1));
+        assert!(!sets[0].intersects(FLAG1));
+        assert!(sets[1].contains(0));
+        assert!(!sets[1].intersects(FLAG0 | FLAG1 | FLAG2));
+        assert_eq!(sets[0].as_raw(), FLAG0 | FLAG2);
+        assert!(sets[0].iter_set_bits().eq([0, 2]));
+        assert_eq!(sets[1].iter_set_bits().next(), None);
+    }
+
+    #[test]
+    fn bitwise_length_mismatch() {
+        let a = flags(&[FLAG0, FLAG1]);
+        let b = flags(&[FLAG0]);
+
+        assert!(matches!(
+            a.and(&b),
+            Err(PackedBitsError::LengthMismatch {
+                expected: 2,
+                found: 1
+            })
+        ));
+        assert!(a.or(&b).is_err());
+        assert!(a.xor(&b).is_err());
+    }
+
     #[test]
     fn iter_flags_works() -> Result<()> {
         let mut fc = FlagsContainer::<3>::new_in_memory().unwrap();
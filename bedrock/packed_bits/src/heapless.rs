@@ -0,0 +1,126 @@
+//! Fixed-capacity bit-packed storage held inline in a byte array.
+//!
+//! [`StaticPackedBits`] never allocates, but the crate itself still links
+//! `alloc` through `raw_bytes`, so this is not a way to run without an
+//! allocator.
+//!
+//! # Examples
+//!
+//! ```rust
+//! use packed_bits::heapless::{StaticPackedBits, bytes_for};
+//!
+//! // Room for 100 values of 12 bits each
+//! let mut bits = StaticPackedBits::<12, { bytes_for(12, 100) }>::new();
+//! bits.push(0xABC).unwrap();
+//!
+//! assert_eq!(bits.get(0), Some(0xABC));
+//! assert_eq!(bits.capacity(), 100);
+//! ```
+
+use crate::PackedBitsError;
+use crate::bit_ops;
+
+/// Number of bytes needed to hold `count` values of `bit_width` bits.
+///
+/// Use it to size the `BYTES` parameter of [`StaticPackedBits`].
+pub const fn bytes_for(bit_width: usize, count: usize) -> usize {
+    (bit_width * count).div_ceil(8)
+}
+
+/// A fixed-capacity container of `N`-bit values backed by a `[u8; BYTES]` array.
+///
+/// Stable Rust cannot size an array from an expression over a const parameter, so
+/// the backing size is given in bytes; [`bytes_for`] computes it for a capacity in
+/// elements. Values are laid out exactly as in [`PackedBitsContainer`]'s data section.
+///
+/// [`PackedBitsContainer`]: crate::PackedBitsContainer
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct StaticPackedBits<const N: usize, const BYTES: usize> {
+    data: [u8; BYTES],
+    len: usize,
+}
+
+impl<const N: usize, const BYTES: usize> StaticPackedBits<N, BYTES> {
+    /// Creates an empty container.
+    ///
+    /// # Panics
+    ///
+    /// Fails to compile if `N` is not in `1..=32`.
+    pub const fn new() -> Self {
+        const { assert!(N > 0 && N <= 32, "N must be 1..=32") };
+        Self {
+            data: [0; BYTES],
+            len: 0,
+        }
+    }
+
+    /// Maximum number of values the container can hold.
+    pub const fn capacity(&self) -> usize {
+        BYTES * 8 / N
+    }
+
+    pub const fn len(&self) -> usize {
+        self.len
+    }
+
+    pub const fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Pushes a value that must fit in N bits.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PackedBitsError::Overflow` if the container is full.
+    ///
+    /// # Panics
+    ///
+    /// Panics if the value doesn't fit in N bits.
+    pub fn push(&mut self, value: u32) -> Result<(), PackedBitsError> {
+        if self.len >= self.capacity() {
+            return Err(PackedBitsError::Overflow);
+        }
+        let max_val = if N == 32 { u32::MAX } else { (1u32 << N) - 1 };
+        assert!(value <= max_val, "value must fit in {} bits", N);
+
+        bit_ops::set_bits(&mut self.data, self.len * N, N, value as u64);
+        self.len += 1;
+        Ok(())
+    }
+
+    pub fn get(&self, index: usize) -> Option<u32> {
+        if index >= self.len {
+            return None;
+        }
+        Some(bit_ops::get_bits(&self.data, index * N, N) as u32)
+    }
+
+    pub fn set(&mut self, index: usize, value: u32) -> Result<(), PackedBitsError> {
+        assert!(index < self.len, "index out of bounds");
+        let max_val = if N == 32 { u32::MAX } else { (1u32 << N) - 1 };
+        assert!(value <= max_val, "value must fit in {} bits", N);
+
+        bit_ops::set_bits(&mut self.data, index * N, N, value as u64);
+        Ok(())
+    }
+
+    pub fn clear(&mut self) {
+        self.data = [0; BYTES];
+        self.len = 0;
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = u32> + '_ {
+        (0..self.len).map(move |i| bit_ops::get_bits(&self.data, i * N, N) as u32)
+    }
+
+    /// The packed bytes, including unused capacity.
+    pub fn as_bytes(&self) -> &[u8; BYTES] {
+        &self.data
+    }
+}
+
+impl<const N: usize, const BYTES: usize> Default for StaticPackedBits<N, BYTES> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
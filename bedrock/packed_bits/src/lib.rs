@@ -34,13 +34,13 @@
 
 #![cfg_attr(not(feature = "std"), no_std)]
 
-#[cfg(all(feature = "container", not(feature = "std")))]
+#[cfg(not(feature = "std"))]
 extern crate alloc;
 
 pub mod error;
 pub use error::PackedBitsError;
 
-mod bit_ops;
+pub mod bit_ops;
 
 #[cfg(feature = "container")]
 pub mod container;
@@ -48,8 +48,17 @@ pub mod container;
 #[cfg(feature = "container")]
 pub mod flags;
 
+#[cfg(feature = "heapless")]
+pub mod heapless;
+
+#[cfg(feature = "serde")]
+mod serde_impl;
+
 #[cfg(feature = "container")]
 pub use container::PackedBitsContainer;
 
 #[cfg(feature = "container")]
-pub use flags::FlagsContainer;
+pub use flags::{FlagSet, FlagsContainer};
+
+#[cfg(feature = "heapless")]
+pub use heapless::StaticPackedBits;
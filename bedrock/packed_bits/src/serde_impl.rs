@@ -0,0 +1,140 @@
+//! `serde` support for [`PackedBitsContainer`] and [`FlagsContainer`].
+//!
+//! A container serializes as a struct holding its bit width and the logical
+//! values, e.g. `{"bits":12,"values":[2748,291]}` in JSON. Deserializing
+//! checks the bit width against `N` and that every value fits.
+//!
+//! A flags container serializes as a plain array of bitmasks, or through
+//! [`FlagsContainer::bit_positions`] as arrays of set bit positions.
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+
+use serde::de::{Deserialize, Deserializer, Error};
+use serde::ser::{Serialize, SerializeSeq, SerializeStruct, Serializer};
+
+use crate::flags::{BitPositions, FlagsIter};
+use crate::{FlagsContainer, PackedBitsContainer};
+
+#[inline]
+fn max_value<const N: usize>() -> u32 {
+    if N == 32 { u32::MAX } else { (1u32 << N) - 1 }
+}
+
+/// The logical values of a container, serialized as a sequence.
+struct Values<'a, const N: usize>(&'a PackedBitsContainer<N>);
+
+impl<const N: usize> Serialize for Values<'_, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.0.iter())
+    }
+}
+
+impl<const N: usize> Serialize for PackedBitsContainer<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut state = serializer.serialize_struct("PackedBitsContainer", 2)?;
+        state.serialize_field("bits", &(N as u32))?;
+        state.serialize_field("values", &Values(self))?;
+        state.end()
+    }
+}
+
+#[derive(serde::Deserialize)]
+#[serde(rename = "PackedBitsContainer")]
+struct Repr {
+    bits: u32,
+    values: Vec<u32>,
+}
+
+impl<'de, const N: usize> Deserialize<'de> for PackedBitsContainer<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let repr = Repr::deserialize(deserializer)?;
+        if repr.bits as usize != N {
+            return Err(D::Error::custom(format_args!(
+                "N mismatch: expected {}, found {}",
+                N, repr.bits
+            )));
+        }
+
+        let max_val = max_value::<N>();
+        let mut container = Self::with_capacity(repr.values.len()).map_err(D::Error::custom)?;
+        for value in repr.values {
+            if value > max_val {
+                return Err(D::Error::custom(format_args!(
+                    "Value {} does not fit in {} bits",
+                    value, N
+                )));
+            }
+            container.push(value).map_err(D::Error::custom)?;
+        }
+        Ok(container)
+    }
+}
+
+impl<const N: usize> Serialize for FlagsContainer<N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(self.packed_bits().iter())
+    }
+}
+
+/// The set bit positions of one bitmask, serialized as a sequence.
+struct Positions(u32);
+
+impl Serialize for Positions {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_seq(FlagsIter::new(self.0).map(u32::trailing_zeros))
+    }
+}
+
+impl<const N: usize> Serialize for BitPositions<'_, N> {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        let mut seq = serializer.serialize_seq(Some(self.0.len()))?;
+        for flags in self.0.iter() {
+            seq.serialize_element(&Positions(flags.as_raw()))?;
+        }
+        seq.end()
+    }
+}
+
+/// One element of a serialized flags container, in either form.
+#[derive(serde::Deserialize)]
+#[serde(untagged)]
+enum FlagsRepr {
+    Mask(u32),
+    Positions(Vec<u32>),
+}
+
+impl<'de, const N: usize> Deserialize<'de> for FlagsContainer<N> {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let elements = Vec::<FlagsRepr>::deserialize(deserializer)?;
+
+        let max_val = max_value::<N>();
+        let mut container = Self::with_capacity(elements.len()).map_err(D::Error::custom)?;
+        for element in elements {
+            let flags = match element {
+                FlagsRepr::Mask(mask) => mask,
+                FlagsRepr::Positions(positions) => {
+                    let mut mask = 0u32;
+                    for pos in positions {
+                        if pos as usize >= N {
+                            return Err(D::Error::custom(format_args!(
+                                "Bit position {} out of range for {} flags",
+                                pos, N
+                            )));
+                        }
+                        mask |= 1 << pos;
+                    }
+                    mask
+                }
+            };
+            if flags > max_val {
+                return Err(D::Error::custom(format_args!(
+                    "Value {} does not fit in {} bits",
+                    flags, N
+                )));
+            }
+            container.push(flags).map_err(D::Error::custom)?;
+        }
+        Ok(container)
+    }
+}
@@ -0,0 +1,10 @@
+//! Compile-time rejection of invalid bit widths.
+
+#[test]
+fn invalid_bit_widths() {
+    let t = trybuild::TestCases::new();
+    // A passing case makes trybuild run `cargo build` instead of `cargo
+    // check`, which is needed for the post-monomorphization const assert
+    t.pass("tests/ui/pass/*.rs");
+    t.compile_fail("tests/ui/*.rs");
+}
@@ -0,0 +1,70 @@
+//! Tests for the fixed-capacity `StaticPackedBits`.
+
+#![cfg(feature = "heapless")]
+
+use packed_bits::PackedBitsError;
+use packed_bits::heapless::{StaticPackedBits, bytes_for};
+
+#[test]
+fn push_get_roundtrip() {
+    let mut bits = StaticPackedBits::<12, { bytes_for(12, 16) }>::new();
+    for i in 0..16 {
+        bits.push(i * 255).unwrap();
+    }
+
+    assert_eq!(bits.len(), 16);
+    for i in 0..16 {
+        assert_eq!(bits.get(i as usize), Some(i * 255));
+    }
+    assert_eq!(bits.get(16), None);
+}
+
+#[test]
+fn push_fails_when_full() {
+    // 3 bytes hold two 12-bit values
+    let mut bits = StaticPackedBits::<12, 3>::new();
+    assert_eq!(bits.capacity(), 2);
+    bits.push(1).unwrap();
+    bits.push(2).unwrap();
+
+    assert!(matches!(bits.push(3), Err(PackedBitsError::Overflow)));
+    assert_eq!(bits.len(), 2);
+}
+
+#[test]
+fn set_leaves_neighbours_intact() {
+    let mut bits = StaticPackedBits::<5, { bytes_for(5, 3) }>::new();
+    bits.push(31).unwrap();
+    bits.push(31).unwrap();
+    bits.push(31).unwrap();
+
+    bits.set(1, 0).unwrap();
+    assert_eq!(bits.get(0), Some(31));
+    assert_eq!(bits.get(1), Some(0));
+    assert_eq!(bits.get(2), Some(31));
+}
+
+#[test]
+fn matches_container_layout() {
+    let mut bits = StaticPackedBits::<7, { bytes_for(7, 4) }>::new();
+    for v in [100, 50, 127, 1] {
+        bits.push(v).unwrap();
+    }
+
+    let mut values = [0u32; 4];
+    for (slot, v) in values.iter_mut().zip(bits.iter()) {
+        *slot = v;
+    }
+    assert_eq!(values, [100, 50, 127, 1]);
+
+    bits.clear();
+    assert!(bits.is_empty());
+    assert_eq!(bits.as_bytes(), &[0u8; 4]);
+}
+
+#[test]
+#[should_panic(expected = "value must fit")]
+fn push_rejects_wide_value() {
+    let mut bits = StaticPackedBits::<4, 1>::new();
+    let _ = bits.push(16);
+}
@@ -0,0 +1,157 @@
+#![cfg(feature = "mmap")]
+
+use packed_bits::{FlagsContainer, PackedBitsContainer, PackedBitsError};
+use std::io::Write;
+use tempfile::NamedTempFile;
+
+/// Write an in-memory container's storage (header included) to a temp file.
+fn write_container<const N: usize>(values: &[u32], capacity: usize) -> NamedTempFile {
+    let mut pb = PackedBitsContainer::<N>::with_capacity(capacity).unwrap();
+    for &v in values {
+        pb.push(v).unwrap();
+    }
+
+    let mut file = NamedTempFile::new().unwrap();
+    file.write_all(pb.storage().as_slice()).unwrap();
+    file.flush().unwrap();
+    file
+}
+
+#[test]
+fn mmap_readonly_get() {
+    let values = [0, 1, 511, 300, 42];
+    let file = write_container::<9>(&values, values.len());
+
+    let pb = PackedBitsContainer::<9>::mmap_readonly(file.path()).unwrap();
+    assert_eq!(pb.len(), values.len());
+    assert_eq!(pb.iter().collect::<Vec<_>>(), values);
+    assert_eq!(pb.get(5), None);
+}
+
+#[test]
+fn mmap_readonly_rejects_mutation() {
+    let file = write_container::<9>(&[1, 2, 3], 3);
+    let mut pb = PackedBitsContainer::<9>::mmap_readonly(file.path()).unwrap();
+
+    assert!(matches!(pb.set(0, 7), Err(PackedBitsError::StorageReadOnly)));
+    assert!(matches!(pb.push(7), Err(PackedBitsError::StorageReadOnly)));
+    assert!(matches!(pb.clear(), Err(PackedBitsError::StorageReadOnly)));
+    assert_eq!(pb.get(0), Some(1));
+}
+
+#[test]
+fn mmap_readwrite_set_persists() {
+    let file = write_container::<13>(&[10, 20, 30, 40], 4);
+
+    {
+        let mut pb = PackedBitsContainer::<13>::mmap_readwrite(file.path()).unwrap();
+        pb.set(1, 8191).unwrap();
+        pb.set(3, 0).unwrap();
+        assert_eq!(pb.get(1), Some(8191));
+    }
+
+    // Reopen the file both as a mapping and through `from_storage`
+    let pb = PackedBitsContainer::<13>::mmap_readonly(file.path()).unwrap();
+    assert_eq!(pb.iter().collect::<Vec<_>>(), vec![10, 8191, 30, 0]);
+
+    let bytes = std::fs::read(file.path()).unwrap();
+    let restored =
+        PackedBitsContainer::<13>::from_storage(raw_bytes::Container::from_slice(&bytes))
+            .unwrap();
+    assert_eq!(restored.iter().collect::<Vec<_>>(), vec![10, 8191, 30, 0]);
+}
+
+#[test]
+fn mmap_readwrite_push_not_supported() {
+    // Even with spare capacity in the file
+    let file = write_container::<5>(&[1, 2], 100);
+    let mut pb = PackedBitsContainer::<5>::mmap_readwrite(file.path()).unwrap();
+
+    assert!(matches!(pb.push(3), Err(PackedBitsError::Container(_))));
+    assert_eq!(pb.len(), 2);
+
+    pb.clear().unwrap();
+    assert!(pb.is_empty());
+    drop(pb);
+
+    let pb = PackedBitsContainer::<5>::mmap_readonly(file.path()).unwrap();
+    assert!(pb.is_empty());
+}
+
+#[test]
+fn mmap_validates_header() {
+    let file = write_container::<7>(&[1, 2, 3], 3);
+    assert!(matches!(
+        PackedBitsContainer::<8>::mmap_readonly(file.path()),
+        Err(PackedBitsError::InvalidN {
+            expected: 8,
+            found: 7
+        })
+    ));
+
+    let mut garbage = NamedTempFile::new().unwrap();
+    garbage.write_all(b"not a packed bits file").unwrap();
+    garbage.flush().unwrap();
+    assert!(matches!(
+        PackedBitsContainer::<7>::mmap_readonly(garbage.path()),
+        Err(PackedBitsError::InvalidMagic)
+    ));
+
+    // Header claims more elements than the file holds
+    let mut bytes = std::fs::read(file.path()).unwrap();
+    bytes[8..12].copy_from_slice(&100u32.to_le_bytes());
+    let mut truncated = NamedTempFile::new().unwrap();
+    truncated.write_all(&bytes).unwrap();
+    truncated.flush().unwrap();
+    assert!(matches!(
+        PackedBitsContainer::<7>::mmap_readwrite(truncated.path()),
+        Err(PackedBitsError::InsufficientBytes(100))
+    ));
+}
+
+const READ: u32 = 1 << 0;
+const WRITE: u32 = 1 << 1;
+const EXECUTE: u32 = 1 << 2;
+
+#[test]
+fn flags_mmap_readwrite_persists() {
+    let mut flags = FlagsContainer::<3>::new_in_memory().unwrap();
+    flags.push(READ).unwrap();
+    flags.push(READ | WRITE).unwrap();
+    flags.push(EXECUTE).unwrap();
+
+    let mut file = NamedTempFile::new().unwrap();
+    file.write_all(flags.packed_bits().storage().as_slice()).unwrap();
+    file.flush().unwrap();
+
+    {
+        let mut mapped = FlagsContainer::<3>::mmap_readwrite(file.path()).unwrap();
+        mapped.set_mask(0, EXECUTE).unwrap();
+        mapped.clear_mask(1, WRITE).unwrap();
+        mapped.toggle_mask(2, READ | EXECUTE).unwrap();
+        assert!(matches!(mapped.push(READ), Err(PackedBitsError::Container(_))));
+    }
+
+    let reopened = FlagsContainer::<3>::mmap_readonly(file.path()).unwrap();
+    assert_eq!(reopened.len(), 3);
+    assert_eq!(reopened.get(0), Some(READ | EXECUTE));
+    assert_eq!(reopened.get(1), Some(READ));
+    assert_eq!(reopened.get(2), Some(READ));
+}
+
+#[test]
+fn flags_mmap_readonly_rejects_masks() {
+    let mut flags = FlagsContainer::<3>::new_in_memory().unwrap();
+    flags.push(WRITE).unwrap();
+
+    let mut file = NamedTempFile::new().unwrap();
+    file.write_all(flags.packed_bits().storage().as_slice()).unwrap();
+    file.flush().unwrap();
+
+    let mut mapped = FlagsContainer::<3>::mmap_readonly(file.path()).unwrap();
+    assert!(mapped.contains(0, WRITE));
+    assert!(matches!(mapped.set_mask(0, READ), Err(PackedBitsError::StorageReadOnly)));
+    assert!(matches!(mapped.clear_mask(0, WRITE), Err(PackedBitsError::StorageReadOnly)));
+    assert!(matches!(mapped.toggle_mask(0, WRITE), Err(PackedBitsError::StorageReadOnly)));
+    assert_eq!(mapped.get(0), Some(WRITE));
+}
@@ -704,3 +704,58 @@ proptest! {
         prop_assert_eq!(container.len(), 0);
     }
 }
+
+//
+// -----------------------------------------------------------------------------
+// Search
+// -----------------------------------------------------------------------------
+
+proptest! {
+    #[test]
+    fn prop_find_locates_pushed_value(
+        values in prop::collection::vec(0u32..64, 1..300),
+        pick in any::<prop::sample::Index>()
+    ) {
+        let mut container = PackedBitsContainer::<6>::new_in_memory().unwrap();
+        for &v in &values {
+            container.push(v).unwrap();
+        }
+
+        let target = values[pick.index(values.len())];
+        let first = container.find(target);
+        let last = container.rfind(target);
+
+        prop_assert_eq!(first, values.iter().position(|&v| v == target));
+        prop_assert_eq!(last, values.iter().rposition(|&v| v == target));
+
+        let all: Vec<usize> = container.find_all(target).collect();
+        prop_assert_eq!(all.first().copied(), first);
+        prop_assert_eq!(all.last().copied(), last);
+        prop_assert!(all.iter().all(|&i| values[i] == target));
+    }
+}
+
+//
+// -----------------------------------------------------------------------------
+// Compaction
+// -----------------------------------------------------------------------------
+
+proptest! {
+    #[test]
+    fn prop_sorted_dedup_strictly_increasing(
+        mut values in prop::collection::vec(0u32..32, 0..300)
+    ) {
+        values.sort_unstable();
+        let mut container = PackedBitsContainer::<5>::new_in_memory().unwrap();
+        for &v in &values {
+            container.push(v).unwrap();
+        }
+
+        container.dedup().unwrap();
+        let deduped: Vec<u32> = container.iter().collect();
+
+        prop_assert!(deduped.windows(2).all(|w| w[0] < w[1]));
+        values.dedup();
+        prop_assert_eq!(deduped, values);
+    }
+}
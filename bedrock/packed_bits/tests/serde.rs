@@ -0,0 +1,86 @@
+#![cfg(feature = "serde")]
+
+use packed_bits::{FlagsContainer, PackedBitsContainer};
+
+#[test]
+fn serde_json_roundtrip() {
+    let mut pb = PackedBitsContainer::<12>::new_in_memory().unwrap();
+    for v in [0xABC, 0x123, 0xFFF, 0] {
+        pb.push(v).unwrap();
+    }
+
+    let json = serde_json::to_string(&pb).unwrap();
+    assert_eq!(json, r#"{"bits":12,"values":[2748,291,4095,0]}"#);
+
+    let restored: PackedBitsContainer<12> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.len(), 4);
+    assert_eq!(restored.iter().collect::<Vec<_>>(), pb.iter().collect::<Vec<_>>());
+}
+
+#[test]
+fn serde_json_empty_and_full_width() {
+    let empty = PackedBitsContainer::<3>::new_in_memory().unwrap();
+    let json = serde_json::to_string(&empty).unwrap();
+    let restored: PackedBitsContainer<3> = serde_json::from_str(&json).unwrap();
+    assert!(restored.is_empty());
+
+    let mut wide = PackedBitsContainer::<32>::new_in_memory().unwrap();
+    wide.push(u32::MAX).unwrap();
+    let json = serde_json::to_string(&wide).unwrap();
+    let restored: PackedBitsContainer<32> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.get(0), Some(u32::MAX));
+}
+
+#[test]
+fn serde_json_rejects_wrong_width() {
+    let result = serde_json::from_str::<PackedBitsContainer<8>>(r#"{"bits":12,"values":[1]}"#);
+    let err = result.unwrap_err().to_string();
+    assert!(err.contains("N mismatch: expected 8, found 12"), "{err}");
+}
+
+#[test]
+fn serde_json_rejects_oversized_value() {
+    let result = serde_json::from_str::<PackedBitsContainer<4>>(r#"{"bits":4,"values":[3,16]}"#);
+    let err = result.unwrap_err().to_string();
+    assert!(err.contains("Value 16 does not fit in 4 bits"), "{err}");
+}
+
+#[test]
+fn flags_serde_json_roundtrip() {
+    let mut flags = FlagsContainer::<8>::new_in_memory().unwrap();
+    for v in [0b0000_0101, 0, 0b1000_0000, 0xFF] {
+        flags.push(v).unwrap();
+    }
+
+    let json = serde_json::to_string(&flags).unwrap();
+    assert_eq!(json, "[5,0,128,255]");
+
+    let restored: FlagsContainer<8> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.iter().collect::<Vec<_>>(), vec![5, 0, 128, 255]);
+}
+
+#[test]
+fn flags_bit_positions_view() {
+    let mut flags = FlagsContainer::<8>::new_in_memory().unwrap();
+    for v in [0b0000_0101, 0, 0b1000_0010] {
+        flags.push(v).unwrap();
+    }
+
+    let json = serde_json::to_string(&flags.bit_positions()).unwrap();
+    assert_eq!(json, "[[0,2],[],[1,7]]");
+
+    // Both forms deserialize, and may be mixed
+    let restored: FlagsContainer<8> = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.iter().collect::<Vec<_>>(), vec![5, 0, 130]);
+    let mixed: FlagsContainer<8> = serde_json::from_str("[[3],16]").unwrap();
+    assert_eq!(mixed.iter().collect::<Vec<_>>(), vec![8, 16]);
+}
+
+#[test]
+fn flags_reject_out_of_range() {
+    let err = serde_json::from_str::<FlagsContainer<4>>("[[4]]").unwrap_err().to_string();
+    assert!(err.contains("Bit position 4 out of range for 4 flags"), "{err}");
+
+    let err = serde_json::from_str::<FlagsContainer<4>>("[16]").unwrap_err().to_string();
+    assert!(err.contains("Value 16 does not fit in 4 bits"), "{err}");
+}
@@ -0,0 +1,5 @@
+use packed_bits::PackedBitsContainer;
+
+fn main() {
+    let _ = PackedBitsContainer::<33>::with_capacity(4);
+}
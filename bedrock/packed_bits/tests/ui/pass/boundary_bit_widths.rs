@@ -0,0 +1,6 @@
+use packed_bits::PackedBitsContainer;
+
+fn main() {
+    let _ = PackedBitsContainer::<1>::new_in_memory();
+    let _ = PackedBitsContainer::<32>::with_capacity(4);
+}
@@ -0,0 +1,5 @@
+use packed_bits::PackedBitsContainer;
+
+fn main() {
+    let _ = PackedBitsContainer::<0>::new_in_memory();
+}
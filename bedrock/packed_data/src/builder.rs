@@ -3,6 +3,9 @@
 
 use std::marker::PhantomData;
 
+use fixed_point::{FixedPointArray, FixedPointError};
+use packed_bits::{PackedBitsContainer, PackedBitsError};
+
 /// Builder for creating packed data arrays with a fluent API
 pub struct PackedDataBuilder<T> {
     data: Vec<T>,
@@ -39,6 +42,67 @@ impl<T> PackedDataBuilder<T> {
     }
 }
 
+impl PackedDataBuilder<u32> {
+    /// Packs the collected values into an `N`-bit container.
+    ///
+    /// # Errors
+    ///
+    /// Returns `PackedBitsError::ValueOverflow` if a value does not fit in N bits.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use packed_data::builder::PackedDataBuilder;
+    ///
+    /// let packed = PackedDataBuilder::new()
+    ///     .push(3u32)
+    ///     .extend([7, 1, 5])
+    ///     .build_packed::<3>()?;
+    ///
+    /// assert_eq!(packed.len(), 4);
+    /// assert_eq!(packed.iter().collect::<Vec<_>>(), vec![3, 7, 1, 5]);
+    /// # Ok::<(), packed_bits::PackedBitsError>(())
+    /// ```
+    pub fn build_packed<const N: usize>(&self) -> Result<PackedBitsContainer<N>, PackedBitsError> {
+        let max_val = if N == 32 { u32::MAX } else { (1u32 << N) - 1 };
+        if let Some(&value) = self.data.iter().find(|&&value| value > max_val) {
+            return Err(PackedBitsError::ValueOverflow(value, N));
+        }
+
+        let mut container = PackedBitsContainer::with_capacity(self.data.len())?;
+        for &value in &self.data {
+            container.push(value)?;
+        }
+        Ok(container)
+    }
+}
+
+impl PackedDataBuilder<f32> {
+    /// Converts the collected values into a fixed-point array.
+    ///
+    /// # Errors
+    ///
+    /// Returns `FixedPointError::Overflow` if a value is outside the format's range.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use packed_data::builder::PackedDataBuilder;
+    ///
+    /// let array = PackedDataBuilder::new()
+    ///     .extend([1.5f32, -2.25, 3.0])
+    ///     .build_fixed_point::<16, 8>()?;
+    ///
+    /// assert_eq!(array.to_f32_vec(), vec![1.5, -2.25, 3.0]);
+    /// # Ok::<(), fixed_point::FixedPointError>(())
+    /// ```
+    pub fn build_fixed_point<const N: usize, const F: usize>(
+        &self,
+    ) -> Result<FixedPointArray<N, F>, FixedPointError> {
+        FixedPointArray::from_iter(self.data.iter().copied())
+    }
+}
+
 impl<T> Default for PackedDataBuilder<T> {
     fn default() -> Self {
         Self::new()
@@ -76,6 +140,32 @@ impl<T> EntityBuilder<T> {
         }
     }
 
+    /// Adds every `Ok` item, failing on the first `Err`.
+    pub fn try_add_all<E, I>(mut self, iter: I) -> Result<Self, E>
+    where
+        I: IntoIterator<Item = Result<T, E>>,
+    {
+        for item in iter {
+            self.items.push(item?);
+        }
+        Ok(self)
+    }
+
+    /// Adds items until the first `Err`, handing back the partially-built
+    /// builder together with the error.
+    pub fn try_extend_from_iter<E, I>(mut self, iter: I) -> Result<Self, (Self, E)>
+    where
+        I: IntoIterator<Item = Result<T, E>>,
+    {
+        for item in iter {
+            match item {
+                Ok(v) => self.items.push(v),
+                Err(e) => return Err((self, e)),
+            }
+        }
+        Ok(self)
+    }
+
     pub fn build(self) -> Vec<T> {
         self.items
     }
@@ -113,6 +203,46 @@ mod tests {
         Ok(())
     }
 
+    fn items_then_error() -> Vec<Result<Dummy, &'static str>> {
+        vec![Ok(Dummy(1, 2)), Ok(Dummy(3, 4)), Ok(Dummy(5, 6)), Err("bad"), Ok(Dummy(7, 8))]
+    }
+
+    #[test]
+    fn test_entity_builder_try_add_all() {
+        let err = EntityBuilder::new().try_add_all(items_then_error()).unwrap_err();
+        assert_eq!(err, "bad");
+
+        let ok: Vec<Result<Dummy, &'static str>> = vec![Ok(Dummy(1, 2)), Ok(Dummy(3, 4))];
+        let built = EntityBuilder::new().add(Dummy(0, 0)).try_add_all(ok).unwrap().build();
+        assert_eq!(built, vec![Dummy(0, 0), Dummy(1, 2), Dummy(3, 4)]);
+    }
+
+    #[test]
+    fn test_entity_builder_try_extend_from_iter() {
+        let (partial, err) = EntityBuilder::new()
+            .try_extend_from_iter(items_then_error())
+            .unwrap_err();
+        assert_eq!(err, "bad");
+        assert_eq!(partial.build(), vec![Dummy(1, 2), Dummy(3, 4), Dummy(5, 6)]);
+    }
+
+    #[test]
+    fn test_build_packed_overflow() {
+        let builder = PackedDataBuilder::new().extend([1u32, 8, 2]);
+        assert!(matches!(
+            builder.build_packed::<3>(),
+            Err(PackedBitsError::ValueOverflow(8, 3))
+        ));
+        assert_eq!(builder.build_packed::<4>().unwrap().get(1), Some(8));
+    }
+
+    #[test]
+    fn test_build_fixed_point_overflow() {
+        let builder = PackedDataBuilder::new().extend([1.0f32, 1000.0]);
+        assert!(builder.build_fixed_point::<16, 8>().is_err());
+        assert_eq!(builder.build_fixed_point::<32, 8>().unwrap().len(), 2);
+    }
+
     #[test]
     fn test_entity_builder_extend() {
         let items = vec![Dummy(5, 6), Dummy(7, 8)];
@@ -0,0 +1,13 @@
+use thiserror::Error;
+
+/// Errors from packed data I/O
+#[derive(Debug, Error)]
+pub enum PackedDataError {
+    /// IO error
+    #[error("IO error: {0}")]
+    Io(#[from] std::io::Error),
+
+    /// Input length is not a whole number of elements
+    #[error("{byte_count} bytes is not a multiple of the {element_size}-byte element size")]
+    IncompleteElement { byte_count: usize, element_size: usize },
+}
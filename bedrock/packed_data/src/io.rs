@@ -9,6 +9,8 @@ use mtf::dynamic::DynamicContainer;
 use save::{save, load, SaveError};
 use bytemuck::Pod;
 
+use crate::error::PackedDataError;
+
 /// Save data with MTF metadata for runtime introspection
 pub fn save_with_metadata<T, P>(path: P, data: &[T]) -> io::Result<()>
 where
@@ -42,7 +44,31 @@ where
 }
 
 /// Load raw bytes into typed slice
-pub fn load_raw<T, P>(path: P) -> io::Result<Vec<T>>
+///
+/// Fails with `PackedDataError::IncompleteElement` if the file size is not a
+/// multiple of `size_of::<T>()`; use [`load_raw_partial`] to drop trailing bytes instead.
+pub fn load_raw<T, P>(path: P) -> Result<Vec<T>, PackedDataError>
+where
+    T: Pod,
+    P: AsRef<Path>,
+{
+    let bytes = std::fs::read(path)?;
+    let element_size = std::mem::size_of::<T>();
+    if !bytes.len().is_multiple_of(element_size) {
+        return Err(PackedDataError::IncompleteElement {
+            byte_count: bytes.len(),
+            element_size,
+        });
+    }
+
+    Ok(bytes
+        .chunks_exact(element_size)
+        .map(bytemuck::pod_read_unaligned)
+        .collect())
+}
+
+/// Load raw bytes into typed slice, discarding a trailing partial element
+pub fn load_raw_partial<T, P>(path: P) -> io::Result<Vec<T>>
 where
     T: Pod,
     P: AsRef<Path>,
@@ -67,7 +93,7 @@ where
     P: AsRef<Path>,
 {
     let container = packed_structs::PackedStructContainer::from_slice(data);
-    save::save_to_file(path, &container)
+    save::save_to_file(path, &container, 0)
         .map_err(|e| io::Error::new(io::ErrorKind::Other, e))
 }
 
@@ -80,9 +106,9 @@ where
     T: Pod + Copy,
     P: AsRef<Path>,
 {
-    let container = save::load_from_file(path)
+    let (data, _user_version) = save::load_from_file(path)
         .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
-    Ok(container.as_slice().to_vec())
+    Ok(data)
 }
 
 /// Efficient streaming writer for large datasets
@@ -143,3 +169,199 @@ impl<R: Read, T: Pod> PackedReader<R, T> {
         Ok(bytemuck::cast_slice(&bytes).to_vec())
     }
 }
+
+/// Streaming writer that collects up to `BUF_SIZE` elements in a stack array
+/// before handing them to the underlying writer in one `write_all`.
+///
+/// Buffered elements are flushed when the writer is dropped, but errors are
+/// ignored there; call [`flush`](Self::flush) to observe them.
+pub struct BufferedPackedWriter<W: Write, T: Pod, const BUF_SIZE: usize> {
+    writer: W,
+    buf: [T; BUF_SIZE],
+    len: usize,
+}
+
+impl<W: Write, T: Pod, const BUF_SIZE: usize> BufferedPackedWriter<W, T, BUF_SIZE> {
+    pub fn new(writer: W) -> Self {
+        const { assert!(BUF_SIZE > 0, "BUF_SIZE must be non-zero") };
+        Self {
+            writer,
+            buf: [T::zeroed(); BUF_SIZE],
+            len: 0,
+        }
+    }
+
+    pub fn write(&mut self, item: &T) -> io::Result<()> {
+        if self.len == BUF_SIZE {
+            self.flush_buffer()?;
+        }
+        self.buf[self.len] = *item;
+        self.len += 1;
+        Ok(())
+    }
+
+    pub fn write_batch(&mut self, items: &[T]) -> io::Result<()> {
+        for item in items {
+            self.write(item)?;
+        }
+        Ok(())
+    }
+
+    /// Writes out any buffered elements and flushes the underlying writer.
+    pub fn flush(&mut self) -> io::Result<()> {
+        self.flush_buffer()?;
+        self.writer.flush()
+    }
+
+    /// Number of elements waiting in the buffer.
+    pub fn buffered(&self) -> usize {
+        self.len
+    }
+
+    fn flush_buffer(&mut self) -> io::Result<()> {
+        if self.len > 0 {
+            self.writer
+                .write_all(bytemuck::cast_slice(&self.buf[..self.len]))?;
+            self.len = 0;
+        }
+        Ok(())
+    }
+}
+
+impl<W: Write, T: Pod, const BUF_SIZE: usize> Drop for BufferedPackedWriter<W, T, BUF_SIZE> {
+    fn drop(&mut self) {
+        let _ = self.flush();
+    }
+}
+
+/// Streaming reader that reads ahead up to `BUF_SIZE` elements into a stack array.
+///
+/// Like [`PackedReader`], a trailing partial element at end of input is discarded.
+pub struct BufferedPackedReader<R: Read, T: Pod, const BUF_SIZE: usize> {
+    reader: R,
+    buf: [T; BUF_SIZE],
+    pos: usize,
+    len: usize,
+}
+
+impl<R: Read, T: Pod, const BUF_SIZE: usize> BufferedPackedReader<R, T, BUF_SIZE> {
+    pub fn new(reader: R) -> Self {
+        const { assert!(BUF_SIZE > 0, "BUF_SIZE must be non-zero") };
+        Self {
+            reader,
+            buf: [T::zeroed(); BUF_SIZE],
+            pos: 0,
+            len: 0,
+        }
+    }
+
+    pub fn read(&mut self) -> io::Result<Option<T>> {
+        if self.pos == self.len {
+            self.fill_buffer()?;
+            if self.len == 0 {
+                return Ok(None);
+            }
+        }
+        let item = self.buf[self.pos];
+        self.pos += 1;
+        Ok(Some(item))
+    }
+
+    /// Reads up to `count` elements, returning fewer only at end of input.
+    pub fn read_batch(&mut self, count: usize) -> io::Result<Vec<T>> {
+        let mut items = Vec::with_capacity(count);
+        while items.len() < count {
+            match self.read()? {
+                Some(item) => items.push(item),
+                None => break,
+            }
+        }
+        Ok(items)
+    }
+
+    fn fill_buffer(&mut self) -> io::Result<()> {
+        let bytes: &mut [u8] = bytemuck::cast_slice_mut(&mut self.buf);
+        let mut filled = 0;
+        while filled < bytes.len() {
+            match self.reader.read(&mut bytes[filled..]) {
+                Ok(0) => break,
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        self.pos = 0;
+        self.len = filled / std::mem::size_of::<T>().max(1);
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_load_raw_incomplete_element() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let file = tempfile::NamedTempFile::new()?;
+        std::fs::write(file.path(), [1u8, 2, 3])?;
+
+        assert!(matches!(
+            load_raw::<u32, _>(file.path()),
+            Err(PackedDataError::IncompleteElement {
+                byte_count: 3,
+                element_size: 4
+            })
+        ));
+        assert!(load_raw_partial::<u32, _>(file.path())?.is_empty());
+        Ok(())
+    }
+
+    #[test]
+    fn test_load_raw_round_trip() -> std::result::Result<(), Box<dyn std::error::Error>> {
+        let file = tempfile::NamedTempFile::new()?;
+        save_raw(file.path(), &[1u32, 2, 3])?;
+        assert_eq!(load_raw::<u32, _>(file.path())?, vec![1, 2, 3]);
+        Ok(())
+    }
+
+    #[test]
+    fn test_buffered_round_trip() -> io::Result<()> {
+        let values: Vec<u32> = (0..1000).map(|i| i * 7).collect();
+
+        let mut bytes = Vec::new();
+        let mut writer = BufferedPackedWriter::<_, u32, 64>::new(&mut bytes);
+        for v in &values {
+            writer.write(v)?;
+        }
+        assert_eq!(writer.buffered(), 1000 % 64);
+        writer.flush()?;
+        drop(writer);
+        assert_eq!(bytes.len(), 1000 * 4);
+
+        let mut reader = BufferedPackedReader::<_, u32, 64>::new(bytes.as_slice());
+        let mut read_back = Vec::new();
+        while let Some(v) = reader.read()? {
+            read_back.push(v);
+        }
+        assert_eq!(read_back, values);
+        Ok(())
+    }
+
+    #[test]
+    fn test_buffered_writer_flushes_on_drop() {
+        let mut bytes = Vec::new();
+        {
+            let mut writer = BufferedPackedWriter::<_, u16, 8>::new(&mut bytes);
+            writer.write_batch(&[1, 2, 3]).unwrap();
+        }
+        assert_eq!(bytes, bytemuck::cast_slice::<u16, u8>(&[1, 2, 3]));
+    }
+
+    #[test]
+    fn test_buffered_reader_discards_partial_element() {
+        let bytes = [1u8, 0, 0, 0, 2, 0, 0, 0, 3, 0];
+        let mut reader = BufferedPackedReader::<_, u32, 4>::new(&bytes[..]);
+        assert_eq!(reader.read_batch(10).unwrap(), vec![1, 2]);
+        assert_eq!(reader.read().unwrap(), None);
+    }
+}
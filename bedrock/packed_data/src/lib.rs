@@ -49,9 +49,14 @@ pub mod builder;
 pub mod convert;
 pub mod iter;
 pub mod io;
+pub mod error;
+#[cfg(feature = "verified")]
+pub mod verified;
 
 pub use crate::convert::{ToBytes, FromBytes, PackedConvert, batch, try_parse_iter, parse_with};
 pub use crate::iter::{iter_parse, SliceParseExt};
+pub use crate::error::PackedDataError;
+pub use crate::io::{PackedWriter, PackedReader, BufferedPackedWriter, BufferedPackedReader};
 
 // Re-export MTF types
 pub use mtf::{MTF, MTFType, MTFError};
@@ -84,8 +89,11 @@ pub use raw_bytes::{Container, Storage, ContainerError};
 #[cfg(feature = "verified")]
 pub use save::{
     save, load,
-    //merkle::MerkleNode, merkle::build_merkle_tree, merkle::verify_merkle_tree,
 };
+#[cfg(feature = "verified")]
+pub use ::save::save::save_streaming;
+#[cfg(feature = "verified")]
+pub use ::save::merkle::{MerkleNode, build_merkle_tree, verify_merkle_tree};
 
 // Re-export for convenience
 pub use bytemuck::{Pod, Zeroable};
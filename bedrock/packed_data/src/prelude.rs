@@ -3,7 +3,9 @@
 
 pub use crate::builder::{PackedDataBuilder, EntityBuilder};
 pub use crate::convert::{ToBytes, FromBytes, PackedConvert};
-pub use crate::io::{save_with_metadata, load_dynamic, save_raw, load_raw};
+pub use crate::io::{save_with_metadata, load_dynamic, save_raw, load_raw, load_raw_partial};
+pub use crate::io::{PackedWriter, PackedReader, BufferedPackedWriter, BufferedPackedReader};
+pub use crate::error::PackedDataError;
 
 // MTF types
 pub use mtf::{MTF, MTFType};
@@ -29,6 +31,10 @@ pub use bytemuck::{Pod, Zeroable, cast_slice, cast_slice_mut};
 // Re-export verified save functions
 #[cfg(feature = "verified")]
 pub use save::{save, load};
+#[cfg(feature = "verified")]
+pub use ::save::save::save_streaming;
+#[cfg(feature = "verified")]
+pub use crate::io::{save_verified, load_verified};
 
 // Common result type
 pub type Result<T> = std::result::Result<T, Box<dyn std::error::Error>>;
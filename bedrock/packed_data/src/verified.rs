@@ -0,0 +1,23 @@
+//! Verified save functions from the `save` crate.
+//!
+//! [`save_with_metadata`] and [`load_with_metadata`] here read and write the
+//! `save` format's key-value metadata section. They are unrelated to
+//! [`crate::io::save_with_metadata`], which writes MTF files read back by
+//! [`crate::io::load_dynamic`].
+//!
+//! ```rust
+//! use std::collections::HashMap;
+//! use packed_data::verified::{load_with_metadata, save_with_metadata};
+//!
+//! let meta = HashMap::from([("author".to_string(), "stormogulen".to_string())]);
+//! let mut bytes = Vec::new();
+//! save_with_metadata(&mut bytes, &[1u32, 2, 3], 2, &meta)?;
+//!
+//! let (data, user_version, loaded) = load_with_metadata::<u32, _>(&mut bytes.as_slice())?;
+//! assert_eq!(data, [1, 2, 3]);
+//! assert_eq!(user_version, 2);
+//! assert_eq!(loaded, meta);
+//! # Ok::<(), Box<dyn std::error::Error>>(())
+//! ```
+
+pub use ::save::save::{Metadata, load_with_metadata, save_streaming, save_with_metadata};
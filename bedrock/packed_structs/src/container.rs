@@ -187,6 +187,32 @@ impl<T: Pod + Copy> PackedStructContainer<T> {
         self.storage.clear()
     }
 
+    /// Retain only the elements for which `f` returns `true` (in-memory only).
+    ///
+    /// Mirrors [`Vec::retain`]; the order of retained elements is preserved.
+    ///
+    /// # Errors
+    /// Returns an error if the storage is memory-mapped.
+    pub fn retain<F>(&mut self, f: F) -> Result<(), raw_bytes::ContainerError>
+    where
+        F: FnMut(&T) -> bool,
+    {
+        self.storage.retain(f)
+    }
+
+    /// Remove consecutive elements that `same_bucket` considers equal (in-memory only).
+    ///
+    /// Mirrors [`Vec::dedup_by`]; the first element of each run is kept.
+    ///
+    /// # Errors
+    /// Returns an error if the storage is memory-mapped.
+    pub fn dedup_by<F>(&mut self, same_bucket: F) -> Result<(), raw_bytes::ContainerError>
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        self.storage.dedup_by(same_bucket)
+    }
+
     /// Write a value to the element at the given index.
     ///
     /// # Errors
@@ -226,6 +252,15 @@ impl<T: Pod + Copy> Default for PackedStructContainer<T> {
     }
 }
 
+/// Containers are equal when their elements are, regardless of storage backend.
+impl<T: Pod + Copy + PartialEq> PartialEq for PackedStructContainer<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Pod + Copy + Eq> Eq for PackedStructContainer<T> {}
+
 /// Deref to slice for ergonomic access.
 ///
 /// Allows using the container like a slice: `container[i]`, `container.len()`, etc.
@@ -259,6 +294,60 @@ impl<'a, T: Pod + Copy> IntoIterator for &'a PackedStructContainer<T> {
     }
 }
 
+/// Consume the container, yielding owned elements.
+///
+/// In-memory storage hands over its vector; memory-mapped storage copies its
+/// elements out first.
+impl<T: Pod + Copy> IntoIterator for PackedStructContainer<T> {
+    type Item = T;
+    type IntoIter = std::vec::IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.storage.into_vec().into_iter()
+    }
+}
+
+/// Collect into a new in-memory container.
+///
+/// # Example
+/// ```
+/// use packed_structs::PackedStructContainer;
+///
+/// let container: PackedStructContainer<u32> = (1..=3).collect();
+/// assert_eq!(container.as_slice(), &[1, 2, 3]);
+/// ```
+impl<T: Pod + Copy> FromIterator<T> for PackedStructContainer<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Self::validate_alignment();
+        Self {
+            storage: iter.into_iter().collect(),
+            _marker: PhantomData,
+        }
+    }
+}
+
+/// Append elements from an iterator (in-memory only).
+///
+/// Method-call syntax resolves to the inherent, fallible
+/// [`PackedStructContainer::extend`]; this impl serves generic code.
+///
+/// # Panics
+/// Panics if the container is backed by a memory-mapped file.
+///
+/// # Example
+/// ```
+/// use packed_structs::PackedStructContainer;
+///
+/// let mut container = PackedStructContainer::from_slice(&[1u32]);
+/// Extend::extend(&mut container, [2, 3]);
+/// assert_eq!(container.as_slice(), &[1, 2, 3]);
+/// ```
+impl<T: Pod + Copy> Extend<T> for PackedStructContainer<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        Extend::extend(&mut self.storage, iter);
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -315,6 +404,57 @@ mod tests {
         assert_eq!(container.len(), 2);
     }
 
+    #[test]
+    fn test_collect() {
+        let container: PackedStructContainer<Point> = (0..4)
+            .map(|i| Point { x: i as f32, y: 0.0 })
+            .collect();
+
+        assert_eq!(container.len(), 4);
+        assert_eq!(container[3].x, 3.0);
+    }
+
+    #[test]
+    fn test_extend_trait() {
+        fn fill<C: Extend<Point>>(target: &mut C) {
+            target.extend((0..3).map(|i| Point { x: i as f32, y: 1.0 }));
+        }
+
+        let mut container = PackedStructContainer::from_slice(&[Point { x: 9.0, y: 9.0 }]);
+        fill(&mut container);
+
+        assert_eq!(container.len(), 4);
+        assert_eq!(container[0].x, 9.0);
+        assert_eq!(container[3], Point { x: 2.0, y: 1.0 });
+    }
+
+    #[test]
+    fn test_retain() {
+        let mut container: PackedStructContainer<Point> = (0..6)
+            .map(|i| Point { x: i as f32, y: 0.0 })
+            .collect();
+
+        container.retain(|p| p.x >= 3.0).unwrap();
+        assert_eq!(container.len(), 3);
+        assert_eq!(container[0].x, 3.0);
+    }
+
+    #[test]
+    fn test_dedup_by() {
+        let mut container = PackedStructContainer::from_slice(&[
+            Point { x: 1.0, y: 1.0 },
+            Point { x: 1.0, y: 2.0 },
+            Point { x: 2.0, y: 3.0 },
+            Point { x: 2.0, y: 4.0 },
+        ]);
+
+        container.dedup_by(|a, b| a.x == b.x).unwrap();
+        assert_eq!(
+            container.as_slice(),
+            &[Point { x: 1.0, y: 1.0 }, Point { x: 2.0, y: 3.0 }]
+        );
+    }
+
     #[test]
     fn test_deref() {
         let mut container = PackedStructContainer::from_slice(&[
@@ -343,6 +483,21 @@ mod tests {
         assert_eq!(collected2, points);
     }
 
+    #[test]
+    fn test_owned_iterator() {
+        let points = [Point { x: 1.0, y: 2.0 }, Point { x: 3.0, y: 4.0 }];
+
+        let mut sum = 0.0;
+        for point in PackedStructContainer::from_slice(&points) {
+            sum += point.x + point.y;
+        }
+        assert_eq!(sum, 10.0);
+
+        let container = PackedStructContainer::from_slice(&points);
+        let xs: Vec<f32> = container.into_iter().map(|p| p.x).collect();
+        assert_eq!(xs, vec![1.0, 3.0]);
+    }
+
     #[test]
     fn test_get_mut() {
         let mut container = PackedStructContainer::from_slice(&[Point { x: 1.0, y: 2.0 }]);
@@ -396,6 +551,23 @@ mod tests {
         assert_eq!(container[0].y, 88.0);
     }
 
+    #[test]
+    fn test_equality() {
+        let points = [Point { x: 1.0, y: 2.0 }, Point { x: 3.0, y: 4.0 }];
+        let a = PackedStructContainer::from_slice(&points);
+        let mut b = PackedStructContainer::from_slice(&points);
+        assert_eq!(a, b);
+
+        b[1].y = 4.5;
+        assert_ne!(a, b);
+
+        let shorter = PackedStructContainer::from_slice(&points[..1]);
+        assert_ne!(a, shorter);
+
+        let ids = PackedStructContainer::from_slice(&[1u32, 2, 3]);
+        assert_eq!(ids, PackedStructContainer::from_slice(&[1u32, 2, 3]));
+    }
+
     #[test]
     fn test_reserve() {
         let mut container = PackedStructContainer::<Point>::new();
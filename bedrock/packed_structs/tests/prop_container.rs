@@ -0,0 +1,31 @@
+//! Property-based tests for `PackedStructContainer` bulk operations.
+
+use packed_structs::PackedStructContainer;
+use proptest::prelude::*;
+
+proptest! {
+    #[test]
+    fn prop_retain_matches_vec(values: Vec<u32>, modulus in 1u32..8) {
+        let keep = |v: &u32| v.is_multiple_of(modulus);
+
+        let mut container = PackedStructContainer::from_slice(&values);
+        container.retain(keep).unwrap();
+
+        let mut expected = values.clone();
+        expected.retain(keep);
+        prop_assert_eq!(container.as_slice(), expected.as_slice());
+    }
+
+    #[test]
+    fn prop_dedup_by_matches_vec(values in prop::collection::vec(0u32..4, 0..64)) {
+        let mut container = PackedStructContainer::from_slice(&values);
+        container.dedup_by(|a, b| a == b).unwrap();
+
+        let mut expected = values.clone();
+        expected.dedup_by(|a, b| a == b);
+        prop_assert_eq!(container.as_slice(), expected.as_slice());
+
+        // No two neighbours are equal after deduplication
+        prop_assert!(container.windows(2).all(|w| w[0] != w[1]));
+    }
+}
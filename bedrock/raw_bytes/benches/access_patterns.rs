@@ -203,6 +203,42 @@ fn bench_push_operations(c: &mut Criterion) {
     group.finish();
 }
 
+fn bench_sort(c: &mut Criterion) {
+    use criterion::BatchSize;
+    use rand::prelude::*;
+
+    let sizes = vec![100, 1_000, 10_000];
+
+    let mut group = c.benchmark_group("sort");
+    for size in sizes {
+        let mut rng = StdRng::seed_from_u64(42);
+        let data: Vec<u32> = (0..size).map(|_| rng.random()).collect();
+
+        group.bench_with_input(BenchmarkId::new("container", size), &size, |b, _| {
+            b.iter_batched(
+                || Container::from_slice(&data),
+                |mut container| {
+                    container.sort_unstable_by(|a, b| a.cmp(b)).unwrap();
+                    container
+                },
+                BatchSize::SmallInput,
+            );
+        });
+
+        group.bench_with_input(BenchmarkId::new("raw_vec", size), &size, |b, _| {
+            b.iter_batched(
+                || data.clone(),
+                |mut vec| {
+                    vec.sort_unstable();
+                    vec
+                },
+                BatchSize::SmallInput,
+            );
+        });
+    }
+    group.finish();
+}
+
 #[cfg(feature = "mmap")]
 fn bench_mmap_operations(c: &mut Criterion) {
     use std::io::Write;
@@ -360,7 +396,12 @@ criterion_group!(
     bench_index_syntax
 );
 
-criterion_group!(write_benches, bench_write_operations, bench_push_operations);
+criterion_group!(
+    write_benches,
+    bench_write_operations,
+    bench_push_operations,
+    bench_sort
+);
 
 #[cfg(feature = "mmap")]
 criterion_group!(mmap_benches, bench_mmap_operations);
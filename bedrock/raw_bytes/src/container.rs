@@ -1,5 +1,17 @@
 use crate::{ContainerError, Storage};
 use bytemuck::Pod;
+use core::ops::{Bound, RangeBounds};
+
+#[cfg(not(feature = "std"))]
+use alloc::vec::Drain as VecDrain;
+#[cfg(not(feature = "std"))]
+use alloc::vec::IntoIter as VecIntoIter;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+#[cfg(feature = "std")]
+use std::vec::Drain as VecDrain;
+#[cfg(feature = "std")]
+use std::vec::IntoIter as VecIntoIter;
 
 /// High-level container for typed elements backed by different storage mechanisms.
 ///
@@ -234,6 +246,37 @@ impl<T: Pod> Container<T> {
         Ok(Container { storage })
     }
 
+    /// Memory-maps `len` elements starting at byte `offset` of a file, read-only.
+    ///
+    /// Useful for files that begin with a header. The element data must be
+    /// aligned for `T`: the mapping starts on a page boundary, so `offset`
+    /// must be a multiple of `align_of::<T>()`.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error if:
+    /// - The file doesn't exist or can't be opened
+    /// - The range extends past the end of the file
+    /// - `offset` is not aligned for `T`
+    /// - Memory mapping fails
+    #[cfg(feature = "mmap")]
+    pub fn mmap_readonly_at<P: AsRef<std::path::Path>>(
+        path: P,
+        offset: u64,
+        len: usize,
+    ) -> Result<Self, ContainerError> {
+        let bytes = len
+            .checked_mul(core::mem::size_of::<T>())
+            .ok_or(ContainerError::OutOfBounds(len))?;
+        let storage = Storage::from_mmap_readonly_at(path.as_ref(), offset, bytes)?;
+
+        if let Storage::MmapReadOnly(ref m) = storage {
+            validate_mmap_layout::<T>(m.as_ref())?;
+        }
+
+        Ok(Container { storage })
+    }
+
     /// Opens a memory-mapped file for read-write access.
     ///
     /// Changes made to the container are persisted directly to the file.
@@ -386,7 +429,7 @@ impl<T: Pod> Container<T> {
     /// # Errors
     ///
     /// - `ContainerError::OutOfBounds` if `index >= len()`
-    /// - `ContainerError::Io` if storage is read-only
+    /// - `ContainerError::NotSupported` if storage is read-only
     ///
     /// # Examples
     ///
@@ -415,7 +458,7 @@ impl<T: Pod> Container<T> {
     /// # Errors
     ///
     /// - `ContainerError::OutOfBounds` if `index >= len()`
-    /// - `ContainerError::Io` if storage is read-only
+    /// - `ContainerError::NotSupported` if storage is read-only
     ///
     /// # Examples
     ///
@@ -444,7 +487,7 @@ impl<T: Pod> Container<T> {
     ///
     /// # Errors
     ///
-    /// Returns `ContainerError::Io` if the container is backed by a memory-mapped file.
+    /// Returns `ContainerError::NotSupported` if the container is backed by a memory-mapped file.
     ///
     /// # Examples
     ///
@@ -467,6 +510,242 @@ impl<T: Pod> Container<T> {
         self.storage.push(value)
     }
 
+    /// Inserts an element at `index`, shifting all elements after it to the right.
+    ///
+    /// This is an O(n) operation. Only available for in-memory containers.
+    ///
+    /// # Errors
+    ///
+    /// - `ContainerError::OutOfBounds` if `index > len()`
+    /// - `ContainerError::NotSupported` if the container is backed by a memory-mapped file
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raw_bytes::Container;
+    ///
+    /// let mut c = Container::from_slice(&[1u32, 3]);
+    /// c.insert(1, 2).unwrap();
+    /// assert_eq!(c.as_slice(), &[1, 2, 3]);
+    /// ```
+    pub fn insert(&mut self, index: usize, value: T) -> Result<(), ContainerError> {
+        match &mut self.storage {
+            Storage::InMemory(vec) => {
+                if index > vec.len() {
+                    return Err(ContainerError::OutOfBounds(index));
+                }
+                vec.insert(index, value);
+                Ok(())
+            }
+            #[cfg(feature = "mmap")]
+            _ => Err(ContainerError::NotSupported("Cannot insert into mmap storage")),
+        }
+    }
+
+    /// Removes and returns the element at `index`, shifting all elements after it to the left.
+    ///
+    /// This is an O(n) operation. Only available for in-memory containers.
+    ///
+    /// # Errors
+    ///
+    /// - `ContainerError::OutOfBounds` if `index >= len()`
+    /// - `ContainerError::NotSupported` if the container is backed by a memory-mapped file
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raw_bytes::Container;
+    ///
+    /// let mut c = Container::from_slice(&[1u32, 2, 3]);
+    /// assert_eq!(c.remove(0).unwrap(), 1);
+    /// assert_eq!(c.as_slice(), &[2, 3]);
+    /// ```
+    pub fn remove(&mut self, index: usize) -> Result<T, ContainerError> {
+        match &mut self.storage {
+            Storage::InMemory(vec) => {
+                if index >= vec.len() {
+                    return Err(ContainerError::OutOfBounds(index));
+                }
+                Ok(vec.remove(index))
+            }
+            #[cfg(feature = "mmap")]
+            _ => Err(ContainerError::NotSupported("Cannot remove from mmap storage")),
+        }
+    }
+
+    /// Retains only the elements for which `f` returns `true`, removing the rest in place.
+    ///
+    /// Semantics are identical to [`Vec::retain`]: elements are visited exactly once, in
+    /// order, and the relative order of retained elements is preserved. Only available
+    /// for in-memory containers.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ContainerError::NotSupported` if the container is backed by a memory-mapped file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raw_bytes::Container;
+    ///
+    /// let mut c = Container::from_slice(&[1u32, 2, 3, 4, 5, 6]);
+    /// c.retain(|v| v % 2 == 0).unwrap();
+    /// assert_eq!(c.as_slice(), &[2, 4, 6]);
+    /// ```
+    pub fn retain<F: FnMut(&T) -> bool>(&mut self, f: F) -> Result<(), ContainerError> {
+        match &mut self.storage {
+            Storage::InMemory(vec) => {
+                vec.retain(f);
+                Ok(())
+            }
+            #[cfg(feature = "mmap")]
+            _ => Err(ContainerError::NotSupported("Cannot retain on mmap storage")),
+        }
+    }
+
+    /// Splits the elements into two new in-memory containers: those for which `f` returns
+    /// `true`, and the rest. Relative order is preserved in both.
+    ///
+    /// Works on any storage backend; `self` is left untouched.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raw_bytes::Container;
+    ///
+    /// let c = Container::from_slice(&[1u32, 2, 3, 4, 5]);
+    /// let (even, odd) = c.partition(|v| v % 2 == 0);
+    /// assert_eq!(even.as_slice(), &[2, 4]);
+    /// assert_eq!(odd.as_slice(), &[1, 3, 5]);
+    /// ```
+    pub fn partition<F: Fn(&T) -> bool>(&self, f: F) -> (Container<T>, Container<T>) {
+        let (matching, rest): (Vec<T>, Vec<T>) = self.iter().copied().partition(|v| f(v));
+        (
+            Container {
+                storage: Storage::InMemory(matching),
+            },
+            Container {
+                storage: Storage::InMemory(rest),
+            },
+        )
+    }
+
+    /// Removes consecutive elements for which `same_bucket` returns `true`, keeping the first.
+    ///
+    /// Semantics are identical to [`Vec::dedup_by`]: `same_bucket(a, b)` is passed the
+    /// candidate `a` and the most recently kept element `b`. Only available for in-memory
+    /// containers.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ContainerError::NotSupported` if the container is backed by a memory-mapped file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raw_bytes::Container;
+    ///
+    /// let mut c = Container::from_slice(&[1u32, 1, 2, 3, 3, 3, 1]);
+    /// c.dedup_by(|a, b| a == b).unwrap();
+    /// assert_eq!(c.as_slice(), &[1, 2, 3, 1]);
+    /// ```
+    pub fn dedup_by<F>(&mut self, same_bucket: F) -> Result<(), ContainerError>
+    where
+        F: FnMut(&mut T, &mut T) -> bool,
+    {
+        match &mut self.storage {
+            Storage::InMemory(vec) => {
+                vec.dedup_by(same_bucket);
+                Ok(())
+            }
+            #[cfg(feature = "mmap")]
+            _ => Err(ContainerError::NotSupported("Cannot dedup mmap storage")),
+        }
+    }
+
+    /// Shortens the container to `new_len` elements, dropping the rest.
+    ///
+    /// Has no effect if `new_len >= len()`, like [`Vec::truncate`]. Only available for
+    /// in-memory containers: shrinking a memory-mapped file would require resizing it,
+    /// which is platform-specific, so both read-only and read-write mappings are rejected.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ContainerError::NotSupported` if the container is backed by a memory-mapped file.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raw_bytes::Container;
+    ///
+    /// let mut c = Container::from_slice(&[1u32, 2, 3, 4]);
+    /// c.truncate(2).unwrap();
+    /// assert_eq!(c.as_slice(), &[1, 2]);
+    /// ```
+    pub fn truncate(&mut self, new_len: usize) -> Result<(), ContainerError> {
+        match &mut self.storage {
+            Storage::InMemory(vec) => {
+                vec.truncate(new_len);
+                Ok(())
+            }
+            #[cfg(feature = "mmap")]
+            _ => Err(ContainerError::NotSupported("Cannot truncate mmap storage")),
+        }
+    }
+
+    /// Removes the elements in `range` and returns them as an iterator.
+    ///
+    /// Semantics match [`Vec::drain`]: the range is removed even if the iterator is not
+    /// fully consumed, with the remaining elements dropped when it goes out of scope.
+    /// Removing a range this way is O(n), unlike repeated calls to
+    /// [`remove`](Self::remove). Only available for in-memory containers.
+    ///
+    /// # Errors
+    ///
+    /// - `ContainerError::OutOfBounds` if the range ends past `len()` or starts after it ends
+    /// - `ContainerError::NotSupported` if the container is backed by a memory-mapped file
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raw_bytes::Container;
+    ///
+    /// let mut c = Container::from_slice(&[1u32, 2, 3, 4, 5]);
+    /// let removed: Vec<u32> = c.drain(1..3).unwrap().collect();
+    /// assert_eq!(removed, vec![2, 3]);
+    /// assert_eq!(c.as_slice(), &[1, 4, 5]);
+    /// ```
+    pub fn drain<R: RangeBounds<usize>>(
+        &mut self,
+        range: R,
+    ) -> Result<Drain<'_, T>, ContainerError> {
+        match &mut self.storage {
+            Storage::InMemory(vec) => {
+                let start = match range.start_bound() {
+                    Bound::Included(&i) => i,
+                    Bound::Excluded(&i) => i.saturating_add(1),
+                    Bound::Unbounded => 0,
+                };
+                let end = match range.end_bound() {
+                    Bound::Included(&i) => i.saturating_add(1),
+                    Bound::Excluded(&i) => i,
+                    Bound::Unbounded => vec.len(),
+                };
+                if end > vec.len() {
+                    return Err(ContainerError::OutOfBounds(end));
+                }
+                if start > end {
+                    return Err(ContainerError::OutOfBounds(start));
+                }
+                Ok(Drain {
+                    inner: vec.drain(start..end),
+                })
+            }
+            #[cfg(feature = "mmap")]
+            _ => Err(ContainerError::NotSupported("Cannot drain mmap storage")),
+        }
+    }
+
     /// Extend with elements from slice (InMemory only)
     pub fn extend_from_slice(&mut self, values: &[T]) -> Result<(), ContainerError> {
         match &mut self.storage {
@@ -475,15 +754,7 @@ impl<T: Pod> Container<T> {
                 Ok(())
             }
             #[cfg(feature = "mmap")]
-            _ => {
-                #[cfg(feature = "std")]
-                return Err(ContainerError::Io(std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    "Cannot extend mmap storage",
-                )));
-                #[cfg(not(feature = "std"))]
-                return Err(ContainerError::Io("Cannot extend mmap storage"));
-            }
+            _ => Err(ContainerError::NotSupported("Cannot extend mmap storage")),
         }
     }
 
@@ -495,15 +766,7 @@ impl<T: Pod> Container<T> {
                 Ok(())
             }
             #[cfg(feature = "mmap")]
-            _ => {
-                #[cfg(feature = "std")]
-                return Err(ContainerError::Io(std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    "Cannot reserve on mmap storage",
-                )));
-                #[cfg(not(feature = "std"))]
-                return Err(ContainerError::Io("Cannot reserve on mmap storage"));
-            }
+            _ => Err(ContainerError::NotSupported("Cannot reserve on mmap storage")),
         }
     }
 
@@ -515,15 +778,7 @@ impl<T: Pod> Container<T> {
                 Ok(())
             }
             #[cfg(feature = "mmap")]
-            _ => {
-                #[cfg(feature = "std")]
-                return Err(ContainerError::Io(std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    "Cannot clear mmap storage",
-                )));
-                #[cfg(not(feature = "std"))]
-                return Err(ContainerError::Io("Cannot clear mmap storage"));
-            }
+            _ => Err(ContainerError::NotSupported("Cannot clear mmap storage")),
         }
     }
 
@@ -567,22 +822,35 @@ impl<T: Pod> Container<T> {
         match &mut self.storage {
             Storage::InMemory(vec) => Ok(vec.as_mut_slice()),
             #[cfg(feature = "mmap")]
-            Storage::MmapReadOnly(_) => {
-                #[cfg(feature = "std")]
-                return Err(ContainerError::Io(std::io::Error::new(
-                    std::io::ErrorKind::PermissionDenied,
-                    "Cannot get mutable slice from read-only storage",
-                )));
-                #[cfg(not(feature = "std"))]
-                return Err(ContainerError::Io(
-                    "Cannot get mutable slice from read-only storage",
-                ));
-            }
+            Storage::MmapReadOnly(_) => Err(ContainerError::NotSupported(
+                "Cannot get mutable slice from read-only storage",
+            )),
             #[cfg(feature = "mmap")]
             Storage::MmapReadWrite(m) => Ok(bytemuck::cast_slice_mut(m.as_mut())),
         }
     }
 
+    /// Converts the container into a vector of its elements.
+    ///
+    /// In-memory containers hand over their vector without copying.
+    /// Memory-mapped containers copy their elements out of the mapping.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raw_bytes::Container;
+    ///
+    /// let c = Container::from_slice(&[1u16, 2, 3]);
+    /// assert_eq!(c.into_vec(), vec![1, 2, 3]);
+    /// ```
+    pub fn into_vec(self) -> Vec<T> {
+        match self.storage {
+            Storage::InMemory(vec) => vec,
+            #[cfg(feature = "mmap")]
+            _ => self.as_slice().to_vec(),
+        }
+    }
+
     /// Returns an iterator over elements.
     ///
     /// # Examples
@@ -612,63 +880,517 @@ impl<T: Pod> Container<T> {
     pub fn iter_mut(&mut self) -> Result<core::slice::IterMut<'_, T>, ContainerError> {
         Ok(self.as_mut_slice()?.iter_mut())
     }
-}
-
-// Implement Index for convenient access
-impl<T: Pod> core::ops::Index<usize> for Container<T> {
-    type Output = T;
 
-    fn index(&self, index: usize) -> &Self::Output {
-        self.get(index).expect("index out of bounds")
+    /// Iterate over overlapping windows of `size` elements, like [`slice::windows`].
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raw_bytes::Container;
+    ///
+    /// let c = Container::from_slice(&[1u32, 2, 3, 4]);
+    /// let sums: Vec<u32> = c.windows(2).map(|w| w[0] + w[1]).collect();
+    /// assert_eq!(sums, vec![3, 5, 7]);
+    /// ```
+    pub fn windows(&self, size: usize) -> core::slice::Windows<'_, T> {
+        self.as_slice().windows(size)
     }
-}
 
-impl<T: Pod> core::ops::IndexMut<usize> for Container<T> {
-    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
-        self.get_mut(index).expect("index out of bounds")
+    /// Iterate over chunks of `size` elements, like [`slice::chunks`].
+    /// The last chunk is shorter if `len()` is not a multiple of `size`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn chunks(&self, size: usize) -> core::slice::Chunks<'_, T> {
+        self.as_slice().chunks(size)
     }
-}
 
-// Default implementation
-impl<T: Pod> Default for Container<T> {
-    fn default() -> Self {
-        Self::new()
+    /// Iterate over chunks of exactly `size` elements, like
+    /// [`slice::chunks_exact`]. Leftover elements are available from the
+    /// iterator's `remainder`.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is zero.
+    pub fn chunks_exact(&self, size: usize) -> core::slice::ChunksExact<'_, T> {
+        self.as_slice().chunks_exact(size)
     }
-}
-
-#[cfg(feature = "mmap")]
-fn validate_mmap_layout<T: Pod>(bytes: &[u8]) -> Result<(), ContainerError> {
-    bytemuck::try_cast_slice::<u8, T>(bytes)
-        .map(|_| ())
-        .map_err(|e| {
-            #[cfg(feature = "std")]
-            return ContainerError::Io(std::io::Error::new(
-                std::io::ErrorKind::InvalidData,
-                format!("Invalid mmap layout for type {}: {:?}", 
-                        core::any::type_name::<T>(), e)
-            ));
-            #[cfg(not(feature = "std"))]
-            return ContainerError::Io("Invalid mmap layout");
-        })
-}
 
-impl<T: Pod> Container<T> {
-    // ... rest of your impl
-}
+    /// Sorts the elements with a comparator function (stable).
+    ///
+    /// Delegates to [`slice::sort_by`], so it works for in-memory containers and
+    /// read-write memory-mapped files alike.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ContainerError::NotSupported` if storage is read-only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raw_bytes::Container;
+    ///
+    /// let mut c = Container::from_slice(&[3u32, 1, 2]);
+    /// c.sort_by(|a, b| b.cmp(a)).unwrap();
+    /// assert_eq!(c.as_slice(), &[3, 2, 1]);
+    /// ```
+    pub fn sort_by<F>(&mut self, cmp: F) -> Result<(), ContainerError>
+    where
+        F: FnMut(&T, &T) -> core::cmp::Ordering,
+    {
+        self.as_mut_slice()?.sort_by(cmp);
+        Ok(())
+    }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use bytemuck_derive::{Pod, Zeroable};
+    /// Sorts the elements with a key extraction function (stable).
+    ///
+    /// # Errors
+    ///
+    /// Returns `ContainerError::NotSupported` if storage is read-only.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raw_bytes::Container;
+    /// use bytemuck_derive::{Pod, Zeroable};
+    ///
+    /// #[repr(C)]
+    /// #[derive(Clone, Copy, Pod, Zeroable)]
+    /// struct Score { player_id: u32, points: u32 }
+    ///
+    /// let mut c = Container::from_slice(&[
+    ///     Score { player_id: 1, points: 300 },
+    ///     Score { player_id: 2, points: 100 },
+    /// ]);
+    /// c.sort_by_key(|s| s.points).unwrap();
+    /// assert_eq!(c[0].player_id, 2);
+    /// ```
+    pub fn sort_by_key<K, F>(&mut self, f: F) -> Result<(), ContainerError>
+    where
+        F: FnMut(&T) -> K,
+        K: Ord,
+    {
+        self.as_mut_slice()?.sort_by_key(f);
+        Ok(())
+    }
 
-    #[repr(C)]
-    #[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
-    struct Packet {
-        id: u32,
-        value: f32,
+    /// Sorts the elements with a comparator function, without preserving the
+    /// order of equal elements.
+    ///
+    /// Usually faster than [`sort_by`](Self::sort_by) and does not allocate.
+    ///
+    /// # Errors
+    ///
+    /// Returns `ContainerError::NotSupported` if storage is read-only.
+    pub fn sort_unstable_by<F>(&mut self, cmp: F) -> Result<(), ContainerError>
+    where
+        F: FnMut(&T, &T) -> core::cmp::Ordering,
+    {
+        self.as_mut_slice()?.sort_unstable_by(cmp);
+        Ok(())
     }
 
-    #[test]
+    /// Rotates the elements left by `k` places, so the element at index `k` becomes
+    /// the first.
+    ///
+    /// Works on any writable storage, including read-write memory maps.
+    ///
+    /// # Errors
+    ///
+    /// - `ContainerError::OutOfBounds` if `k > len()`
+    /// - `ContainerError::NotSupported` if storage is read-only
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raw_bytes::Container;
+    ///
+    /// let mut c = Container::from_slice(&[1u32, 2, 3, 4, 5]);
+    /// c.rotate_left(2).unwrap();
+    /// assert_eq!(c.as_slice(), &[3, 4, 5, 1, 2]);
+    /// ```
+    pub fn rotate_left(&mut self, k: usize) -> Result<(), ContainerError> {
+        if k > self.len() {
+            return Err(ContainerError::OutOfBounds(k));
+        }
+        self.as_mut_slice()?.rotate_left(k);
+        Ok(())
+    }
+
+    /// Rotates the elements right by `k` places, so the last `k` elements move to
+    /// the front.
+    ///
+    /// # Errors
+    ///
+    /// - `ContainerError::OutOfBounds` if `k > len()`
+    /// - `ContainerError::NotSupported` if storage is read-only
+    pub fn rotate_right(&mut self, k: usize) -> Result<(), ContainerError> {
+        if k > self.len() {
+            return Err(ContainerError::OutOfBounds(k));
+        }
+        self.as_mut_slice()?.rotate_right(k);
+        Ok(())
+    }
+
+    /// Overwrites every element with the corresponding element of `src`.
+    ///
+    /// Like [`slice::copy_from_slice`], but reports a length mismatch instead of
+    /// panicking. Works on any writable storage, including read-write memory maps.
+    ///
+    /// # Errors
+    ///
+    /// - `ContainerError::LengthMismatch` if `src.len() != len()`
+    /// - `ContainerError::NotSupported` if storage is read-only
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raw_bytes::Container;
+    ///
+    /// let mut c = Container::from_slice(&[0u32; 3]);
+    /// c.copy_from_slice(&[7, 8, 9]).unwrap();
+    /// assert_eq!(c.as_slice(), &[7, 8, 9]);
+    /// assert!(c.copy_from_slice(&[1]).is_err());
+    /// ```
+    pub fn copy_from_slice(&mut self, src: &[T]) -> Result<(), ContainerError> {
+        if src.len() != self.len() {
+            return Err(ContainerError::LengthMismatch {
+                expected: self.len(),
+                found: src.len(),
+            });
+        }
+        self.as_mut_slice()?.copy_from_slice(src);
+        Ok(())
+    }
+
+    /// Binary searches a sorted container with a comparator function.
+    ///
+    /// Mirrors [`slice::binary_search_by`]: returns `Ok(index)` of a matching
+    /// element, or `Err(index)` where a matching element could be inserted
+    /// while keeping the container sorted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raw_bytes::Container;
+    ///
+    /// let c = Container::from_slice(&[1u32, 3, 5, 7]);
+    /// assert_eq!(c.binary_search_by(|v| v.cmp(&5)), Ok(2));
+    /// assert_eq!(c.binary_search_by(|v| v.cmp(&4)), Err(2));
+    /// ```
+    pub fn binary_search_by<F>(&self, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> core::cmp::Ordering,
+    {
+        self.as_slice().binary_search_by(f)
+    }
+
+    /// Binary searches a container sorted by a key extraction function.
+    ///
+    /// Mirrors [`slice::binary_search_by_key`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use raw_bytes::Container;
+    /// use bytemuck_derive::{Pod, Zeroable};
+    ///
+    /// #[repr(C)]
+    /// #[derive(Clone, Copy, Pod, Zeroable)]
+    /// struct Event { timestamp: u64 }
+    ///
+    /// let c = Container::from_slice(&[
+    ///     Event { timestamp: 100 },
+    ///     Event { timestamp: 200 },
+    /// ]);
+    /// assert_eq!(c.binary_search_by_key(&200, |e| e.timestamp), Ok(1));
+    /// ```
+    pub fn binary_search_by_key<B, F>(&self, key: &B, f: F) -> Result<usize, usize>
+    where
+        F: FnMut(&T) -> B,
+        B: Ord,
+    {
+        self.as_slice().binary_search_by_key(key, f)
+    }
+}
+
+// Implement Index for convenient access
+impl<T: Pod> core::ops::Index<usize> for Container<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index).expect("index out of bounds")
+    }
+}
+
+impl<T: Pod> core::ops::IndexMut<usize> for Container<T> {
+    fn index_mut(&mut self, index: usize) -> &mut Self::Output {
+        self.get_mut(index).expect("index out of bounds")
+    }
+}
+
+// Range indexing slices through `as_slice`/`as_mut_slice`, so `IndexMut`
+// panics on read-only storage
+macro_rules! impl_range_index {
+    ($($range:ty),*) => {$(
+        impl<T: Pod> core::ops::Index<$range> for Container<T> {
+            type Output = [T];
+
+            fn index(&self, range: $range) -> &Self::Output {
+                &self.as_slice()[range]
+            }
+        }
+
+        impl<T: Pod> core::ops::IndexMut<$range> for Container<T> {
+            fn index_mut(&mut self, range: $range) -> &mut Self::Output {
+                &mut self.as_mut_slice().expect("storage is read-only")[range]
+            }
+        }
+    )*};
+}
+
+impl_range_index!(
+    core::ops::RangeFull,
+    core::ops::Range<usize>,
+    core::ops::RangeFrom<usize>,
+    core::ops::RangeTo<usize>,
+    core::ops::RangeInclusive<usize>
+);
+
+// Default implementation
+impl<T: Pod> Default for Container<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Containers are equal when their elements are, regardless of storage backend.
+///
+/// # Examples
+///
+/// ```
+/// use raw_bytes::Container;
+///
+/// let a = Container::from_slice(&[1u32, 2, 3]);
+/// let b: Container<u32> = (1..=3u32).collect();
+/// assert_eq!(a, b);
+/// ```
+impl<T: Pod + PartialEq> PartialEq for Container<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_slice() == other.as_slice()
+    }
+}
+
+impl<T: Pod + Eq> Eq for Container<T> {}
+
+/// Hashes the element count followed by each element, consistent with `Eq`.
+///
+/// Element types that hash floating-point fields by their bits (Rust's float
+/// types do not implement `Hash` themselves) inherit the usual caveats: `NaN`
+/// payloads and `0.0`/`-0.0` may hash differently from how they compare.
+///
+/// # Examples
+///
+/// ```
+/// use raw_bytes::Container;
+/// use std::collections::HashSet;
+///
+/// let mut seen = HashSet::new();
+/// assert!(seen.insert(Container::from_slice(&[1u8, 2])));
+/// assert!(!seen.insert([1u8, 2].into_iter().collect()));
+/// ```
+impl<T: Pod + core::hash::Hash> core::hash::Hash for Container<T> {
+    fn hash<H: core::hash::Hasher>(&self, state: &mut H) {
+        self.as_slice().hash(state);
+    }
+}
+
+/// Collects elements into a new in-memory container.
+///
+/// Memory-mapped containers cannot be created this way; use
+/// [`Container::mmap_readonly`] or [`Container::mmap_readwrite`] instead.
+///
+/// # Examples
+///
+/// ```
+/// use raw_bytes::Container;
+///
+/// let c: Container<u32> = (0..10u32).collect();
+/// assert_eq!(c.len(), 10);
+/// ```
+impl<T: Pod> FromIterator<T> for Container<T> {
+    fn from_iter<I: IntoIterator<Item = T>>(iter: I) -> Self {
+        Container {
+            storage: Storage::InMemory(iter.into_iter().collect()),
+        }
+    }
+}
+
+/// Appends elements from an iterator (InMemory only).
+///
+/// # Panics
+///
+/// Panics if the container is backed by a memory-mapped file.
+/// Use [`Container::extend_from_slice`] for non-panicking access.
+impl<T: Pod> Extend<T> for Container<T> {
+    fn extend<I: IntoIterator<Item = T>>(&mut self, iter: I) {
+        match &mut self.storage {
+            Storage::InMemory(vec) => vec.extend(iter),
+            #[cfg(feature = "mmap")]
+            _ => panic!("Cannot extend mmap storage"),
+        }
+    }
+}
+
+/// Iterate over references to elements.
+impl<'a, T: Pod> IntoIterator for &'a Container<T> {
+    type Item = &'a T;
+    type IntoIter = core::slice::Iter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Consume the container, yielding owned elements.
+///
+/// In-memory containers hand over their vector without copying. Memory-mapped
+/// containers copy each element out of the mapping as it is yielded.
+///
+/// # Examples
+///
+/// ```
+/// use raw_bytes::Container;
+///
+/// let c = Container::from_slice(&[1u32, 2, 3]);
+/// let v: Vec<u32> = c.into_iter().collect();
+/// assert_eq!(v, vec![1, 2, 3]);
+/// ```
+impl<T: Pod> IntoIterator for Container<T> {
+    type Item = T;
+    type IntoIter = IntoIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let inner = match self.storage {
+            Storage::InMemory(vec) => IntoIterInner::InMemory(vec.into_iter()),
+            #[cfg(feature = "mmap")]
+            storage => IntoIterInner::Mapped {
+                storage,
+                index: 0,
+            },
+        };
+        IntoIter { inner }
+    }
+}
+
+/// Owning iterator over the elements of a [`Container`].
+///
+/// Created by [`Container::into_iter`].
+#[derive(Debug)]
+pub struct IntoIter<T: Pod> {
+    inner: IntoIterInner<T>,
+}
+
+#[derive(Debug)]
+enum IntoIterInner<T: Pod> {
+    InMemory(VecIntoIter<T>),
+    #[cfg(feature = "mmap")]
+    Mapped { storage: Storage<T>, index: usize },
+}
+
+impl<T: Pod> Iterator for IntoIter<T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        match &mut self.inner {
+            IntoIterInner::InMemory(iter) => iter.next(),
+            #[cfg(feature = "mmap")]
+            IntoIterInner::Mapped { storage, index } => {
+                let value = *storage.get(*index).ok()?;
+                *index += 1;
+                Some(value)
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = match &self.inner {
+            IntoIterInner::InMemory(iter) => iter.len(),
+            #[cfg(feature = "mmap")]
+            IntoIterInner::Mapped { storage, index } => storage.len() - index,
+        };
+        (remaining, Some(remaining))
+    }
+}
+
+impl<T: Pod> ExactSizeIterator for IntoIter<T> {}
+
+/// Draining iterator over a range of a [`Container`].
+///
+/// Created by [`Container::drain`].
+#[derive(Debug)]
+pub struct Drain<'a, T: Pod> {
+    inner: VecDrain<'a, T>,
+}
+
+impl<T: Pod> Iterator for Drain<'_, T> {
+    type Item = T;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.inner.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.inner.size_hint()
+    }
+}
+
+impl<T: Pod> DoubleEndedIterator for Drain<'_, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.inner.next_back()
+    }
+}
+
+impl<T: Pod> ExactSizeIterator for Drain<'_, T> {}
+
+#[cfg(feature = "mmap")]
+fn validate_mmap_layout<T: Pod>(bytes: &[u8]) -> Result<(), ContainerError> {
+    bytemuck::try_cast_slice::<u8, T>(bytes)
+        .map(|_| ())
+        .map_err(|e| {
+            #[cfg(feature = "std")]
+            return ContainerError::Io(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("Invalid mmap layout for type {}: {:?}", 
+                        core::any::type_name::<T>(), e)
+            ));
+            #[cfg(not(feature = "std"))]
+            return ContainerError::Io("Invalid mmap layout");
+        })
+}
+
+impl<T: Pod> Container<T> {
+    // ... rest of your impl
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use bytemuck_derive::{Pod, Zeroable};
+
+    #[repr(C)]
+    #[derive(Clone, Copy, Debug, PartialEq, Pod, Zeroable)]
+    struct Packet {
+        id: u32,
+        value: f32,
+    }
+
+    #[test]
     fn in_memory_basic_operations() -> Result<(), ContainerError> {
         let mut c = Container::<Packet>::new();
         assert!(c.is_empty());
@@ -708,6 +1430,51 @@ mod tests {
         assert_eq!(c[1].value, 42.0);
     }
 
+    #[test]
+    fn windows_and_chunks() {
+        let values: Vec<u32> = (0..10).collect();
+        let c = Container::<u32>::from_slice(&values);
+
+        assert_eq!(c.windows(3).count(), c.len() - 2);
+        for (i, w) in c.windows(3).enumerate() {
+            assert_eq!(w, &[i as u32, i as u32 + 1, i as u32 + 2]);
+        }
+        assert_eq!(c.windows(11).count(), 0);
+
+        let chunks: Vec<&[u32]> = c.chunks(4).collect();
+        assert_eq!(chunks, vec![&values[0..4], &values[4..8], &values[8..]]);
+
+        let mut exact = c.chunks_exact(4);
+        assert_eq!(exact.len(), 2);
+        assert_eq!(exact.next(), Some(&values[0..4]));
+        assert_eq!(exact.remainder(), &[8, 9]);
+    }
+
+    #[test]
+    fn range_index_operations() {
+        let mut c = Container::<u32>::from_slice(&[0, 1, 2, 3, 4, 5]);
+
+        assert_eq!(&c[..], &[0, 1, 2, 3, 4, 5]);
+        assert_eq!(&c[1..4], &[1, 2, 3]);
+        assert_eq!(&c[4..], &[4, 5]);
+        assert_eq!(&c[..2], &[0, 1]);
+        assert_eq!(&c[2..=3], &[2, 3]);
+
+        c[..].fill(7);
+        c[1..3].copy_from_slice(&[10, 11]);
+        c[4..][0] = 12;
+        c[..1][0] = 13;
+        c[5..=5][0] = 14;
+        assert_eq!(c.as_slice(), &[13, 10, 11, 7, 12, 14]);
+    }
+
+    #[test]
+    #[should_panic]
+    fn range_index_out_of_bounds() {
+        let c = Container::<u32>::from_slice(&[0, 1, 2]);
+        let _ = &c[1..4];
+    }
+
     #[test]
     fn slice_operations() {
         let data = vec![
@@ -747,6 +1514,36 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn from_iterator_and_extend() {
+        let mut c = (0..100u32).collect::<Container<u32>>();
+        assert_eq!(c.len(), 100);
+        assert_eq!(c[0], 0);
+        assert_eq!(c[99], 99);
+
+        c.extend(100..110u32);
+        assert_eq!(c.len(), 110);
+        assert!(c.iter().copied().eq(0..110u32));
+    }
+
+    #[test]
+    fn owned_into_iterator() {
+        let data = vec![
+            Packet { id: 1, value: 10.0 },
+            Packet { id: 2, value: 20.0 },
+            Packet { id: 3, value: 30.0 },
+        ];
+        let c = Container::<Packet>::from_slice(&data);
+
+        let by_ref: Vec<Packet> = (&c).into_iter().copied().collect();
+        assert_eq!(by_ref, data);
+
+        let iter = c.into_iter();
+        assert_eq!(iter.len(), 3);
+        let owned: Vec<Packet> = iter.collect();
+        assert_eq!(owned, data);
+    }
+
     #[test]
     fn clear_operation() -> Result<(), ContainerError> {
         let mut c = Container::<Packet>::from_slice(&[
@@ -762,6 +1559,311 @@ mod tests {
         Ok(())
     }
 
+    #[test]
+    fn insert_head_middle_tail() -> Result<(), ContainerError> {
+        let mut c = Container::<u32>::from_slice(&[2, 4]);
+
+        c.insert(0, 1)?;
+        assert_eq!(c.as_slice(), &[1, 2, 4]);
+
+        c.insert(2, 3)?;
+        assert_eq!(c.as_slice(), &[1, 2, 3, 4]);
+
+        c.insert(4, 5)?;
+        assert_eq!(c.as_slice(), &[1, 2, 3, 4, 5]);
+
+        assert!(matches!(c.insert(6, 6), Err(ContainerError::OutOfBounds(6))));
+        assert_eq!(c.len(), 5);
+
+        Ok(())
+    }
+
+    #[test]
+    fn remove_head_middle_tail() -> Result<(), ContainerError> {
+        let mut c = Container::<u32>::from_slice(&[1, 2, 3, 4, 5]);
+
+        assert_eq!(c.remove(0)?, 1);
+        assert_eq!(c.as_slice(), &[2, 3, 4, 5]);
+
+        assert_eq!(c.remove(1)?, 3);
+        assert_eq!(c.as_slice(), &[2, 4, 5]);
+
+        assert_eq!(c.remove(2)?, 5);
+        assert_eq!(c.as_slice(), &[2, 4]);
+
+        assert!(matches!(c.remove(2), Err(ContainerError::OutOfBounds(2))));
+        assert_eq!(c.len(), 2);
+
+        Ok(())
+    }
+
+    #[test]
+    fn retain_operation() -> Result<(), ContainerError> {
+        let mut c = Container::<Packet>::from_slice(&[
+            Packet { id: 1, value: 10.0 },
+            Packet { id: 2, value: 20.0 },
+            Packet { id: 3, value: 30.0 },
+            Packet { id: 4, value: 40.0 },
+        ]);
+
+        c.retain(|p| p.id % 2 == 1)?;
+        assert_eq!(c.len(), 2);
+        assert_eq!(c[0].id, 1);
+        assert_eq!(c[1].id, 3);
+
+        c.retain(|_| false)?;
+        assert!(c.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn partition_operation() {
+        let c = Container::<u32>::from_slice(&[7, 2, 9, 4, 4, 1, 8]);
+
+        let (even, odd) = c.partition(|v| v % 2 == 0);
+        assert_eq!(even.as_slice(), &[2, 4, 4, 8]);
+        assert_eq!(odd.as_slice(), &[7, 9, 1]);
+        assert_eq!(c.len(), 7);
+
+        let (all, none) = c.partition(|_| true);
+        assert_eq!(all, c);
+        assert!(none.is_empty());
+    }
+
+    #[test]
+    fn truncate_operation() -> Result<(), ContainerError> {
+        let mut c = Container::<Packet>::from_slice(&[
+            Packet { id: 1, value: 10.0 },
+            Packet { id: 2, value: 20.0 },
+            Packet { id: 3, value: 30.0 },
+        ]);
+
+        c.truncate(5)?;
+        assert_eq!(c.len(), 3);
+
+        c.truncate(1)?;
+        assert_eq!(c.len(), 1);
+        assert_eq!(c[0].id, 1);
+
+        c.truncate(0)?;
+        assert!(c.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn drain_operation() -> Result<(), ContainerError> {
+        let mut c = Container::<Packet>::from_slice(&[
+            Packet { id: 1, value: 10.0 },
+            Packet { id: 2, value: 20.0 },
+            Packet { id: 3, value: 30.0 },
+            Packet { id: 4, value: 40.0 },
+            Packet { id: 5, value: 50.0 },
+        ]);
+
+        let drained: Vec<u32> = c.drain(1..=3)?.map(|p| p.id).collect();
+        assert_eq!(drained, vec![2, 3, 4]);
+        assert_eq!(c.len(), 2);
+        assert_eq!(c[0].id, 1);
+        assert_eq!(c[1].id, 5);
+
+        // Unconsumed elements are still removed
+        let mut partial = c.drain(..)?;
+        assert_eq!(partial.next().map(|p| p.id), Some(1));
+        drop(partial);
+        assert!(c.is_empty());
+
+        Ok(())
+    }
+
+    #[test]
+    fn drain_invalid_range() {
+        let mut c = Container::<u32>::from_slice(&[1, 2, 3]);
+        assert!(matches!(c.drain(2..4), Err(ContainerError::OutOfBounds(4))));
+        #[allow(clippy::reversed_empty_ranges)]
+        let reversed = c.drain(3..1).map(|_| ());
+        assert!(matches!(reversed, Err(ContainerError::OutOfBounds(3))));
+        assert_eq!(c.len(), 3);
+    }
+
+    #[test]
+    fn rotate_operation() -> Result<(), ContainerError> {
+        let mut c = Container::<u32>::from_slice(&[1, 2, 3, 4, 5]);
+
+        c.rotate_left(2)?;
+        assert_eq!(c.as_slice(), &[3, 4, 5, 1, 2]);
+
+        c.rotate_right(2)?;
+        assert_eq!(c.as_slice(), &[1, 2, 3, 4, 5]);
+
+        c.rotate_left(5)?;
+        assert_eq!(c.as_slice(), &[1, 2, 3, 4, 5]);
+        assert!(matches!(c.rotate_right(6), Err(ContainerError::OutOfBounds(6))));
+
+        Ok(())
+    }
+
+    #[test]
+    fn copy_from_slice_operation() -> Result<(), ContainerError> {
+        let mut c = Container::<u32>::from_slice(&[1, 2, 3]);
+
+        c.copy_from_slice(&[4, 5, 6])?;
+        assert_eq!(c.as_slice(), &[4, 5, 6]);
+
+        assert!(matches!(
+            c.copy_from_slice(&[1, 2]),
+            Err(ContainerError::LengthMismatch {
+                expected: 3,
+                found: 2
+            })
+        ));
+        assert_eq!(c.as_slice(), &[4, 5, 6]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn dedup_by_operation() -> Result<(), ContainerError> {
+        let mut c = Container::<Packet>::from_slice(&[
+            Packet { id: 1, value: 10.0 },
+            Packet { id: 1, value: 11.0 },
+            Packet { id: 2, value: 20.0 },
+            Packet { id: 1, value: 12.0 },
+        ]);
+
+        c.dedup_by(|a, b| a.id == b.id)?;
+        assert_eq!(c.len(), 3);
+        assert_eq!(c[0].value, 10.0);
+        assert_eq!(c[1].id, 2);
+        assert_eq!(c[2].value, 12.0);
+
+        Ok(())
+    }
+
+    #[test]
+    fn sort_operations() -> Result<(), ContainerError> {
+        let mut c = Container::<u32>::from_slice(&[5, 3, 9, 1, 7]);
+
+        c.sort_by(|a, b| a.cmp(b))?;
+        assert_eq!(c.as_slice(), &[1, 3, 5, 7, 9]);
+
+        c.sort_unstable_by(|a, b| b.cmp(a))?;
+        assert_eq!(c.as_slice(), &[9, 7, 5, 3, 1]);
+
+        let mut packets = Container::<Packet>::from_slice(&[
+            Packet { id: 1, value: 30.0 },
+            Packet { id: 2, value: 10.0 },
+            Packet { id: 3, value: 20.0 },
+        ]);
+        packets.sort_by_key(|p| p.value as u32)?;
+        let ids: Vec<u32> = packets.iter().map(|p| p.id).collect();
+        assert_eq!(ids, vec![2, 3, 1]);
+
+        Ok(())
+    }
+
+    #[test]
+    fn binary_search_operations() -> Result<(), ContainerError> {
+        let mut c = Container::<u32>::new();
+        for v in [10, 20, 30, 40, 50] {
+            c.push(v)?;
+        }
+
+        // Present value
+        assert_eq!(c.binary_search_by(|v| v.cmp(&30)), Ok(2));
+
+        // Absent value: Err holds the insertion point
+        let idx = c.binary_search_by(|v| v.cmp(&35)).unwrap_err();
+        assert_eq!(idx, 3);
+        c.insert(idx, 35)?;
+        assert_eq!(c.as_slice(), &[10, 20, 30, 35, 40, 50]);
+
+        // Before head and past tail
+        assert_eq!(c.binary_search_by(|v| v.cmp(&5)), Err(0));
+        assert_eq!(c.binary_search_by(|v| v.cmp(&99)), Err(6));
+
+        let packets = Container::<Packet>::from_slice(&[
+            Packet { id: 1, value: 10.0 },
+            Packet { id: 4, value: 20.0 },
+            Packet { id: 9, value: 30.0 },
+        ]);
+        assert_eq!(packets.binary_search_by_key(&4, |p| p.id), Ok(1));
+        assert_eq!(packets.binary_search_by_key(&5, |p| p.id), Err(2));
+
+        Ok(())
+    }
+
+    #[test]
+    fn equality() {
+        let a = Container::from_slice(&[
+            Packet { id: 1, value: 1.0 },
+            Packet { id: 2, value: 2.0 },
+        ]);
+        let mut b = Container::new();
+        b.push(Packet { id: 1, value: 1.0 }).unwrap();
+        assert_ne!(a, b);
+
+        b.push(Packet { id: 2, value: 2.0 }).unwrap();
+        assert_eq!(a, b);
+
+        b[1].value = 2.5;
+        assert_ne!(a, b);
+
+        assert_eq!(Container::<u64>::new(), Container::<u64>::from_slice(&[]));
+    }
+
+    #[test]
+    fn hash_matches_equality() {
+        use std::collections::HashMap;
+        use std::collections::hash_map::DefaultHasher;
+        use std::hash::{Hash, Hasher};
+
+        fn hash_of<T: Hash>(value: &T) -> u64 {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            hasher.finish()
+        }
+
+        let a = Container::from_slice(&[10u16, 20, 30]);
+        let b: Container<u16> = [10u16, 20, 30].into_iter().collect();
+        assert_eq!(a, b);
+        assert_eq!(hash_of(&a), hash_of(&b));
+
+        // The length is part of the hash, so splitting elements differently differs
+        let nested = (Container::from_slice(&[1u8]), Container::from_slice(&[2u8, 3]));
+        let shifted = (Container::from_slice(&[1u8, 2]), Container::from_slice(&[3u8]));
+        assert_ne!(hash_of(&nested), hash_of(&shifted));
+
+        let mut names = HashMap::new();
+        names.insert(a, "tens");
+        assert_eq!(names.get(&b), Some(&"tens"));
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn mmap_equals_in_memory() -> Result<(), ContainerError> {
+        use std::io::Write;
+        use tempfile::NamedTempFile;
+
+        let values = [3u32, 1, 4, 1, 5];
+        let mut file = NamedTempFile::new()?;
+        file.write_all(bytemuck::cast_slice(&values))?;
+        file.flush()?;
+
+        let in_memory = Container::from_slice(&values);
+        let readonly = Container::<u32>::mmap_readonly(file.path())?;
+        let mut readwrite = Container::<u32>::mmap_readwrite(file.path())?;
+        assert_eq!(readonly, in_memory);
+        assert_eq!(readwrite, in_memory);
+        assert_eq!(readonly, readwrite);
+
+        readwrite.write(0, 9)?;
+        assert_ne!(readwrite, in_memory);
+
+        Ok(())
+    }
+
     #[cfg(feature = "mmap")]
     #[test]
     fn mmap_readonly_operations() -> Result<(), ContainerError> {
@@ -791,7 +1893,41 @@ mod tests {
         );
         assert!(c.get_mut(0).is_err());
         assert!(c.as_mut_slice().is_err());
+        assert!(c.sort_by(|a, b| a.id.cmp(&b.id)).is_err());
+
+        // Range indexing can still read
+        assert_eq!(&c[..], &packets);
+        assert_eq!(&c[1..=1], &packets[1..]);
+
+        // Consuming iteration copies elements out of the mapping
+        let owned: Vec<Packet> = c.into_iter().collect();
+        assert_eq!(owned, packets);
+
+        Ok(())
+    }
+
+    #[cfg(feature = "mmap")]
+    #[test]
+    fn mmap_readonly_at_offset() -> Result<(), ContainerError> {
+        use std::io::Write;
+        use tempfile::NamedTempFile;
+
+        let mut file = NamedTempFile::new()?;
+        let values: Vec<u32> = (0..100).collect();
+        file.write_all(b"HEAD")?;
+        file.write_all(bytemuck::cast_slice(&values))?;
+        file.write_all(b"TAIL")?;
+        file.flush()?;
 
+        let c = Container::<u32>::mmap_readonly_at(file.path(), 4, 100)?;
+        assert_eq!(c.as_slice(), &values[..]);
+        let c = Container::<u32>::mmap_readonly_at(file.path(), 4 + 40, 10)?;
+        assert_eq!(c.as_slice(), &values[10..20]);
+        assert!(Container::<u32>::mmap_readonly_at(file.path(), 4, 0)?.is_empty());
+
+        // Past the end of the file, and misaligned for u32
+        assert!(Container::<u32>::mmap_readonly_at(file.path(), 4, 102).is_err());
+        assert!(Container::<u32>::mmap_readonly_at(file.path(), 2, 10).is_err());
         Ok(())
     }
 
@@ -828,8 +1964,49 @@ mod tests {
         slice[1].value = 42.0;
         assert_eq!(c.get(1)?.value, 42.0);
 
+        // Range indexing writes through the mapping
+        c[1..][0].id = 7;
+        assert_eq!(c[..].len(), 2);
+        assert_eq!(c.get(1)?.id, 7);
+        c[1..=1][0].id = 2;
+
+        // Can rotate in place
+        c.rotate_left(1)?;
+        assert_eq!(c.get(0)?.value, 42.0);
+        c.rotate_right(1)?;
+        assert_eq!(c.get(0)?.id, 99);
+
+        // Can sort in place
+        c.sort_by_key(|p| p.id)?;
+        assert_eq!(c.get(0)?.id, 2);
+        assert_eq!(c.get(1)?.id, 99);
+
         // Cannot push/extend
-        assert!(c.push(Packet { id: 3, value: 3.0 }).is_err());
+        assert!(matches!(
+            c.push(Packet { id: 3, value: 3.0 }),
+            Err(ContainerError::NotSupported(_))
+        ));
+        assert!(matches!(
+            c.extend_from_slice(&[Packet { id: 3, value: 3.0 }]),
+            Err(ContainerError::NotSupported(_))
+        ));
+        assert!(matches!(c.reserve(1), Err(ContainerError::NotSupported(_))));
+        assert!(matches!(c.clear(), Err(ContainerError::NotSupported(_))));
+
+        // Cannot insert/remove
+        assert!(matches!(
+            c.insert(0, Packet { id: 4, value: 4.0 }),
+            Err(ContainerError::NotSupported(_))
+        ));
+        assert!(matches!(c.remove(0), Err(ContainerError::NotSupported(_))));
+        assert!(matches!(c.retain(|_| false), Err(ContainerError::NotSupported(_))));
+        assert!(matches!(
+            c.dedup_by(|_, _| true),
+            Err(ContainerError::NotSupported(_))
+        ));
+        assert!(matches!(c.truncate(0), Err(ContainerError::NotSupported(_))));
+        assert!(matches!(c.drain(..), Err(ContainerError::NotSupported(_))));
+        assert_eq!(c.len(), 2);
 
         Ok(())
     }
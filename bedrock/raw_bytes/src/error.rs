@@ -23,4 +23,20 @@ pub enum ContainerError {
 
     #[cfg(not(feature = "std"))]
     OutOfBounds(usize),
+
+    /// Operation not supported by the storage backend (e.g. resizing mmap)
+    #[cfg(feature = "std")]
+    #[error("Operation not supported: {0}")]
+    NotSupported(&'static str),
+
+    #[cfg(not(feature = "std"))]
+    NotSupported(&'static str),
+
+    /// Two operands that must have the same length do not
+    #[cfg(feature = "std")]
+    #[error("Length mismatch: expected {expected}, found {found}")]
+    LengthMismatch { expected: usize, found: usize },
+
+    #[cfg(not(feature = "std"))]
+    LengthMismatch { expected: usize, found: usize },
 }
@@ -9,7 +9,7 @@ use alloc::vec::Vec;
 use std::{fs::File, io::Error as IoError, path::Path};
 
 #[cfg(feature = "mmap")]
-use memmap2::{Mmap, MmapMut};
+use memmap2::{Mmap, MmapMut, MmapOptions};
 
 use crate::ContainerError;
 
@@ -63,18 +63,9 @@ impl<T: Pod> Storage<T> {
             }
 
             #[cfg(feature = "mmap")]
-            Storage::MmapReadOnly(_) | Storage::MmapReadWrite(_) => {
-                #[cfg(feature = "std")]
-                return Err(ContainerError::Io(std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    "Cannot push to mmap storage (fixed size)",
-                )));
-
-                #[cfg(not(feature = "std"))]
-                return Err(ContainerError::Io(
-                    "Cannot push to mmap storage (fixed size)",
-                ));
-            }
+            Storage::MmapReadOnly(_) | Storage::MmapReadWrite(_) => Err(
+                ContainerError::NotSupported("Cannot push to mmap storage (fixed size)"),
+            ),
         }
     }
 
@@ -114,18 +105,9 @@ impl<T: Pod> Storage<T> {
             Storage::InMemory(vec) => Ok(&mut vec[index]),
 
             #[cfg(feature = "mmap")]
-            Storage::MmapReadOnly(_) => {
-                #[cfg(feature = "std")]
-                return Err(ContainerError::Io(std::io::Error::new(
-                    std::io::ErrorKind::Other,
-                    "Cannot mut-access read-only mmap storage",
-                )));
-
-                #[cfg(not(feature = "std"))]
-                return Err(ContainerError::Io(
-                    "Cannot mut-access read-only mmap storage",
-                ));
-            }
+            Storage::MmapReadOnly(_) => Err(ContainerError::NotSupported(
+                "Cannot mut-access read-only mmap storage",
+            )),
 
             #[cfg(feature = "mmap")]
             Storage::MmapReadWrite(m) => {
@@ -150,6 +132,28 @@ impl<T: Pod> Storage<T> {
         Ok(Storage::MmapReadOnly(mmap))
     }
 
+    /// Map `len` bytes starting at byte `offset` of the file, read-only.
+    ///
+    /// The range must lie within the file, since touching a mapped page past
+    /// the end of the file faults.
+    #[cfg(feature = "mmap")]
+    pub fn from_mmap_readonly_at(
+        path: &Path,
+        offset: u64,
+        len: usize,
+    ) -> Result<Self, ContainerError> {
+        let file = File::open(path)?;
+        let file_len = file.metadata()?.len();
+        if offset.checked_add(len as u64).is_none_or(|end| end > file_len) {
+            return Err(ContainerError::Io(IoError::new(
+                std::io::ErrorKind::UnexpectedEof,
+                "mapped range extends past the end of the file",
+            )));
+        }
+        let mmap = unsafe { MmapOptions::new().offset(offset).len(len).map(&file)? };
+        Ok(Storage::MmapReadOnly(mmap))
+    }
+
     #[cfg(feature = "mmap")]
     pub fn from_mmap_readwrite(path: &Path) -> Result<Self, ContainerError> {
         let file = File::options().read(true).write(true).open(path)?;
@@ -70,6 +70,36 @@ proptest! {
     }
 }
 
+proptest! {
+    #[test]
+    fn prop_in_memory_retain_matches_filter(values: Vec<u32>, modulus in 1u32..8) {
+        let keep = |v: &u32| v.is_multiple_of(modulus);
+
+        let mut c = Container::<u32>::from_slice(&values);
+        c.retain(keep).unwrap();
+
+        let expected: Vec<u32> = values.iter().copied().filter(keep).collect();
+        prop_assert_eq!(c.as_slice(), expected.as_slice());
+    }
+}
+
+proptest! {
+    #[test]
+    fn prop_in_memory_truncate(values: Vec<u32>, new_len in 0usize..64) {
+        let mut c = Container::<u32>::from_slice(&values);
+        c.truncate(values.len()).unwrap();
+        prop_assert_eq!(c.as_slice(), values.as_slice());
+
+        c.truncate(new_len).unwrap();
+        prop_assert_eq!(c.as_slice(), &values[..new_len.min(values.len())]);
+
+        let mut cleared = Container::<u32>::from_slice(&values);
+        cleared.clear().unwrap();
+        c.truncate(0).unwrap();
+        prop_assert_eq!(c.as_slice(), cleared.as_slice());
+    }
+}
+
 //
 // -----------------------------------------------------------------------------
 // Mmap Read-Only Properties
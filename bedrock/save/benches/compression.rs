@@ -0,0 +1,88 @@
+// benches/compression.rs
+
+use bytemuck_derive::{Pod, Zeroable};
+use criterion::{BenchmarkId, Criterion, black_box, criterion_group, criterion_main};
+use save::save::{load, load_compressed, save, save_compressed};
+
+#[repr(C)]
+#[derive(Clone, Copy, Pod, Zeroable)]
+struct Tile {
+    kind: u16,
+    variant: u16,
+    height: u32,
+}
+
+/// A world map with long runs of identical tiles, typical of game saves.
+fn create_tiles(size: usize) -> Vec<Tile> {
+    (0..size)
+        .map(|i| Tile {
+            kind: (i / 64 % 8) as u16,
+            variant: 0,
+            height: (i / 256) as u32,
+        })
+        .collect()
+}
+
+fn bench_save(c: &mut Criterion) {
+    let sizes = vec![1_000, 10_000, 100_000];
+
+    let mut group = c.benchmark_group("save");
+    for size in sizes {
+        let tiles = create_tiles(size);
+
+        let mut plain = Vec::new();
+        save(&mut plain, &tiles, 0).unwrap();
+        let mut compressed = Vec::new();
+        save_compressed(&mut compressed, &tiles, 0).unwrap();
+        println!(
+            "{} tiles: {} bytes uncompressed, {} bytes compressed ({:.1}%)",
+            size,
+            plain.len(),
+            compressed.len(),
+            100.0 * compressed.len() as f64 / plain.len() as f64
+        );
+
+        group.bench_with_input(BenchmarkId::new("uncompressed", size), &size, |b, _| {
+            b.iter(|| {
+                let mut out = Vec::with_capacity(plain.len());
+                save(&mut out, black_box(&tiles), 0).unwrap();
+                out
+            });
+        });
+
+        group.bench_with_input(BenchmarkId::new("lz4", size), &size, |b, _| {
+            b.iter(|| {
+                let mut out = Vec::new();
+                save_compressed(&mut out, black_box(&tiles), 0).unwrap();
+                out
+            });
+        });
+    }
+    group.finish();
+}
+
+fn bench_load(c: &mut Criterion) {
+    let sizes = vec![1_000, 10_000, 100_000];
+
+    let mut group = c.benchmark_group("load");
+    for size in sizes {
+        let tiles = create_tiles(size);
+
+        let mut plain = Vec::new();
+        save(&mut plain, &tiles, 0).unwrap();
+        let mut compressed = Vec::new();
+        save_compressed(&mut compressed, &tiles, 0).unwrap();
+
+        group.bench_with_input(BenchmarkId::new("uncompressed", size), &size, |b, _| {
+            b.iter(|| load::<Tile, _>(&mut black_box(plain.as_slice())).unwrap());
+        });
+
+        group.bench_with_input(BenchmarkId::new("lz4", size), &size, |b, _| {
+            b.iter(|| load_compressed::<Tile, _>(&mut black_box(compressed.as_slice())).unwrap());
+        });
+    }
+    group.finish();
+}
+
+criterion_group!(benches, bench_save, bench_load);
+criterion_main!(benches);
@@ -3,7 +3,10 @@
 //! The format supports packed POD structs and computes a Merkle-compatible
 //! root hash over the payload for integrity verification.
 
+#[cfg(feature = "lz4")]
+pub mod lz4;
 pub mod merkle;
 pub mod save;
+pub mod sections;
 
 pub use save::{SaveError, SaveHeader};
@@ -0,0 +1,40 @@
+//! LZ4 block format codec.
+//!
+//! Produces and consumes raw LZ4 blocks (no frame header), using
+//! [`lz4_flex`]'s block implementation so files interoperate with any
+//! conforming LZ4 decoder.
+
+/// Compress `input` into a single LZ4 block.
+pub fn compress(input: &[u8]) -> Vec<u8> {
+    lz4_flex::block::compress(input)
+}
+
+/// Largest block [`compress`] can produce for `input_len` bytes.
+pub fn max_compressed_len(input_len: usize) -> usize {
+    lz4_flex::block::get_maximum_output_size(input_len)
+}
+
+/// Upper bound on the bytes an LZ4 block of `compressed_len` bytes decodes to.
+///
+/// Every output byte past the first few costs at least 1/255 of an input
+/// byte (a `0xFF` length byte), so no block expands by more than 255 times.
+pub fn max_decompressed_len(compressed_len: usize) -> usize {
+    compressed_len.saturating_mul(255)
+}
+
+/// Decompress an LZ4 block that decodes to exactly `expected_len` bytes.
+///
+/// Returns `None` if the block is malformed or has a different length.
+pub fn decompress(input: &[u8], expected_len: usize) -> Option<Vec<u8>> {
+    // An empty input is not a block; the empty block is a single zero token
+    if input.is_empty() {
+        return None;
+    }
+    // One spare byte tells a longer block apart from an exact fit
+    let mut out = vec![0u8; expected_len + 1];
+    let len = lz4_flex::block::decompress_into(input, &mut out).ok()?;
+    (len == expected_len).then(|| {
+        out.truncate(len);
+        out
+    })
+}
@@ -3,6 +3,9 @@
 //! Computes a Merkle root over a byte slice without allocating
 //! or storing tree nodes. Hashes are compatible with standard
 //! Merkle tree constructions.
+//!
+//! When individual chunks need to be checked later, [`build_merkle_tree`]
+//! keeps every node so corruption can be traced to the chunks it affects.
 
 use blake3::Hasher;
 
@@ -23,6 +26,56 @@ fn hash_parent(left: &blake3::Hash, right: &blake3::Hash) -> blake3::Hash {
     h.finalize()
 }
 
+/// Pending subtree roots, one slot per tree depth.
+///
+/// Pushing a leaf merges equal-depth subtrees like a binary counter, so only
+/// one hash per level is ever kept.
+struct LeafStack {
+    level: [blake3::Hash; 64],
+    level_len: [usize; 64],
+}
+
+impl LeafStack {
+    fn new() -> Self {
+        Self {
+            level: [blake3::Hash::from([0u8; 32]); 64],
+            level_len: [0usize; 64],
+        }
+    }
+
+    fn push(&mut self, leaf: blake3::Hash) {
+        let mut h = leaf;
+        let mut depth = 0;
+
+        loop {
+            if self.level_len[depth] == 0 {
+                self.level[depth] = h;
+                self.level_len[depth] = 1;
+                break;
+            }
+
+            h = hash_parent(&self.level[depth], &h);
+            self.level_len[depth] = 0;
+            depth += 1;
+        }
+    }
+
+    fn finish(&self) -> blake3::Hash {
+        let mut root: Option<blake3::Hash> = None;
+
+        for depth in 0..64 {
+            if self.level_len[depth] == 1 {
+                root = Some(match root {
+                    None => self.level[depth],
+                    Some(r) => hash_parent(&self.level[depth], &r),
+                });
+            }
+        }
+
+        root.unwrap_or_else(|| hash_leaf(&[]))
+    }
+}
+
 /// Compute a Merkle-compatible root hash over `data`.
 ///
 /// - `chunk_size` defines leaf granularity
@@ -34,38 +87,208 @@ fn hash_parent(left: &blake3::Hash, right: &blake3::Hash) -> blake3::Hash {
 pub fn merkle_root(data: &[u8], chunk_size: usize) -> blake3::Hash {
     assert!(chunk_size > 0);
 
-    let mut level = [blake3::Hash::from([0u8; 32]); 64];
-    let mut level_len = [0usize; 64];
-
-    // --- Leaf hashing ---
+    let mut stack = LeafStack::new();
     for chunk in data.chunks(chunk_size) {
-        let mut h = hash_leaf(chunk);
-        let mut depth = 0;
+        stack.push(hash_leaf(chunk));
+    }
+    stack.finish()
+}
 
-        loop {
-            if level_len[depth] == 0 {
-                level[depth] = h;
-                level_len[depth] = 1;
-                break;
+/// Incremental version of [`merkle_root`] for data arriving in pieces.
+///
+/// Only the current, incomplete leaf chunk is buffered. Feeding the same
+/// bytes in any split yields the same root as [`merkle_root`].
+pub struct MerkleHasher {
+    stack: LeafStack,
+    chunk_size: usize,
+    buf: Vec<u8>,
+}
+
+impl MerkleHasher {
+    pub fn new(chunk_size: usize) -> Self {
+        assert!(chunk_size > 0);
+
+        Self {
+            stack: LeafStack::new(),
+            chunk_size,
+            buf: Vec::with_capacity(chunk_size),
+        }
+    }
+
+    /// Append bytes to the hashed data.
+    pub fn update(&mut self, mut bytes: &[u8]) {
+        while !bytes.is_empty() {
+            let take = (self.chunk_size - self.buf.len()).min(bytes.len());
+            self.buf.extend_from_slice(&bytes[..take]);
+            bytes = &bytes[take..];
+
+            if self.buf.len() == self.chunk_size {
+                self.stack.push(hash_leaf(&self.buf));
+                self.buf.clear();
             }
+        }
+    }
 
-            h = hash_parent(&level[depth], &h);
-            level_len[depth] = 0;
-            depth += 1;
+    /// Hash the final partial chunk, if any, and return the root.
+    pub fn finalize(mut self) -> blake3::Hash {
+        if !self.buf.is_empty() {
+            self.stack.push(hash_leaf(&self.buf));
         }
+        self.stack.finish()
     }
+}
+
+/// A node of a Merkle tree built by [`build_merkle_tree`].
+///
+/// Nodes refer to each other by index into the tree's `Vec`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MerkleNode {
+    pub hash: blake3::Hash,
+    /// Indices of the left and right child, or `None` for a leaf.
+    pub children: Option<(usize, usize)>,
+    /// Index of the parent, or `None` for the root.
+    pub parent: Option<usize>,
+    /// Leaf chunks covered by this node.
+    pub chunks: core::ops::Range<usize>,
+}
+
+/// Build the full Merkle tree over `data`, with the same shape and root as
+/// [`merkle_root`].
+///
+/// Leaves come first, in chunk order, so leaf `i` is `tree[i]`. Parents follow
+/// their children and the root is the last node. Empty data yields a single
+/// leaf holding the hash of no bytes.
+pub fn build_merkle_tree(data: &[u8], chunk_size: usize) -> Vec<MerkleNode> {
+    assert!(chunk_size > 0);
 
-    // --- Fold remaining nodes ---
-    let mut root: Option<blake3::Hash> = None;
+    let mut tree: Vec<MerkleNode> = data
+        .chunks(chunk_size)
+        .enumerate()
+        .map(|(i, chunk)| MerkleNode {
+            hash: hash_leaf(chunk),
+            children: None,
+            parent: None,
+            chunks: i..i + 1,
+        })
+        .collect();
+    if tree.is_empty() {
+        tree.push(MerkleNode {
+            hash: hash_leaf(&[]),
+            children: None,
+            parent: None,
+            chunks: 0..1,
+        });
+    }
+
+    let leaves = tree.len();
+    build_subtree(&mut tree, 0..leaves);
+    tree
+}
+
+/// Add the parents above leaves `range`, returning the subtree's root index.
+///
+/// Mirrors [`LeafStack`]: the left subtree is the largest power of two below
+/// the leaf count.
+fn build_subtree(tree: &mut Vec<MerkleNode>, range: core::ops::Range<usize>) -> usize {
+    let len = range.len();
+    if len == 1 {
+        return range.start;
+    }
+
+    let split = range.start + len.next_power_of_two() / 2;
+    let left = build_subtree(tree, range.start..split);
+    let right = build_subtree(tree, split..range.end);
+
+    let index = tree.len();
+    tree.push(MerkleNode {
+        hash: hash_parent(&tree[left].hash, &tree[right].hash),
+        children: Some((left, right)),
+        parent: None,
+        chunks: range,
+    });
+    tree[left].parent = Some(index);
+    tree[right].parent = Some(index);
+    index
+}
 
-    for depth in 0..64 {
-        if level_len[depth] == 1 {
-            root = Some(match root {
-                None => level[depth],
-                Some(r) => hash_parent(&level[depth], &r),
-            });
+/// Check every node of `tree` against `data`: leaves against their chunks and
+/// parents against their children.
+///
+/// Returns `false` if the tree was built for a different number of chunks.
+pub fn verify_merkle_tree(tree: &[MerkleNode], data: &[u8], chunk_size: usize) -> bool {
+    assert!(chunk_size > 0);
+
+    let leaves = data.len().div_ceil(chunk_size).max(1);
+    if tree.len() != 2 * leaves - 1 {
+        return false;
+    }
+    if !corrupted_chunks(tree, data, chunk_size).is_empty() {
+        return false;
+    }
+    tree.iter().all(|node| verify_node(tree, node))
+}
+
+/// Indices of the chunks of `data` whose leaf hash does not match `tree`.
+///
+/// Only the leaves are compared, so a tree built by [`build_merkle_tree`]
+/// pinpoints exactly which chunks changed.
+pub fn corrupted_chunks(tree: &[MerkleNode], data: &[u8], chunk_size: usize) -> Vec<usize> {
+    assert!(chunk_size > 0);
+
+    let matches = |i: usize, chunk: &[u8]| {
+        tree.get(i)
+            .is_some_and(|leaf| leaf.hash == hash_leaf(chunk))
+    };
+    if data.is_empty() {
+        return if matches(0, &[]) { Vec::new() } else { vec![0] };
+    }
+    data.chunks(chunk_size)
+        .enumerate()
+        .filter(|&(i, chunk)| !matches(i, chunk))
+        .map(|(i, _)| i)
+        .collect()
+}
+
+/// Verify one chunk against `tree` without hashing the rest of the data.
+///
+/// Hashes `chunk` and checks each node on the path from leaf `index` to the
+/// root against its children, so the cost is one leaf plus `O(log n)` parents.
+/// Compare the root (`tree.last()`) against a trusted hash to trust the result.
+pub fn verify_chunk(tree: &[MerkleNode], index: usize, chunk: &[u8]) -> bool {
+    let Some(leaf) = tree.get(index) else {
+        return false;
+    };
+    if leaf.children.is_some() || leaf.hash != hash_leaf(chunk) {
+        return false;
+    }
+
+    // A well-formed path is shorter than the tree, which also stops cycles
+    let mut current = index;
+    for _ in 0..tree.len() {
+        let Some(parent) = tree[current].parent else {
+            return true;
+        };
+        let Some(node) = tree.get(parent) else {
+            return false;
+        };
+        let is_child = node
+            .children
+            .is_some_and(|(l, r)| l == current || r == current);
+        if !is_child || !verify_node(tree, node) {
+            return false;
         }
+        current = parent;
     }
+    false
+}
 
-    root.unwrap_or_else(|| hash_leaf(&[]))
+/// Check a parent's hash against its children; leaves always pass.
+fn verify_node(tree: &[MerkleNode], node: &MerkleNode) -> bool {
+    match node.children {
+        None => true,
+        Some((left, right)) => match (tree.get(left), tree.get(right)) {
+            (Some(l), Some(r)) => node.hash == hash_parent(&l.hash, &r.hash),
+            _ => false,
+        },
+    }
 }
@@ -1,14 +1,23 @@
-use crate::merkle::merkle_root;
+use crate::merkle::{MerkleHasher, merkle_root};
 use bytemuck::{Pod, cast_slice, cast_slice_mut};
 use bytemuck_derive::Pod;
 use bytemuck_derive::Zeroable;
-use std::io::{Read, Write};
+use std::collections::HashMap;
+use std::io::{BufWriter, Read, Seek, SeekFrom, Write};
 use std::fs::File;
 use std::path::Path;
 
-const MAGIC: u32 = 0x53415645; // "SAVE"
-const VERSION: u16 = 1;
-const DEFAULT_CHUNK_SIZE: usize = 4096;
+pub(crate) const MAGIC: u32 = 0x53415645; // "SAVE"
+const VERSION: u16 = 2;
+/// Version 1 headers have no `user_version` field.
+const VERSION_1: u16 = 1;
+/// Set in `version` when the payload is LZ4-compressed.
+const COMPRESSED_FLAG: u16 = 0x8000;
+const COMPRESSED_VERSION: u16 = VERSION | COMPRESSED_FLAG;
+/// Set in `version` when a metadata section follows the payload.
+const METADATA_FLAG: u16 = 0x4000;
+const METADATA_VERSION: u16 = VERSION | METADATA_FLAG;
+pub(crate) const DEFAULT_CHUNK_SIZE: usize = 4096;
 
 #[repr(C)]
 #[derive(Clone, Copy, Debug, Pod, Zeroable)]
@@ -18,7 +27,25 @@ pub struct SaveHeader {
     pub element_size: u16,
     pub element_count: u32,
     pub chunk_size: u32,
+    /// Application-defined schema version, covered by `merkle_root`.
+    ///
+    /// Files written before this field existed load with a value of 0.
+    pub user_version: u32,
     pub merkle_root: [u8; 32],
+    /// Written as 0. Pads the header to 56 bytes, so the payload that follows
+    /// is 8-byte aligned and can be memory-mapped as `u64` or `f64`.
+    pub reserved: u32,
+}
+
+/// Size of the header fields shared by all versions (up to `chunk_size`).
+const HEADER_PREFIX: usize = 16;
+
+/// Root hash stored in the header, binding the user version to the payload root.
+fn header_root(payload_root: blake3::Hash, user_version: u32) -> [u8; 32] {
+    let mut h = blake3::Hasher::new();
+    h.update(&user_version.to_le_bytes());
+    h.update(payload_root.as_bytes());
+    *h.finalize().as_bytes()
 }
 
 #[derive(Debug)]
@@ -27,6 +54,28 @@ pub enum SaveError {
     InvalidMagic,
     InvalidVersion,
     HashMismatch,
+    /// A header or section directory declares a Merkle chunk size of 0.
+    InvalidChunkSize,
+    /// A streaming save produced a different number of elements than declared.
+    ElementCountMismatch { expected: u32, found: u64 },
+    /// A section stores elements of a different size than the requested type.
+    ElementSizeMismatch { expected: usize, found: usize },
+    /// A section name is empty, longer than 32 bytes, contains NUL or is duplicated.
+    InvalidSectionName(String),
+    /// No section with the requested name exists.
+    SectionNotFound(String),
+    /// A metadata key or value is longer than 65535 bytes or is not UTF-8, or
+    /// the metadata section does not hold the entries it declares.
+    InvalidMetadata(String),
+    /// A compressed payload is longer than its declared size allows, its
+    /// declared size is larger than it could decode to, or it passed
+    /// verification but is not valid LZ4 data.
+    #[cfg(feature = "lz4")]
+    DecompressionFailed,
+    /// The payload's byte offset in the file is not a multiple of the
+    /// element type's alignment, so it cannot be memory-mapped as `T`.
+    #[cfg(feature = "mmap")]
+    MisalignedPayload { offset: usize, align: usize },
 }
 
 impl From<std::io::Error> for SaveError {
@@ -44,6 +93,28 @@ impl fmt::Display for SaveError {
             SaveError::InvalidMagic => write!(f, "Invalid SAVE magic"),
             SaveError::InvalidVersion => write!(f, "Unsupported SAVE version"),
             SaveError::HashMismatch => write!(f, "Merkle hash mismatch"),
+            SaveError::InvalidChunkSize => write!(f, "Invalid Merkle chunk size 0"),
+            SaveError::ElementCountMismatch { expected, found } => write!(
+                f,
+                "Element count mismatch: declared {}, iterator yielded {}",
+                expected, found
+            ),
+            SaveError::ElementSizeMismatch { expected, found } => write!(
+                f,
+                "Element size mismatch: expected {} bytes, found {}",
+                expected, found
+            ),
+            SaveError::InvalidSectionName(name) => write!(f, "Invalid section name: {:?}", name),
+            SaveError::SectionNotFound(name) => write!(f, "Section not found: {:?}", name),
+            SaveError::InvalidMetadata(entry) => write!(f, "Invalid metadata entry: {:?}", entry),
+            #[cfg(feature = "lz4")]
+            SaveError::DecompressionFailed => write!(f, "Invalid LZ4 payload"),
+            #[cfg(feature = "mmap")]
+            SaveError::MisalignedPayload { offset, align } => write!(
+                f,
+                "Payload at byte offset {} cannot be mapped for an element alignment of {}",
+                offset, align
+            ),
         }
     }
 }
@@ -59,72 +130,520 @@ impl std::error::Error for SaveError {
 
 
 /// Save a slice of POD elements to a writer.
-pub fn save<T: Pod, W: Write>(writer: &mut W, data: &[T]) -> Result<(), SaveError> {
-    let bytes = cast_slice(data);
-    let root = merkle_root(bytes, DEFAULT_CHUNK_SIZE);
+///
+/// `user_version` is the application's own schema version, returned again
+/// by [`load`] so the application can migrate older data.
+pub fn save<T: Pod, W: Write>(
+    writer: &mut W,
+    data: &[T],
+    user_version: u32,
+) -> Result<(), SaveError> {
+    let header = header_for(data, user_version);
+    writer.write_all(bytemuck::bytes_of(&header))?;
+    writer.write_all(cast_slice(data))?;
+    Ok(())
+}
 
-    let header = SaveHeader {
+/// Header for an uncompressed save of `data`.
+fn header_for<T: Pod>(data: &[T], user_version: u32) -> SaveHeader {
+    SaveHeader {
         magic: MAGIC,
         version: VERSION,
         element_size: std::mem::size_of::<T>() as u16,
         element_count: data.len() as u32,
         chunk_size: DEFAULT_CHUNK_SIZE as u32,
-        merkle_root: *root.as_bytes(),
+        user_version,
+        merkle_root: header_root(merkle_root(cast_slice(data), DEFAULT_CHUNK_SIZE), user_version),
+        reserved: 0,
+    }
+}
+
+/// Save POD elements from an iterator without buffering them in memory.
+///
+/// Elements are written one at a time while the Merkle root is computed
+/// incrementally. The header is written first with a placeholder root and
+/// patched once all elements are written, so the writer must be seekable.
+/// The iterator must yield exactly `element_count` elements. A longer one is
+/// rejected as soon as it yields one element too many, with `found` set to
+/// `element_count + 1`; the extra element is not written.
+pub fn save_streaming<T, W, I>(
+    writer: &mut W,
+    iter: I,
+    element_count: u32,
+    user_version: u32,
+) -> Result<(), SaveError>
+where
+    T: Pod,
+    W: Write + Seek,
+    I: Iterator<Item = T>,
+{
+    let mut header = SaveHeader {
+        magic: MAGIC,
+        version: VERSION,
+        element_size: std::mem::size_of::<T>() as u16,
+        element_count,
+        chunk_size: DEFAULT_CHUNK_SIZE as u32,
+        user_version,
+        merkle_root: [0; 32],
+        reserved: 0,
     };
 
+    let start = writer.stream_position()?;
     writer.write_all(bytemuck::bytes_of(&header))?;
-    writer.write_all(bytes)?;
+
+    let mut hasher = MerkleHasher::new(DEFAULT_CHUNK_SIZE);
+    let mut found = 0u64;
+    for element in iter {
+        if found == element_count as u64 {
+            return Err(SaveError::ElementCountMismatch {
+                expected: element_count,
+                found: found + 1,
+            });
+        }
+        let bytes = bytemuck::bytes_of(&element);
+        hasher.update(bytes);
+        writer.write_all(bytes)?;
+        found += 1;
+    }
+
+    if found != element_count as u64 {
+        return Err(SaveError::ElementCountMismatch {
+            expected: element_count,
+            found,
+        });
+    }
+
+    header.merkle_root = header_root(hasher.finalize(), user_version);
+
+    let end = writer.stream_position()?;
+    writer.seek(SeekFrom::Start(start))?;
+    writer.write_all(bytemuck::bytes_of(&header))?;
+    writer.seek(SeekFrom::Start(end))?;
     Ok(())
 }
 
-/// Load POD elements from a reader and verify integrity.
-pub fn load<T: Pod, R: Read>(reader: &mut R) -> Result<Vec<T>, SaveError> {
+/// Read and check a header, accepting both uncompressed and compressed files.
+fn read_header<T: Pod, R: Read>(reader: &mut R) -> Result<SaveHeader, SaveError> {
     let mut header = SaveHeader {
         magic: 0,
         version: 0,
         element_size: 0,
         element_count: 0,
         chunk_size: 0,
+        user_version: 0,
         merkle_root: [0; 32],
+        reserved: 0,
     };
 
-    reader.read_exact(bytemuck::bytes_of_mut(&mut header))?;
+    reader.read_exact(&mut bytemuck::bytes_of_mut(&mut header)[..HEADER_PREFIX])?;
 
     if header.magic != MAGIC {
         return Err(SaveError::InvalidMagic);
     }
 
-    if header.version != VERSION {
-        return Err(SaveError::InvalidVersion);
+    match header.version {
+        VERSION | COMPRESSED_VERSION | METADATA_VERSION => {
+            reader.read_exact(&mut bytemuck::bytes_of_mut(&mut header)[HEADER_PREFIX..])?
+        }
+        VERSION_1 => reader.read_exact(&mut header.merkle_root)?,
+        _ => return Err(SaveError::InvalidVersion),
     }
 
     if header.element_size as usize != std::mem::size_of::<T>() {
         return Err(SaveError::InvalidVersion);
     }
 
+    Ok(header)
+}
+
+/// Validate a chunk size read from a file; it is not covered by any hash.
+pub(crate) fn checked_chunk_size(chunk_size: u32) -> Result<usize, SaveError> {
+    match chunk_size {
+        0 => Err(SaveError::InvalidChunkSize),
+        n => Ok(n as usize),
+    }
+}
+
+/// Check a payload against the root stored in `header`.
+fn verify_root(header: &SaveHeader, bytes: &[u8]) -> Result<(), SaveError> {
+    let chunk_size = checked_chunk_size(header.chunk_size)?;
+    let root = if header.version == VERSION_1 {
+        *merkle_root(bytes, chunk_size).as_bytes()
+    } else {
+        header_root(merkle_root(bytes, chunk_size), header.user_version)
+    };
+    if root != header.merkle_root {
+        return Err(SaveError::HashMismatch);
+    }
+    Ok(())
+}
+
+/// Read and verify the uncompressed payload following `header`.
+fn read_payload<T: Pod, R: Read>(reader: &mut R, header: &SaveHeader) -> Result<Vec<T>, SaveError> {
     let mut data = vec![T::zeroed(); header.element_count as usize];
     let bytes = cast_slice_mut(&mut data);
     reader.read_exact(bytes)?;
 
-    let root = merkle_root(bytes, header.chunk_size as usize);
-    if root.as_bytes() != &header.merkle_root {
-        return Err(SaveError::HashMismatch);
+    verify_root(header, bytes)?;
+    Ok(data)
+}
+
+/// Load POD elements from a reader and verify integrity.
+///
+/// Returns the elements together with the user version they were saved with.
+/// Compressed files and files with metadata are rejected with
+/// [`SaveError::InvalidVersion`].
+pub fn load<T: Pod, R: Read>(reader: &mut R) -> Result<(Vec<T>, u32), SaveError> {
+    let header = read_header::<T, _>(reader)?;
+
+    if matches!(header.version, COMPRESSED_VERSION | METADATA_VERSION) {
+        return Err(SaveError::InvalidVersion);
     }
 
-    Ok(data)
+    let data = read_payload(reader, &header)?;
+    Ok((data, header.user_version))
+}
+
+/// Async version of [`save`] for `tokio` writers.
+///
+/// The Merkle root is computed synchronously before anything is written, and
+/// the writer is flushed once the payload is out.
+#[cfg(feature = "tokio")]
+pub async fn save_async<T, W>(
+    writer: &mut W,
+    data: &[T],
+    user_version: u32,
+) -> Result<(), SaveError>
+where
+    T: Pod,
+    W: tokio::io::AsyncWrite + Unpin,
+{
+    use tokio::io::AsyncWriteExt;
+
+    let header = header_for(data, user_version);
+    writer.write_all(bytemuck::bytes_of(&header)).await?;
+    writer.write_all(cast_slice(data)).await?;
+    writer.flush().await?;
+    Ok(())
+}
+
+/// Async version of [`load`] for `tokio` readers.
+///
+/// The payload is read in full and then verified synchronously.
+#[cfg(feature = "tokio")]
+pub async fn load_async<T, R>(reader: &mut R) -> Result<(Vec<T>, u32), SaveError>
+where
+    T: Pod,
+    R: tokio::io::AsyncRead + Unpin,
+{
+    use tokio::io::AsyncReadExt;
+
+    // Read as many header bytes as the version needs, then parse them with
+    // the same code as the blocking path
+    let mut header_bytes = vec![0u8; HEADER_PREFIX];
+    reader.read_exact(&mut header_bytes).await?;
+    let version = u16::from_ne_bytes([header_bytes[4], header_bytes[5]]);
+    let rest = match version {
+        VERSION | COMPRESSED_VERSION | METADATA_VERSION => {
+            std::mem::size_of::<SaveHeader>() - HEADER_PREFIX
+        }
+        VERSION_1 => 32,
+        _ => 0,
+    };
+    header_bytes.resize(HEADER_PREFIX + rest, 0);
+    reader
+        .read_exact(&mut header_bytes[HEADER_PREFIX..])
+        .await?;
+    let header = read_header::<T, _>(&mut header_bytes.as_slice())?;
+
+    if matches!(header.version, COMPRESSED_VERSION | METADATA_VERSION) {
+        return Err(SaveError::InvalidVersion);
+    }
+
+    let mut data = vec![T::zeroed(); header.element_count as usize];
+    let bytes = cast_slice_mut(&mut data);
+    reader.read_exact(bytes).await?;
+
+    verify_root(&header, bytes)?;
+    Ok((data, header.user_version))
+}
+
+/// Save a slice of POD elements with an LZ4-compressed payload.
+///
+/// The Merkle root covers the compressed bytes, so corruption is detected
+/// before decompression is attempted.
+#[cfg(feature = "lz4")]
+pub fn save_compressed<T: Pod, W: Write>(
+    writer: &mut W,
+    data: &[T],
+    user_version: u32,
+) -> Result<(), SaveError> {
+    let compressed = crate::lz4::compress(cast_slice(data));
+
+    let header = SaveHeader {
+        magic: MAGIC,
+        version: COMPRESSED_VERSION,
+        element_size: std::mem::size_of::<T>() as u16,
+        element_count: data.len() as u32,
+        chunk_size: DEFAULT_CHUNK_SIZE as u32,
+        user_version,
+        merkle_root: header_root(merkle_root(&compressed, DEFAULT_CHUNK_SIZE), user_version),
+        reserved: 0,
+    };
+
+    writer.write_all(bytemuck::bytes_of(&header))?;
+    writer.write_all(&(compressed.len() as u32).to_ne_bytes())?;
+    writer.write_all(&compressed)?;
+    Ok(())
+}
+
+/// Load POD elements saved by [`save_compressed`] or [`save`].
+///
+/// Compressed payloads are verified first and decompressed afterwards.
+#[cfg(feature = "lz4")]
+pub fn load_compressed<T: Pod, R: Read>(reader: &mut R) -> Result<(Vec<T>, u32), SaveError> {
+    let header = read_header::<T, _>(reader)?;
+
+    if header.version == METADATA_VERSION {
+        return Err(SaveError::InvalidVersion);
+    }
+    if header.version != COMPRESSED_VERSION {
+        let data = read_payload(reader, &header)?;
+        return Ok((data, header.user_version));
+    }
+
+    // Neither the length nor the element count is hashed. Bound the length by
+    // the largest block the declared payload could compress to, and read it
+    // incrementally so a short file cannot force a large allocation.
+    let mut len = [0u8; 4];
+    reader.read_exact(&mut len)?;
+    let len = u32::from_ne_bytes(len) as usize;
+    let payload_len = (header.element_count as usize)
+        .checked_mul(std::mem::size_of::<T>())
+        .ok_or(SaveError::DecompressionFailed)?;
+    if len > crate::lz4::max_compressed_len(payload_len) {
+        return Err(SaveError::DecompressionFailed);
+    }
+    let mut compressed = Vec::new();
+    if reader.take(len as u64).read_to_end(&mut compressed)? != len {
+        return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+    }
+
+    verify_root(&header, &compressed)?;
+
+    // The block cannot decode to more than LZ4's maximum ratio allows, so a
+    // larger element count is rejected before the output is allocated
+    if payload_len > crate::lz4::max_decompressed_len(len) {
+        return Err(SaveError::DecompressionFailed);
+    }
+
+    let mut data = vec![T::zeroed(); header.element_count as usize];
+    let bytes: &mut [u8] = cast_slice_mut(&mut data);
+    let decompressed =
+        crate::lz4::decompress(&compressed, bytes.len()).ok_or(SaveError::DecompressionFailed)?;
+    bytes.copy_from_slice(&decompressed);
+
+    Ok((data, header.user_version))
+}
+
+/// Key-value pairs stored by [`save_with_metadata`].
+pub type Metadata = HashMap<String, String>;
+
+/// Save a slice of POD elements followed by a key-value metadata section.
+///
+/// The section is `[SECTION_LEN: u32][COUNT: u32]` then, per entry,
+/// `[KEY_LEN: u16][KEY][VALUE_LEN: u16][VALUE]`, with entries sorted by key.
+/// `SECTION_LEN` counts the bytes after itself. The Merkle root covers the
+/// payload, the section and `user_version`, returned again by
+/// [`load_with_metadata`].
+pub fn save_with_metadata<T: Pod, W: Write>(
+    writer: &mut W,
+    data: &[T],
+    user_version: u32,
+    meta: &Metadata,
+) -> Result<(), SaveError> {
+    let mut entries: Vec<_> = meta.iter().collect();
+    entries.sort();
+
+    let mut section = vec![0u8; 4];
+    section.extend_from_slice(&(entries.len() as u32).to_ne_bytes());
+    for (key, value) in entries {
+        for s in [key, value] {
+            let len =
+                u16::try_from(s.len()).map_err(|_| SaveError::InvalidMetadata(key.clone()))?;
+            section.extend_from_slice(&len.to_ne_bytes());
+            section.extend_from_slice(s.as_bytes());
+        }
+    }
+    let section_len = (section.len() - 4) as u32;
+    section[..4].copy_from_slice(&section_len.to_ne_bytes());
+
+    let payload = cast_slice(data);
+    let mut hasher = MerkleHasher::new(DEFAULT_CHUNK_SIZE);
+    hasher.update(payload);
+    hasher.update(&section);
+
+    let header = SaveHeader {
+        version: METADATA_VERSION,
+        merkle_root: header_root(hasher.finalize(), user_version),
+        ..header_for(data, user_version)
+    };
+
+    writer.write_all(bytemuck::bytes_of(&header))?;
+    writer.write_all(payload)?;
+    writer.write_all(&section)?;
+    Ok(())
+}
+
+/// Load POD elements, user version and metadata saved by [`save_with_metadata`].
+///
+/// Files written by [`save`] load with empty metadata. Compressed files are
+/// rejected with [`SaveError::InvalidVersion`]. The section is read no further
+/// than its declared length, and an entry count that cannot fit in it is
+/// rejected before any entry is decoded.
+pub fn load_with_metadata<T: Pod, R: Read>(
+    reader: &mut R,
+) -> Result<(Vec<T>, u32, Metadata), SaveError> {
+    let header = read_header::<T, _>(reader)?;
+
+    match header.version {
+        METADATA_VERSION => {}
+        COMPRESSED_VERSION => return Err(SaveError::InvalidVersion),
+        _ => {
+            let data = read_payload(reader, &header)?;
+            return Ok((data, header.user_version, HashMap::new()));
+        }
+    }
+
+    let mut data = vec![T::zeroed(); header.element_count as usize];
+    let payload_len = std::mem::size_of_val(data.as_slice());
+    let mut bytes = vec![0u8; payload_len + 4];
+    reader.read_exact(&mut bytes)?;
+
+    // Read incrementally so a corrupt length cannot force a huge allocation
+    let section_len = u32::from_ne_bytes(bytes[payload_len..].try_into().unwrap()) as u64;
+    if reader.take(section_len).read_to_end(&mut bytes)? as u64 != section_len {
+        return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+    }
+
+    // Decode only after verification, so corruption reports as a hash mismatch
+    verify_root(&header, &bytes)?;
+    cast_slice_mut(&mut data).copy_from_slice(&bytes[..payload_len]);
+
+    let meta = parse_metadata(&bytes[payload_len + 4..])?;
+    Ok((data, header.user_version, meta))
+}
+
+/// Decode `[COUNT: u32]` and its entries, which must fill `section` exactly.
+fn parse_metadata(section: &[u8]) -> Result<Metadata, SaveError> {
+    let malformed = || SaveError::InvalidMetadata("malformed metadata section".to_string());
+
+    let (count, mut rest) = section.split_first_chunk::<4>().ok_or_else(malformed)?;
+    let count = u32::from_ne_bytes(*count) as usize;
+    // Every entry needs at least its two length prefixes
+    if count > rest.len() / 4 {
+        return Err(malformed());
+    }
+
+    let mut next_string = || {
+        let (len, tail) = rest.split_first_chunk::<2>().ok_or_else(malformed)?;
+        let len = u16::from_ne_bytes(*len) as usize;
+        if len > tail.len() {
+            return Err(malformed());
+        }
+        let (raw, tail) = tail.split_at(len);
+        rest = tail;
+        String::from_utf8(raw.to_vec()).map_err(|e| {
+            SaveError::InvalidMetadata(String::from_utf8_lossy(e.as_bytes()).into_owned())
+        })
+    };
+
+    let mut meta = HashMap::with_capacity(count);
+    for _ in 0..count {
+        let key = next_string()?;
+        let value = next_string()?;
+        meta.insert(key, value);
+    }
+    if !rest.is_empty() {
+        return Err(malformed());
+    }
+    Ok(meta)
 }
 
 pub fn save_to_file<P: AsRef<Path>, T: Pod>(
     path: P,
     data: &[T],
+    user_version: u32,
 ) -> Result<(), SaveError> {
     let mut file = File::create(path)?;
-    save(&mut file, data)
+    save(&mut file, data, user_version)
 }
 
 pub fn load_from_file<P: AsRef<Path>, T: Pod>(
     path: P,
-) -> Result<Vec<T>, SaveError> {
+) -> Result<(Vec<T>, u32), SaveError> {
     let mut file = File::open(path)?;
     load(&mut file)
 }
+
+/// Memory-map the payload of a saved file instead of reading it into memory.
+///
+/// The whole payload is verified against the header's Merkle root before the
+/// container is returned, which reads every page once; afterwards elements
+/// are served from the mapping. The user version is not returned.
+///
+/// The payload starts right after the 56-byte header, so `T` may have an
+/// alignment of up to 8. Version 1 files have a 48-byte header and are
+/// mapped the same way; use [`load`] for types with larger alignment.
+///
+/// # Errors
+///
+/// Compressed files and files with metadata are rejected with
+/// [`SaveError::InvalidVersion`]. A `T` the payload offset is not aligned for
+/// gives [`SaveError::MisalignedPayload`], and a file shorter than its header
+/// declares gives [`SaveError::Io`].
+#[cfg(feature = "mmap")]
+pub fn load_mmap<T: Pod, P: AsRef<Path>>(path: P) -> Result<raw_bytes::Container<T>, SaveError> {
+    let path = path.as_ref();
+    let header = read_header::<T, _>(&mut File::open(path)?)?;
+
+    if matches!(header.version, COMPRESSED_VERSION | METADATA_VERSION) {
+        return Err(SaveError::InvalidVersion);
+    }
+
+    let offset = match header.version {
+        VERSION_1 => HEADER_PREFIX + 32,
+        _ => std::mem::size_of::<SaveHeader>(),
+    };
+    let align = std::mem::align_of::<T>();
+    if !offset.is_multiple_of(align) {
+        return Err(SaveError::MisalignedPayload { offset, align });
+    }
+
+    let container =
+        raw_bytes::Container::mmap_readonly_at(path, offset as u64, header.element_count as usize)
+            .map_err(|e| match e {
+                raw_bytes::ContainerError::Io(e) => SaveError::Io(e),
+                other => SaveError::Io(std::io::Error::other(other)),
+            })?;
+
+    verify_root(&header, cast_slice(container.as_slice()))?;
+    Ok(container)
+}
+
+pub fn save_streaming_to_file<P, T, I>(
+    path: P,
+    iter: I,
+    element_count: u32,
+    user_version: u32,
+) -> Result<(), SaveError>
+where
+    P: AsRef<Path>,
+    T: Pod,
+    I: Iterator<Item = T>,
+{
+    let mut writer = BufWriter::new(File::create(path)?);
+    save_streaming(&mut writer, iter, element_count, user_version)?;
+    writer.flush()?;
+    Ok(())
+}
@@ -0,0 +1,236 @@
+//! Sectioned save format: several named, typed arrays in one file.
+//!
+//! Layout:
+//!
+//! ```text
+//! [magic: u32][version: u16][reserved: u16]
+//! [section payloads...]
+//! [SectionEntry; count]
+//! [directory_offset: u64][count: u32]
+//! ```
+//!
+//! Offsets are relative to the start of the magic. Every section carries its
+//! own Merkle root, so one section can be loaded and verified without
+//! reading the others.
+
+use crate::merkle::merkle_root;
+use crate::save::{DEFAULT_CHUNK_SIZE, MAGIC, SaveError, checked_chunk_size};
+use bytemuck::{Pod, Zeroable, cast_slice, cast_slice_mut};
+use bytemuck_derive::{Pod, Zeroable};
+use std::io::{Read, Seek, SeekFrom, Write};
+
+const SECTIONS_VERSION: u16 = 3;
+const FILE_HEADER_SIZE: u64 = 8;
+const TRAILER_SIZE: u64 = 12;
+
+/// Maximum length of a section name in bytes.
+pub const MAX_SECTION_NAME: usize = 32;
+
+/// A slice of POD elements with its element type erased.
+///
+/// Lets arrays of different types be passed to [`save_sections`] together.
+pub trait ErasedPod {
+    fn element_size(&self) -> usize;
+    fn element_count(&self) -> usize;
+    fn as_bytes(&self) -> &[u8];
+}
+
+impl<T: Pod> ErasedPod for [T] {
+    fn element_size(&self) -> usize {
+        std::mem::size_of::<T>()
+    }
+
+    fn element_count(&self) -> usize {
+        self.len()
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        cast_slice(self)
+    }
+}
+
+impl<T: Pod> ErasedPod for Vec<T> {
+    fn element_size(&self) -> usize {
+        self.as_slice().element_size()
+    }
+
+    fn element_count(&self) -> usize {
+        self.len()
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        self.as_slice().as_bytes()
+    }
+}
+
+impl<T: Pod, const N: usize> ErasedPod for [T; N] {
+    fn element_size(&self) -> usize {
+        self.as_slice().element_size()
+    }
+
+    fn element_count(&self) -> usize {
+        N
+    }
+
+    fn as_bytes(&self) -> &[u8] {
+        self.as_slice().as_bytes()
+    }
+}
+
+/// Directory entry describing one section.
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable)]
+pub struct SectionEntry {
+    /// Section name, zero-padded.
+    pub name: [u8; MAX_SECTION_NAME],
+    pub offset: u64,
+    pub element_size: u32,
+    pub element_count: u32,
+    pub chunk_size: u32,
+    pub reserved: u32,
+    pub merkle_root: [u8; 32],
+}
+
+impl SectionEntry {
+    /// The section name, without padding.
+    pub fn name(&self) -> &[u8] {
+        let len = self.name.iter().position(|&b| b == 0).unwrap_or(MAX_SECTION_NAME);
+        &self.name[..len]
+    }
+}
+
+fn encode_name(name: &str) -> Result<[u8; MAX_SECTION_NAME], SaveError> {
+    let bytes = name.as_bytes();
+    if bytes.is_empty() || bytes.len() > MAX_SECTION_NAME || bytes.contains(&0) {
+        return Err(SaveError::InvalidSectionName(name.to_string()));
+    }
+
+    let mut out = [0u8; MAX_SECTION_NAME];
+    out[..bytes.len()].copy_from_slice(bytes);
+    Ok(out)
+}
+
+/// Save several named arrays, each with its own element type, to a writer.
+pub fn save_sections<W: Write>(
+    writer: &mut W,
+    sections: &[(&str, &dyn ErasedPod)],
+) -> Result<(), SaveError> {
+    let mut entries = Vec::with_capacity(sections.len());
+    for (i, (name, _)) in sections.iter().enumerate() {
+        if sections[..i].iter().any(|(other, _)| other == name) {
+            return Err(SaveError::InvalidSectionName(name.to_string()));
+        }
+        entries.push(encode_name(name)?);
+    }
+
+    writer.write_all(&MAGIC.to_ne_bytes())?;
+    writer.write_all(&SECTIONS_VERSION.to_ne_bytes())?;
+    writer.write_all(&0u16.to_ne_bytes())?;
+
+    let mut offset = FILE_HEADER_SIZE;
+    let mut directory = Vec::with_capacity(sections.len());
+    for (name, (_, data)) in entries.into_iter().zip(sections) {
+        let bytes = data.as_bytes();
+        writer.write_all(bytes)?;
+
+        directory.push(SectionEntry {
+            name,
+            offset,
+            element_size: data.element_size() as u32,
+            element_count: data.element_count() as u32,
+            chunk_size: DEFAULT_CHUNK_SIZE as u32,
+            reserved: 0,
+            merkle_root: *merkle_root(bytes, DEFAULT_CHUNK_SIZE).as_bytes(),
+        });
+        offset += bytes.len() as u64;
+    }
+
+    writer.write_all(cast_slice(&directory))?;
+    writer.write_all(&offset.to_ne_bytes())?;
+    writer.write_all(&(directory.len() as u32).to_ne_bytes())?;
+    Ok(())
+}
+
+/// Read the section directory from a sectioned file ending at the end of `reader`.
+///
+/// Returns the directory and the position of the file start.
+pub fn read_directory<R: Read + Seek>(
+    reader: &mut R,
+) -> Result<(Vec<SectionEntry>, u64), SaveError> {
+    let end = reader.seek(SeekFrom::End(0))?;
+    if end < FILE_HEADER_SIZE + TRAILER_SIZE {
+        return Err(SaveError::InvalidMagic);
+    }
+
+    reader.seek(SeekFrom::Start(end - TRAILER_SIZE))?;
+    let mut trailer = [0u8; TRAILER_SIZE as usize];
+    reader.read_exact(&mut trailer)?;
+    let directory_offset = u64::from_ne_bytes(trailer[..8].try_into().unwrap());
+    let count = u32::from_ne_bytes(trailer[8..].try_into().unwrap()) as usize;
+
+    let directory_len = (count * std::mem::size_of::<SectionEntry>()) as u64;
+    let start = (end - TRAILER_SIZE)
+        .checked_sub(directory_len)
+        .and_then(|pos| pos.checked_sub(directory_offset))
+        .ok_or(SaveError::InvalidMagic)?;
+
+    reader.seek(SeekFrom::Start(start))?;
+    let mut header = [0u8; FILE_HEADER_SIZE as usize];
+    reader.read_exact(&mut header)?;
+    if u32::from_ne_bytes(header[..4].try_into().unwrap()) != MAGIC {
+        return Err(SaveError::InvalidMagic);
+    }
+    if u16::from_ne_bytes(header[4..6].try_into().unwrap()) != SECTIONS_VERSION {
+        return Err(SaveError::InvalidVersion);
+    }
+
+    let mut directory = vec![SectionEntry::zeroed(); count];
+    reader.seek(SeekFrom::Start(start + directory_offset))?;
+    reader.read_exact(cast_slice_mut(&mut directory))?;
+
+    Ok((directory, start))
+}
+
+/// Load and verify a single named section.
+pub fn load_section<T: Pod, R: Read + Seek>(
+    reader: &mut R,
+    name: &str,
+) -> Result<Vec<T>, SaveError> {
+    let (directory, start) = read_directory(reader)?;
+
+    let entry = directory
+        .iter()
+        .find(|e| e.name() == name.as_bytes())
+        .ok_or_else(|| SaveError::SectionNotFound(name.to_string()))?;
+
+    if entry.element_size as usize != std::mem::size_of::<T>() {
+        return Err(SaveError::ElementSizeMismatch {
+            expected: std::mem::size_of::<T>(),
+            found: entry.element_size as usize,
+        });
+    }
+    let chunk_size = checked_chunk_size(entry.chunk_size)?;
+
+    // The directory is not hashed, so check the section fits before allocating
+    let len = entry.element_count as u64 * entry.element_size as u64;
+    let end = reader.seek(SeekFrom::End(0))?;
+    if start
+        .checked_add(entry.offset)
+        .and_then(|pos| pos.checked_add(len))
+        .is_none_or(|section_end| section_end > end)
+    {
+        return Err(std::io::Error::from(std::io::ErrorKind::UnexpectedEof).into());
+    }
+
+    let mut data = vec![T::zeroed(); entry.element_count as usize];
+    let bytes = cast_slice_mut(&mut data);
+    reader.seek(SeekFrom::Start(start + entry.offset))?;
+    reader.read_exact(bytes)?;
+
+    let root = merkle_root(bytes, chunk_size);
+    if root.as_bytes() != &entry.merkle_root {
+        return Err(SaveError::HashMismatch);
+    }
+
+    Ok(data)
+}
@@ -0,0 +1,68 @@
+#![cfg(feature = "tokio")]
+
+use save::SaveError;
+use save::save::{load, load_async, save, save_async};
+use tokio::io::AsyncWriteExt;
+
+#[tokio::test]
+async fn async_round_trip_through_file() {
+    let path = std::env::temp_dir().join(format!("save_async_{}.bin", std::process::id()));
+    let data: Vec<u32> = (0..10_000).collect();
+
+    let mut file = tokio::fs::File::create(&path).await.unwrap();
+    save_async(&mut file, &data, 7).await.unwrap();
+    file.flush().await.unwrap();
+    drop(file);
+
+    let mut file = tokio::fs::File::open(&path).await.unwrap();
+    let (loaded, user_version) = load_async::<u32, _>(&mut file).await.unwrap();
+    drop(file);
+    std::fs::remove_file(&path).unwrap();
+
+    assert_eq!(loaded, data);
+    assert_eq!(user_version, 7);
+}
+
+#[tokio::test]
+async fn async_matches_blocking_format() {
+    let data: Vec<u64> = (0..500).map(|i| i * i).collect();
+
+    let mut expected = Vec::new();
+    save(&mut expected, &data, 3).unwrap();
+
+    let mut written = Vec::new();
+    save_async(&mut written, &data, 3).await.unwrap();
+    assert_eq!(written, expected);
+
+    let (loaded, _) = load_async::<u64, _>(&mut written.as_slice()).await.unwrap();
+    assert_eq!(loaded, load::<u64, _>(&mut expected.as_slice()).unwrap().0);
+}
+
+#[tokio::test]
+async fn async_save_flushes_buffered_writer() {
+    let data: Vec<u32> = (0..100).collect();
+    let mut expected = Vec::new();
+    save(&mut expected, &data, 1).unwrap();
+
+    let mut writer = tokio::io::BufWriter::new(Vec::new());
+    save_async(&mut writer, &data, 1).await.unwrap();
+    assert_eq!(writer.get_ref(), &expected);
+}
+
+#[tokio::test]
+async fn async_load_detects_corruption() {
+    let data: Vec<u32> = (0..100).collect();
+    let mut bytes = Vec::new();
+    save_async(&mut bytes, &data, 0).await.unwrap();
+
+    let last = bytes.len() - 1;
+    bytes[last] ^= 0xFF;
+    assert!(matches!(
+        load_async::<u32, _>(&mut bytes.as_slice()).await,
+        Err(SaveError::HashMismatch)
+    ));
+    assert!(matches!(
+        load_async::<u16, _>(&mut &bytes[..]).await,
+        Err(SaveError::InvalidVersion)
+    ));
+}
@@ -3,6 +3,7 @@ use save::save::{load, save};
 use save::save::{save_to_file, load_from_file};
 
 use save::SaveError;
+use save::merkle::merkle_root;
 use packed_structs::PackedStructContainer;
 use bytemuck_derive::{Pod, Zeroable};
 //use bytemuck::Pod;
@@ -30,9 +31,9 @@ fn round_trip_save_load() {
     ]);
 
     let path = "test_save.bin";
-    save_to_file(path, &container).unwrap();
+    save_to_file(path, &container, 0).unwrap();
 
-    let loaded = load_from_file::<_, SaveData>(path).unwrap();
+    let (loaded, _) = load_from_file::<_, SaveData>(path).unwrap();
 
     assert_eq!(loaded.len(), 2);
     let loaded_slice = loaded.as_slice();
@@ -50,7 +51,7 @@ fn detect_corrupt_save() {
     ]);
 
     let path = "corrupt_test_save.bin";
-    save_to_file(path, &container).unwrap();
+    save_to_file(path, &container, 0).unwrap();
 
     // Corrupt the payload (past header)
     let mut bytes = fs::read(path).unwrap();
@@ -71,7 +72,7 @@ fn reject_wrong_element_size() {
     ]);
 
     let path = "wrong_type.bin";
-    save_to_file(path, &container).unwrap();
+    save_to_file(path, &container, 0).unwrap();
 
     let result = load_from_file::<_, u64>(path);
     assert!(matches!(result, Err(SaveError::InvalidVersion)));
@@ -84,9 +85,76 @@ fn empty_save_roundtrip() {
     let container = PackedStructContainer::<SaveData>::from_slice(&[]);
 
     let path = "empty.bin";
-    save_to_file(path, &container).unwrap();
-    let loaded = load_from_file::<_, SaveData>(path).unwrap();
+    save_to_file(path, &container, 0).unwrap();
+    let (loaded, _) = load_from_file::<_, SaveData>(path).unwrap();
 
     assert_eq!(loaded.len(), 0);
     fs::remove_file(path).unwrap();
 }
+
+#[test]
+fn user_version_roundtrip() {
+    let data = [SaveData::new(1, 2, 3)];
+
+    let mut bytes = Vec::new();
+    save(&mut bytes, &data, 7).unwrap();
+
+    let (loaded, user_version) = load::<SaveData, _>(&mut bytes.as_slice()).unwrap();
+    assert_eq!(loaded, data);
+    assert_eq!(user_version, 7);
+}
+
+#[test]
+fn detect_tampered_user_version() {
+    let mut bytes = Vec::new();
+    save(&mut bytes, &[SaveData::new(1, 2, 3)], 3).unwrap();
+
+    // `user_version` sits right after `chunk_size`
+    bytes[16..20].copy_from_slice(&4u32.to_le_bytes());
+
+    let result = load::<SaveData, _>(&mut bytes.as_slice());
+    assert!(matches!(result, Err(SaveError::HashMismatch)));
+}
+
+#[test]
+fn load_version_1_file() {
+    let data = [SaveData::new(5, 6, 7), SaveData::new(8, 9, 10)];
+    let payload: &[u8] = bytemuck::cast_slice(&data);
+
+    // Version 1 header: no `user_version`, root over the payload only
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(&0x53415645u32.to_le_bytes());
+    bytes.extend_from_slice(&1u16.to_le_bytes());
+    bytes.extend_from_slice(&(std::mem::size_of::<SaveData>() as u16).to_le_bytes());
+    bytes.extend_from_slice(&(data.len() as u32).to_le_bytes());
+    bytes.extend_from_slice(&4096u32.to_le_bytes());
+    bytes.extend_from_slice(merkle_root(payload, 4096).as_bytes());
+    bytes.extend_from_slice(payload);
+
+    let (loaded, user_version) = load::<SaveData, _>(&mut bytes.as_slice()).unwrap();
+    assert_eq!(loaded, data);
+    assert_eq!(user_version, 0);
+}
+
+#[test]
+fn reject_zero_chunk_size() {
+    let mut bytes = Vec::new();
+    save(&mut bytes, &[SaveData::new(1, 2, 3)], 0).unwrap();
+
+    // `chunk_size` is not hashed, so it must be checked before hashing
+    bytes[12..16].copy_from_slice(&0u32.to_le_bytes());
+
+    let result = load::<SaveData, _>(&mut bytes.as_slice());
+    assert!(matches!(result, Err(SaveError::InvalidChunkSize)));
+}
+
+#[test]
+fn header_keeps_payload_8_byte_aligned() {
+    assert_eq!(std::mem::size_of::<save::save::SaveHeader>(), 56);
+
+    let mut bytes = Vec::new();
+    save(&mut bytes, &[1u64, 2], 0).unwrap();
+    assert_eq!(bytes.len(), 56 + 16);
+    assert_eq!(&bytes[52..56], &[0; 4], "reserved is written as 0");
+    assert_eq!(&bytes[56..64], &1u64.to_ne_bytes());
+}
@@ -0,0 +1,162 @@
+#![cfg(feature = "lz4")]
+
+use save::SaveError;
+use save::lz4::{compress, decompress};
+use save::save::{load, load_compressed, save, save_compressed};
+
+fn samples() -> Vec<Vec<u8>> {
+    let mut lcg = 12345u32;
+    let noise: Vec<u8> = (0..5000)
+        .map(|_| {
+            lcg = lcg.wrapping_mul(1103515245).wrapping_add(12345);
+            (lcg >> 24) as u8
+        })
+        .collect();
+
+    vec![
+        vec![],
+        b"a".to_vec(),
+        b"abcdefghijkl".to_vec(),
+        vec![7; 13],
+        vec![0; 100_000],
+        (0..70_000u32).flat_map(|i| (i % 300).to_le_bytes()).collect(),
+        noise,
+    ]
+}
+
+#[test]
+fn lz4_roundtrip() {
+    for input in samples() {
+        let compressed = compress(&input);
+        assert_eq!(decompress(&compressed, input.len()), Some(input.clone()));
+        assert_eq!(decompress(&compressed, input.len() + 1), None);
+    }
+}
+
+#[test]
+fn lz4_decodes_reference_block() {
+    // "abc" then a 9 byte match at offset 3, then 5 trailing literals
+    let block = [0x35, b'a', b'b', b'c', 3, 0, 0x50, b'x', b'y', b'z', b'x', b'y'];
+    assert_eq!(decompress(&block, 17).unwrap(), b"abcabcabcabcxyzxy");
+}
+
+fn entity_log() -> Vec<u8> {
+    (0..40)
+        .map(|i| format!("entity {i:03} hp=100 team={}; ", i % 3))
+        .collect::<String>()
+        .into_bytes()
+}
+
+/// `entity_log()` compressed by the reference `lz4` tool (`lz4 -9 -B4`),
+/// taken from the frame's single data block.
+const REFERENCE_TOOL_BLOCK: &[&str] = &[
+    "f50b656e74697479203030302068703d313030207465616d3d303b201a0019311a0017311a001932",
+    "1a0017321a001f334e00061f344e00061f354e00061f364e00061f374e00061f384e00061f394e00",
+    "052f31304e00052f31314e00052f31324e00061f334e00061f344e00061f354e00061f364e00061f",
+    "374e00061f384e00061f394e00052f32304e00052f32314e00052f32324e00061f334e00061f344e",
+    "00061f354e00061f364e00061f374e00061f384e00061f394e00051f330c03061f330c03061f330c",
+    "03061f330c03061f330c03061f330c03061f330c03061f330c03061f330c030618330401506d3d30",
+    "3b20",
+];
+
+#[test]
+fn lz4_decodes_reference_tool_output() {
+    let hex: String = REFERENCE_TOOL_BLOCK.concat();
+    let block: Vec<u8> = (0..hex.len())
+        .step_by(2)
+        .map(|i| u8::from_str_radix(&hex[i..i + 2], 16).unwrap())
+        .collect();
+
+    let expected = entity_log();
+    assert_eq!(decompress(&block, expected.len()), Some(expected));
+}
+
+#[test]
+fn lz4_rejects_malformed_blocks() {
+    // Offset reaching before the start of the output
+    assert_eq!(decompress(&[0x10, b'a', 2, 0, 0x00], 6), None);
+    // Truncated literals
+    assert_eq!(decompress(&[0x30, b'a'], 3), None);
+    assert_eq!(decompress(&[], 0), None);
+}
+
+#[test]
+fn compressed_roundtrip() {
+    let data: Vec<u32> = (0..10_000).map(|i| i / 10).collect();
+
+    let mut compressed = Vec::new();
+    save_compressed(&mut compressed, &data, 4).unwrap();
+    let mut plain = Vec::new();
+    save(&mut plain, &data, 4).unwrap();
+    assert!(compressed.len() < plain.len() / 4);
+
+    let (loaded, user_version) = load_compressed::<u32, _>(&mut compressed.as_slice()).unwrap();
+    assert_eq!(loaded, data);
+    assert_eq!(user_version, 4);
+
+    // Uncompressed files load as well
+    let (loaded, _) = load_compressed::<u32, _>(&mut plain.as_slice()).unwrap();
+    assert_eq!(loaded, data);
+}
+
+#[test]
+fn plain_load_rejects_compressed() {
+    let mut bytes = Vec::new();
+    save_compressed(&mut bytes, &[1u64, 2, 3], 0).unwrap();
+
+    assert!(matches!(
+        load::<u64, _>(&mut bytes.as_slice()),
+        Err(SaveError::InvalidVersion)
+    ));
+}
+
+#[test]
+fn detect_corrupt_compressed_payload() {
+    let data: Vec<u16> = (0..2000).map(|i| i % 17).collect();
+    let mut bytes = Vec::new();
+    save_compressed(&mut bytes, &data, 0).unwrap();
+
+    let last = bytes.len() - 1;
+    bytes[last] ^= 0x01;
+    assert!(matches!(
+        load_compressed::<u16, _>(&mut bytes.as_slice()),
+        Err(SaveError::HashMismatch)
+    ));
+}
+
+#[test]
+fn oversized_compressed_length_is_rejected() {
+    let mut bytes = Vec::new();
+    save_compressed(&mut bytes, &[1u32, 2, 3], 0).unwrap();
+
+    // The compressed length follows the 56 byte header
+    bytes[56..60].copy_from_slice(&u32::MAX.to_ne_bytes());
+    assert!(matches!(
+        load_compressed::<u32, _>(&mut bytes.as_slice()),
+        Err(SaveError::DecompressionFailed)
+    ));
+}
+
+#[test]
+fn oversized_element_count_is_rejected_before_allocating() {
+    let mut bytes = Vec::new();
+    save_compressed(&mut bytes, &[1u32, 2, 3], 0).unwrap();
+
+    // `element_count` is not hashed; 16 GiB cannot come out of a few bytes
+    bytes[8..12].copy_from_slice(&u32::MAX.to_ne_bytes());
+    assert!(matches!(
+        load_compressed::<u32, _>(&mut bytes.as_slice()),
+        Err(SaveError::DecompressionFailed)
+    ));
+}
+
+#[test]
+fn max_decompressed_len_bounds_real_blocks() {
+    for input in samples() {
+        let block = compress(&input);
+        assert!(input.len() <= save::lz4::max_decompressed_len(block.len()));
+    }
+    let zeros = vec![0u8; 1 << 20];
+    let block = compress(&zeros);
+    assert!(zeros.len() <= save::lz4::max_decompressed_len(block.len()));
+}
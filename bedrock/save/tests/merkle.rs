@@ -0,0 +1,114 @@
+use save::merkle::{
+    MerkleNode, build_merkle_tree, corrupted_chunks, merkle_root, verify_chunk,
+    verify_merkle_tree,
+};
+
+fn sample(len: usize) -> Vec<u8> {
+    (0..len).map(|i| (i * 31 % 251) as u8).collect()
+}
+
+fn root(tree: &[MerkleNode]) -> blake3::Hash {
+    tree.last().unwrap().hash
+}
+
+#[test]
+fn tree_root_matches_merkle_root() {
+    for len in [0, 1, 15, 16, 17, 100, 1000, 4096] {
+        let data = sample(len);
+        for chunk_size in [1, 7, 16, 64] {
+            let tree = build_merkle_tree(&data, chunk_size);
+            assert_eq!(root(&tree), merkle_root(&data, chunk_size), "len {len}, chunk {chunk_size}");
+        }
+    }
+}
+
+#[test]
+fn tree_layout() {
+    let data = sample(5 * 8);
+    let tree = build_merkle_tree(&data, 8);
+
+    // 5 leaves, 4 parents, root last
+    assert_eq!(tree.len(), 9);
+    for (i, leaf) in tree[..5].iter().enumerate() {
+        assert_eq!(leaf.children, None);
+        assert_eq!(leaf.chunks, i..i + 1);
+    }
+    let root = tree.last().unwrap();
+    assert_eq!(root.parent, None);
+    assert_eq!(root.chunks, 0..5);
+
+    // Every non-root node is a child of its parent
+    for (i, node) in tree[..8].iter().enumerate() {
+        let (l, r) = tree[node.parent.unwrap()].children.unwrap();
+        assert!(l == i || r == i);
+    }
+}
+
+#[test]
+fn empty_data_has_single_leaf() {
+    let tree = build_merkle_tree(&[], 16);
+    assert_eq!(tree.len(), 1);
+    assert_eq!(root(&tree), merkle_root(&[], 16));
+    assert!(verify_merkle_tree(&tree, &[], 16));
+    assert!(verify_chunk(&tree, 0, &[]));
+}
+
+#[test]
+fn verify_accepts_matching_data() {
+    let data = sample(1000);
+    let tree = build_merkle_tree(&data, 64);
+    assert!(verify_merkle_tree(&tree, &data, 64));
+    assert!(corrupted_chunks(&tree, &data, 64).is_empty());
+}
+
+#[test]
+fn corruption_is_located() {
+    let data = sample(1000);
+    let tree = build_merkle_tree(&data, 64);
+
+    let mut corrupted = data.clone();
+    corrupted[200] ^= 0xFF; // chunk 3
+    corrupted[999] ^= 0x01; // chunk 15, the partial last one
+
+    assert!(!verify_merkle_tree(&tree, &corrupted, 64));
+    assert_eq!(corrupted_chunks(&tree, &corrupted, 64), vec![3, 15]);
+}
+
+#[test]
+fn verify_rejects_different_chunk_count() {
+    let data = sample(1000);
+    let tree = build_merkle_tree(&data, 64);
+    assert!(!verify_merkle_tree(&tree, &data[..900], 64));
+    assert!(!verify_merkle_tree(&tree, &data, 32));
+}
+
+#[test]
+fn verify_rejects_tampered_parent() {
+    let data = sample(256);
+    let mut tree = build_merkle_tree(&data, 16);
+    let parent = tree[0].parent.unwrap();
+    tree[parent].hash = blake3::hash(b"tampered");
+
+    // Leaves still match, but the parent no longer hashes its children
+    assert!(corrupted_chunks(&tree, &data, 16).is_empty());
+    assert!(!verify_merkle_tree(&tree, &data, 16));
+    assert!(!verify_chunk(&tree, 0, &data[..16]));
+    // A leaf under a different parent is unaffected
+    assert!(verify_chunk(&tree, 15, &data[240..]));
+}
+
+#[test]
+fn verify_single_chunk() {
+    let data = sample(1000);
+    let tree = build_merkle_tree(&data, 64);
+
+    for (i, chunk) in data.chunks(64).enumerate() {
+        assert!(verify_chunk(&tree, i, chunk));
+    }
+    assert!(!verify_chunk(&tree, 2, &data[..64]));
+    assert!(!verify_chunk(&tree, 100, &data[..64]));
+
+    // Parents are not leaves
+    let root_index = tree.len() - 1;
+    assert!(!verify_chunk(&tree, root_index, &data));
+}
@@ -0,0 +1,139 @@
+use std::collections::HashMap;
+
+use save::SaveError;
+use save::merkle::merkle_root;
+use save::save::{load, load_with_metadata, save, save_with_metadata};
+
+fn sample_meta() -> HashMap<String, String> {
+    HashMap::from([
+        ("author".to_string(), "stormogulen".to_string()),
+        ("game_version".to_string(), "1.4.2".to_string()),
+        ("timestamp".to_string(), "2024-05-01T12:00:00Z".to_string()),
+    ])
+}
+
+#[test]
+fn metadata_round_trip() {
+    let data: Vec<u32> = (0..5000).collect();
+    let meta = sample_meta();
+
+    let mut bytes = Vec::new();
+    save_with_metadata(&mut bytes, &data, 9, &meta).unwrap();
+
+    let (loaded, user_version, loaded_meta) =
+        load_with_metadata::<u32, _>(&mut bytes.as_slice()).unwrap();
+    assert_eq!(loaded, data);
+    assert_eq!(user_version, 9);
+    assert_eq!(loaded_meta, meta);
+}
+
+#[test]
+fn metadata_output_is_deterministic() {
+    let data = [1u16, 2, 3];
+    let mut first = Vec::new();
+    let mut second = Vec::new();
+    save_with_metadata(&mut first, &data, 0, &sample_meta()).unwrap();
+    save_with_metadata(&mut second, &data, 0, &sample_meta()).unwrap();
+    assert_eq!(first, second);
+}
+
+#[test]
+fn metadata_is_covered_by_hash() {
+    let data = [7u64; 16];
+    let mut bytes = Vec::new();
+    save_with_metadata(&mut bytes, &data, 0, &sample_meta()).unwrap();
+
+    // Last byte belongs to the value of "timestamp"
+    let last = bytes.len() - 1;
+    bytes[last] = b'X';
+    assert!(matches!(
+        load_with_metadata::<u64, _>(&mut bytes.as_slice()),
+        Err(SaveError::HashMismatch)
+    ));
+}
+
+#[test]
+fn plain_save_loads_with_empty_metadata() {
+    let data = [1u32, 2, 3];
+    let mut bytes = Vec::new();
+    save(&mut bytes, &data, 4).unwrap();
+
+    let (loaded, user_version, meta) = load_with_metadata::<u32, _>(&mut bytes.as_slice()).unwrap();
+    assert_eq!(loaded, data);
+    assert_eq!(user_version, 4);
+    assert!(meta.is_empty());
+}
+
+#[test]
+fn plain_load_rejects_metadata_file() {
+    let mut bytes = Vec::new();
+    save_with_metadata(&mut bytes, &[1u32], 0, &sample_meta()).unwrap();
+    assert!(matches!(
+        load::<u32, _>(&mut bytes.as_slice()),
+        Err(SaveError::InvalidVersion)
+    ));
+}
+
+#[test]
+fn oversized_metadata_is_rejected() {
+    let meta = HashMap::from([("big".to_string(), "x".repeat(70_000))]);
+    let mut bytes = Vec::new();
+    assert!(matches!(
+        save_with_metadata(&mut bytes, &[1u8], 0, &meta),
+        Err(SaveError::InvalidMetadata(key)) if key == "big"
+    ));
+}
+
+#[test]
+fn user_version_is_covered_by_hash() {
+    let mut bytes = Vec::new();
+    save_with_metadata(&mut bytes, &[1u32], 5, &sample_meta()).unwrap();
+
+    // user_version sits right before the 32-byte Merkle root
+    bytes[16] ^= 0x01;
+    assert!(matches!(
+        load_with_metadata::<u32, _>(&mut bytes.as_slice()),
+        Err(SaveError::HashMismatch)
+    ));
+}
+
+/// Recompute the header's root after patching the bytes it covers.
+fn rehash(bytes: &mut [u8]) {
+    let chunk_size = u32::from_ne_bytes(bytes[12..16].try_into().unwrap()) as usize;
+    let mut h = blake3::Hasher::new();
+    h.update(&bytes[16..20]);
+    h.update(merkle_root(&bytes[56..], chunk_size).as_bytes());
+    bytes[20..52].copy_from_slice(h.finalize().as_bytes());
+}
+
+#[test]
+fn oversized_entry_count_is_rejected() {
+    // Header, one u32 element, then a section claiming u32::MAX entries in 4 bytes
+    let mut bytes = Vec::new();
+    save_with_metadata(&mut bytes, &[1u32], 0, &HashMap::new()).unwrap();
+    assert_eq!(bytes.len(), 56 + 4 + 8);
+    rehash(&mut bytes);
+    assert!(load_with_metadata::<u32, _>(&mut bytes.as_slice()).is_ok());
+
+    bytes[64..68].copy_from_slice(&u32::MAX.to_ne_bytes());
+    assert!(matches!(
+        load_with_metadata::<u32, _>(&mut bytes.as_slice()),
+        Err(SaveError::HashMismatch)
+    ));
+    rehash(&mut bytes);
+    assert!(matches!(
+        load_with_metadata::<u32, _>(&mut bytes.as_slice()),
+        Err(SaveError::InvalidMetadata(_))
+    ));
+}
+
+#[test]
+fn truncated_section_is_rejected() {
+    let mut bytes = Vec::new();
+    save_with_metadata(&mut bytes, &[1u32], 0, &sample_meta()).unwrap();
+    bytes.truncate(bytes.len() - 1);
+    assert!(matches!(
+        load_with_metadata::<u32, _>(&mut bytes.as_slice()),
+        Err(SaveError::Io(e)) if e.kind() == std::io::ErrorKind::UnexpectedEof
+    ));
+}
@@ -0,0 +1,108 @@
+#![cfg(feature = "mmap")]
+
+use std::collections::HashMap;
+use std::path::PathBuf;
+
+use save::SaveError;
+use save::save::{load_mmap, save_to_file, save_with_metadata};
+
+fn temp_path(name: &str) -> PathBuf {
+    std::env::temp_dir().join(format!("save_mmap_{}_{}.bin", name, std::process::id()))
+}
+
+#[test]
+fn mmap_round_trip() {
+    let path = temp_path("round_trip");
+    let data: Vec<u32> = (0..10_000).collect();
+    save_to_file(&path, &data, 3).unwrap();
+
+    let container = load_mmap::<u32, _>(&path).unwrap();
+    assert_eq!(container.len(), 10_000);
+    assert_eq!(container.as_slice(), &data[..]);
+    assert_eq!(container[9_999], 9_999);
+    drop(container);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn mmap_detects_corruption() {
+    let path = temp_path("corrupt");
+    let data: Vec<u32> = (0..10_000).collect();
+    save_to_file(&path, &data, 0).unwrap();
+
+    let mut bytes = std::fs::read(&path).unwrap();
+    let last = bytes.len() - 1;
+    bytes[last] ^= 0xFF;
+    std::fs::write(&path, &bytes).unwrap();
+    assert!(matches!(
+        load_mmap::<u32, _>(&path),
+        Err(SaveError::HashMismatch)
+    ));
+
+    // A payload shorter than the header declares is never mapped past the end
+    std::fs::write(&path, &bytes[..last]).unwrap();
+    assert!(matches!(load_mmap::<u32, _>(&path), Err(SaveError::Io(_))));
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn mmap_rejects_unsupported_files() {
+    let path = temp_path("unsupported");
+    let data: Vec<u32> = (0..16).collect();
+
+    let meta = HashMap::from([("author".to_string(), "test".to_string())]);
+    let mut file = std::fs::File::create(&path).unwrap();
+    save_with_metadata(&mut file, &data, 0, &meta).unwrap();
+    drop(file);
+    assert!(matches!(
+        load_mmap::<u32, _>(&path),
+        Err(SaveError::InvalidVersion)
+    ));
+
+    // Element size must match
+    save_to_file(&path, &data, 0).unwrap();
+    assert!(matches!(
+        load_mmap::<u16, _>(&path),
+        Err(SaveError::InvalidVersion)
+    ));
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn mmap_maps_8_byte_aligned_types() {
+    let path = temp_path("u64");
+    let data: Vec<u64> = (0..1000).map(|i| i * 0x1_0000_0001).collect();
+    save_to_file(&path, &data, 0).unwrap();
+
+    let container = load_mmap::<u64, _>(&path).unwrap();
+    assert_eq!(container.as_slice(), &data[..]);
+    drop(container);
+
+    let floats: Vec<f64> = (0..100).map(|i| i as f64 * 0.5).collect();
+    save_to_file(&path, &floats, 0).unwrap();
+    assert_eq!(load_mmap::<f64, _>(&path).unwrap().as_slice(), &floats[..]);
+
+    std::fs::remove_file(&path).unwrap();
+}
+
+#[test]
+fn mmap_rejects_misaligned_element_type() {
+    let path = temp_path("misaligned");
+    let data: Vec<u128> = (0..16).collect();
+    save_to_file(&path, &data, 0).unwrap();
+
+    let err = load_mmap::<u128, _>(&path).unwrap_err();
+    assert!(matches!(
+        err,
+        SaveError::MisalignedPayload {
+            offset: 56,
+            align: 16
+        }
+    ));
+    assert!(err.to_string().contains("alignment of 16"), "{err}");
+
+    std::fs::remove_file(&path).unwrap();
+}
@@ -0,0 +1,143 @@
+use bytemuck_derive::{Pod, Zeroable};
+use save::SaveError;
+use save::sections::{ErasedPod, load_section, read_directory, save_sections};
+use std::io::Cursor;
+
+#[repr(C)]
+#[derive(Clone, Copy, Debug, Pod, Zeroable, PartialEq)]
+struct PlayerStats {
+    level: u32,
+    health: f32,
+}
+
+fn game_save() -> Vec<u8> {
+    let stats = vec![PlayerStats { level: 3, health: 87.5 }];
+    let inventory: Vec<u16> = vec![101, 102, 205, 999];
+    let world = [0.5f64, -1.0, 2.25];
+
+    let mut out = Vec::new();
+    save_sections(
+        &mut out,
+        &[
+            ("player", &stats as &dyn ErasedPod),
+            ("inventory", &inventory),
+            ("world", &world),
+        ],
+    )
+    .unwrap();
+    out
+}
+
+#[test]
+fn load_each_section() {
+    let mut reader = Cursor::new(game_save());
+
+    let stats: Vec<PlayerStats> = load_section(&mut reader, "player").unwrap();
+    assert_eq!(stats, vec![PlayerStats { level: 3, health: 87.5 }]);
+
+    let world: Vec<f64> = load_section(&mut reader, "world").unwrap();
+    assert_eq!(world, vec![0.5, -1.0, 2.25]);
+
+    let inventory: Vec<u16> = load_section(&mut reader, "inventory").unwrap();
+    assert_eq!(inventory, vec![101, 102, 205, 999]);
+
+    let (directory, start) = read_directory(&mut reader).unwrap();
+    assert_eq!(start, 0);
+    let names: Vec<&[u8]> = directory.iter().map(|e| e.name()).collect();
+    assert_eq!(names, vec![&b"player"[..], b"inventory", b"world"]);
+}
+
+#[test]
+fn sections_after_other_data() {
+    let mut bytes = b"leading bytes".to_vec();
+    bytes.extend_from_slice(&game_save());
+
+    let inventory: Vec<u16> = load_section(&mut Cursor::new(bytes), "inventory").unwrap();
+    assert_eq!(inventory.len(), 4);
+}
+
+#[test]
+fn missing_or_mistyped_section() {
+    let mut reader = Cursor::new(game_save());
+
+    assert!(matches!(
+        load_section::<u16, _>(&mut reader, "quests"),
+        Err(SaveError::SectionNotFound(name)) if name == "quests"
+    ));
+    assert!(matches!(
+        load_section::<u32, _>(&mut reader, "inventory"),
+        Err(SaveError::ElementSizeMismatch { expected: 4, found: 2 })
+    ));
+}
+
+#[test]
+fn detect_corrupt_section() {
+    let mut bytes = game_save();
+    // The first inventory element follows the 8-byte file header and `player`
+    bytes[8 + 8] ^= 0xFF;
+
+    let mut reader = Cursor::new(bytes);
+    assert!(matches!(
+        load_section::<u16, _>(&mut reader, "inventory"),
+        Err(SaveError::HashMismatch)
+    ));
+    // Other sections are unaffected
+    assert!(load_section::<f64, _>(&mut reader, "world").is_ok());
+}
+
+#[test]
+fn reject_invalid_names() {
+    let data = vec![1u8];
+    let long = "x".repeat(33);
+
+    for name in ["", "a\0b", long.as_str()] {
+        let result = save_sections(&mut Vec::new(), &[(name, &data as &dyn ErasedPod)]);
+        assert!(matches!(result, Err(SaveError::InvalidSectionName(_))));
+    }
+
+    let result = save_sections(
+        &mut Vec::new(),
+        &[("a", &data as &dyn ErasedPod), ("a", &data)],
+    );
+    assert!(matches!(result, Err(SaveError::InvalidSectionName(n)) if n == "a"));
+
+    // Exactly 32 bytes is allowed
+    let max = "y".repeat(32);
+    let mut out = Vec::new();
+    save_sections(&mut out, &[(max.as_str(), &data as &dyn ErasedPod)]).unwrap();
+    assert_eq!(load_section::<u8, _>(&mut Cursor::new(out), &max).unwrap(), data);
+}
+
+#[test]
+fn oversized_element_count_is_rejected_before_allocating() {
+    let mut bytes = game_save();
+    let (mut directory, _) = read_directory(&mut Cursor::new(&bytes)).unwrap();
+    directory[1].element_count = u32::MAX;
+
+    let entry_size = std::mem::size_of_val(&directory[0]);
+    let directory_start = bytes.len() - 12 - directory.len() * entry_size;
+    bytes[directory_start..directory_start + directory.len() * entry_size]
+        .copy_from_slice(bytemuck::cast_slice(&directory));
+
+    match load_section::<u16, _>(&mut Cursor::new(bytes), "inventory") {
+        Err(SaveError::Io(e)) => assert_eq!(e.kind(), std::io::ErrorKind::UnexpectedEof),
+        other => panic!("unexpected result: {other:?}"),
+    }
+}
+
+#[test]
+fn zero_chunk_size_is_rejected() {
+    let mut bytes = game_save();
+    let (mut directory, _) = read_directory(&mut Cursor::new(&bytes)).unwrap();
+    directory[1].chunk_size = 0;
+
+    let entry_size = std::mem::size_of_val(&directory[0]);
+    let directory_start = bytes.len() - 12 - directory.len() * entry_size;
+    bytes[directory_start..directory_start + directory.len() * entry_size]
+        .copy_from_slice(bytemuck::cast_slice(&directory));
+
+    assert!(matches!(
+        load_section::<u16, _>(&mut Cursor::new(bytes), "inventory"),
+        Err(SaveError::InvalidChunkSize)
+    ));
+}
@@ -0,0 +1,89 @@
+use save::SaveError;
+use save::merkle::{MerkleHasher, merkle_root};
+use save::save::{load, load_from_file, save, save_streaming, save_streaming_to_file};
+use std::io::Cursor;
+
+#[test]
+fn incremental_hash_matches_merkle_root() {
+    let data: Vec<u8> = (0..10_000u32).map(|i| (i * 31 % 251) as u8).collect();
+
+    for chunk_size in [1, 7, 64, 4096] {
+        for split in [1, 3, 100, 5000] {
+            let mut hasher = MerkleHasher::new(chunk_size);
+            for piece in data.chunks(split) {
+                hasher.update(piece);
+            }
+            assert_eq!(hasher.finalize(), merkle_root(&data, chunk_size));
+        }
+    }
+
+    assert_eq!(MerkleHasher::new(16).finalize(), merkle_root(&[], 16));
+}
+
+#[test]
+fn streaming_matches_buffered_save() {
+    let data: Vec<u32> = (0..3000).collect();
+
+    let mut expected = Vec::new();
+    save(&mut expected, &data, 5).unwrap();
+
+    // Start past the beginning, to check the header is patched in place
+    let mut cursor = Cursor::new(b"prefix".to_vec());
+    cursor.set_position(6);
+    save_streaming(&mut cursor, data.iter().copied(), data.len() as u32, 5).unwrap();
+
+    let out = cursor.into_inner();
+    assert_eq!(&out[..6], b"prefix");
+    assert_eq!(&out[6..], &expected[..]);
+}
+
+#[test]
+fn streaming_rejects_wrong_count() {
+    let mut cursor = Cursor::new(Vec::new());
+    let result = save_streaming(&mut cursor, 0..10u64, 11, 0);
+    assert!(matches!(
+        result,
+        Err(SaveError::ElementCountMismatch { expected: 11, found: 10 })
+    ));
+}
+
+#[test]
+fn streaming_stops_at_first_extra_element() {
+    let mut cursor = Cursor::new(Vec::new());
+    let result = save_streaming(&mut cursor, 0u64.., 3, 0);
+    assert!(matches!(
+        result,
+        Err(SaveError::ElementCountMismatch { expected: 3, found: 4 })
+    ));
+    // Only the header and the declared elements were written
+    assert_eq!(cursor.into_inner().len(), 56 + 3 * 8);
+}
+
+#[test]
+fn streaming_million_elements_to_file() {
+    const COUNT: u32 = 1_000_000;
+    let path = std::env::temp_dir().join(format!("save_streaming_{}.bin", std::process::id()));
+
+    let elements = (0..COUNT as u64).map(|i| i.wrapping_mul(0x9E37_79B9_7F4A_7C15));
+    save_streaming_to_file(&path, elements, COUNT, 2).unwrap();
+
+    let (loaded, user_version) = load_from_file::<_, u64>(&path).unwrap();
+    assert_eq!(user_version, 2);
+    assert_eq!(loaded.len(), COUNT as usize);
+    assert!(
+        loaded
+            .iter()
+            .enumerate()
+            .all(|(i, &v)| v == (i as u64).wrapping_mul(0x9E37_79B9_7F4A_7C15))
+    );
+
+    // Integrity is still checked on the streamed file
+    let mut bytes = std::fs::read(&path).unwrap();
+    std::fs::remove_file(&path).unwrap();
+    let last = bytes.len() - 1;
+    bytes[last] ^= 1;
+    assert!(matches!(
+        load::<u64, _>(&mut bytes.as_slice()),
+        Err(SaveError::HashMismatch)
+    ));
+}